@@ -24,10 +24,20 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_one = "super::correction_changeset_item::Entity")]
+    CorrectionChangesetItem,
     #[sea_orm(has_many = "super::correction_revision::Entity")]
     CorrectionRevision,
     #[sea_orm(has_many = "super::correction_user::Entity")]
     CorrectionUser,
+    #[sea_orm(has_many = "super::correction_vote::Entity")]
+    CorrectionVote,
+}
+
+impl Related<super::correction_changeset_item::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CorrectionChangesetItem.def()
+    }
 }
 
 impl Related<super::correction_revision::Entity> for Entity {
@@ -42,6 +52,12 @@ impl Related<super::correction_user::Entity> for Entity {
     }
 }
 
+impl Related<super::correction_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CorrectionVote.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         super::correction_revision::Relation::User.def()
@@ -15,6 +15,7 @@ pub struct Model {
     pub author_id: i32,
     #[sea_orm(column_type = "Text")]
     pub description: String,
+    pub base_history_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -27,6 +28,8 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Correction,
+    #[sea_orm(has_many = "super::correction_revision_source::Entity")]
+    CorrectionRevisionSource,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::AuthorId",
@@ -43,6 +46,12 @@ impl Related<super::correction::Entity> for Entity {
     }
 }
 
+impl Related<super::correction_revision_source::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CorrectionRevisionSource.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
@@ -16,15 +16,22 @@ pub mod artist_membership_role;
 pub mod artist_membership_role_history;
 pub mod artist_membership_tenure;
 pub mod artist_membership_tenure_history;
+pub mod audit_log;
 pub mod comment;
 pub mod comment_revision;
 pub mod correction;
+pub mod correction_changeset;
+pub mod correction_changeset_item;
+pub mod correction_draft;
 pub mod correction_revision;
+pub mod correction_revision_source;
 pub mod correction_user;
+pub mod correction_vote;
 pub mod credit_role;
 pub mod credit_role_history;
 pub mod credit_role_inheritance;
 pub mod credit_role_inheritance_history;
+pub mod entity_protection;
 pub mod event;
 pub mod event_alternative_name;
 pub mod event_alternative_name_history;
@@ -38,6 +45,8 @@ pub mod label_history;
 pub mod label_localized_name;
 pub mod label_localized_name_history;
 pub mod language;
+pub mod notification;
+pub mod notification_preference;
 pub mod release;
 pub mod release_artist;
 pub mod release_artist_history;
@@ -58,7 +67,9 @@ pub mod release_track;
 pub mod release_track_artist;
 pub mod release_track_artist_history;
 pub mod release_track_history;
+pub mod report;
 pub mod role;
+pub mod role_permission;
 pub mod sea_orm_active_enums;
 pub mod song;
 pub mod song_artist;
@@ -81,6 +92,12 @@ pub mod tag_relation;
 pub mod tag_relation_history;
 pub mod user;
 pub mod user_following;
+pub mod user_identity;
 pub mod user_list;
 pub mod user_list_item;
+pub mod user_recovery_code;
 pub mod user_role;
+pub mod user_suspension;
+pub mod user_totp;
+pub mod webhook;
+pub mod webhook_delivery;
@@ -86,6 +86,38 @@ pub enum ArtistType {
     enumset :: EnumSetType,
     utoipa :: ToSchema,
 )]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "AuditAction")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum AuditAction {
+    #[sea_orm(string_value = "GrantRole")]
+    GrantRole,
+    #[sea_orm(string_value = "RevokeRole")]
+    RevokeRole,
+    #[sea_orm(string_value = "SuspendUser")]
+    SuspendUser,
+    #[sea_orm(string_value = "LiftSuspension")]
+    LiftSuspension,
+    #[sea_orm(string_value = "CreateRole")]
+    CreateRole,
+    #[sea_orm(string_value = "GrantPermission")]
+    GrantPermission,
+    #[sea_orm(string_value = "RevokePermission")]
+    RevokePermission,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "CommentState")]
 #[enumset(no_super_impls)]
 #[enumset(serialize_repr = "list")]
@@ -210,6 +242,32 @@ pub enum CorrectionUserType {
     enumset :: EnumSetType,
     utoipa :: ToSchema,
 )]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "CorrectionVoteType"
+)]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum CorrectionVoteType {
+    #[sea_orm(string_value = "Approve")]
+    Approve,
+    #[sea_orm(string_value = "Reject")]
+    Reject,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "DatePrecision")]
 #[enumset(no_super_impls)]
 #[enumset(serialize_repr = "list")]
@@ -296,6 +354,86 @@ pub enum ImageQueueStatus {
     enumset :: EnumSetType,
     utoipa :: ToSchema,
 )]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "NotificationKind")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum NotificationKind {
+    #[sea_orm(string_value = "CorrectionApproved")]
+    CorrectionApproved,
+    #[sea_orm(string_value = "CorrectionRejected")]
+    CorrectionRejected,
+    #[sea_orm(string_value = "ImageApproved")]
+    ImageApproved,
+    #[sea_orm(string_value = "ImageRejected")]
+    ImageRejected,
+    #[sea_orm(string_value = "ImageReverted")]
+    ImageReverted,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "Permission")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum Permission {
+    #[sea_orm(string_value = "ApproveCorrection")]
+    ApproveCorrection,
+    #[sea_orm(string_value = "EditProtected")]
+    EditProtected,
+    #[sea_orm(string_value = "ProtectEntity")]
+    ProtectEntity,
+    #[sea_orm(string_value = "ModerateImage")]
+    ModerateImage,
+    #[sea_orm(string_value = "ModerateReport")]
+    ModerateReport,
+    #[sea_orm(string_value = "ManageUser")]
+    ManageUser,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ProtectionLevel")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum ProtectionLevel {
+    #[sea_orm(string_value = "AutoApprovalDisabled")]
+    AutoApprovalDisabled,
+    #[sea_orm(string_value = "ModeratorOnly")]
+    ModeratorOnly,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ReleaseType")]
 #[enumset(no_super_impls)]
 #[enumset(serialize_repr = "list")]
@@ -326,6 +464,152 @@ pub enum ReleaseType {
     enumset :: EnumSetType,
     utoipa :: ToSchema,
 )]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ReportAction")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum ReportAction {
+    #[sea_orm(string_value = "Correction")]
+    Correction,
+    #[sea_orm(string_value = "CommentHidden")]
+    CommentHidden,
+    #[sea_orm(string_value = "UserSuspended")]
+    UserSuspended,
+    #[sea_orm(string_value = "Other")]
+    Other,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ReportReason")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum ReportReason {
+    #[sea_orm(string_value = "IncorrectData")]
+    IncorrectData,
+    #[sea_orm(string_value = "Duplicate")]
+    Duplicate,
+    #[sea_orm(string_value = "Vandalism")]
+    Vandalism,
+    #[sea_orm(string_value = "Spam")]
+    Spam,
+    #[sea_orm(string_value = "Abuse")]
+    Abuse,
+    #[sea_orm(string_value = "Other")]
+    Other,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ReportState")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum ReportState {
+    #[sea_orm(string_value = "Open")]
+    Open,
+    #[sea_orm(string_value = "Claimed")]
+    Claimed,
+    #[sea_orm(string_value = "Resolved")]
+    Resolved,
+    #[sea_orm(string_value = "Dismissed")]
+    Dismissed,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ReportTarget")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum ReportTarget {
+    #[sea_orm(string_value = "Artist")]
+    Artist,
+    #[sea_orm(string_value = "Label")]
+    Label,
+    #[sea_orm(string_value = "Release")]
+    Release,
+    #[sea_orm(string_value = "Song")]
+    Song,
+    #[sea_orm(string_value = "Tag")]
+    Tag,
+    #[sea_orm(string_value = "Event")]
+    Event,
+    #[sea_orm(string_value = "SongLyrics")]
+    SongLyrics,
+    #[sea_orm(string_value = "CreditRole")]
+    CreditRole,
+    #[sea_orm(string_value = "Comment")]
+    Comment,
+    #[sea_orm(string_value = "User")]
+    User,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "SourceType")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum SourceType {
+    #[sea_orm(string_value = "Booklet")]
+    Booklet,
+    #[sea_orm(string_value = "OfficialSite")]
+    OfficialSite,
+    #[sea_orm(string_value = "EventCatalog")]
+    EventCatalog,
+    #[sea_orm(string_value = "ShopPage")]
+    ShopPage,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "TagRelationType")]
 #[enumset(no_super_impls)]
 #[enumset(serialize_repr = "list")]
@@ -405,3 +689,57 @@ pub enum StorageBackend {
     #[sea_orm(string_value = "fs")]
     Fs,
 }
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "WebhookDeliveryStatus"
+)]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum WebhookDeliveryStatus {
+    #[sea_orm(string_value = "Pending")]
+    Pending,
+    #[sea_orm(string_value = "Succeeded")]
+    Succeeded,
+    #[sea_orm(string_value = "Failed")]
+    Failed,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "WebhookEventKind")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum WebhookEventKind {
+    #[sea_orm(string_value = "Ping")]
+    Ping,
+    #[sea_orm(string_value = "CorrectionApproved")]
+    CorrectionApproved,
+    #[sea_orm(string_value = "ReleaseCreated")]
+    ReleaseCreated,
+    #[sea_orm(string_value = "ImageApproved")]
+    ImageApproved,
+}
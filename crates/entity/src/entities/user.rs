@@ -25,10 +25,16 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::comment::Entity")]
     Comment,
+    #[sea_orm(has_many = "super::correction_draft::Entity")]
+    CorrectionDraft,
     #[sea_orm(has_many = "super::correction_revision::Entity")]
     CorrectionRevision,
     #[sea_orm(has_many = "super::correction_user::Entity")]
     CorrectionUser,
+    #[sea_orm(has_many = "super::correction_vote::Entity")]
+    CorrectionVote,
+    #[sea_orm(has_many = "super::entity_protection::Entity")]
+    EntityProtection,
     #[sea_orm(
         belongs_to = "super::image::Entity",
         from = "Column::AvatarId",
@@ -45,10 +51,22 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Image1,
+    #[sea_orm(has_many = "super::notification::Entity")]
+    Notification,
+    #[sea_orm(has_many = "super::notification_preference::Entity")]
+    NotificationPreference,
+    #[sea_orm(has_many = "super::user_identity::Entity")]
+    UserIdentity,
     #[sea_orm(has_many = "super::user_list::Entity")]
     UserList,
+    #[sea_orm(has_many = "super::user_recovery_code::Entity")]
+    UserRecoveryCode,
     #[sea_orm(has_many = "super::user_role::Entity")]
     UserRole,
+    #[sea_orm(has_one = "super::user_totp::Entity")]
+    UserTotp,
+    #[sea_orm(has_many = "super::webhook::Entity")]
+    Webhook,
 }
 
 impl Related<super::comment::Entity> for Entity {
@@ -57,6 +75,12 @@ impl Related<super::comment::Entity> for Entity {
     }
 }
 
+impl Related<super::correction_draft::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CorrectionDraft.def()
+    }
+}
+
 impl Related<super::correction_revision::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::CorrectionRevision.def()
@@ -69,18 +93,66 @@ impl Related<super::correction_user::Entity> for Entity {
     }
 }
 
+impl Related<super::correction_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CorrectionVote.def()
+    }
+}
+
+impl Related<super::entity_protection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EntityProtection.def()
+    }
+}
+
+impl Related<super::notification::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notification.def()
+    }
+}
+
+impl Related<super::notification_preference::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationPreference.def()
+    }
+}
+
+impl Related<super::user_identity::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserIdentity.def()
+    }
+}
+
 impl Related<super::user_list::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserList.def()
     }
 }
 
+impl Related<super::user_recovery_code::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserRecoveryCode.def()
+    }
+}
+
 impl Related<super::user_role::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserRole.def()
     }
 }
 
+impl Related<super::user_totp::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserTotp.def()
+    }
+}
+
+impl Related<super::webhook::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Webhook.def()
+    }
+}
+
 impl Related<super::correction::Entity> for Entity {
     fn to() -> RelationDef {
         super::correction_revision::Relation::Correction.def()
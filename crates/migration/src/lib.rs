@@ -42,6 +42,20 @@ migrations![
     m20250828_124137_change_duration_to_integer,
     m20250901_053512_create_release_disc,
     m20250918_080000_make_song_credit_role_optional,
+    m20250920_120000_create_correction_vote,
+    m20250921_090000_add_correction_revision_base,
+    m20250922_100000_create_correction_changeset,
+    m20250923_090000_create_correction_draft,
+    m20250924_090000_create_correction_revision_source,
+    m20250925_090000_create_entity_protection,
+    m20250926_090000_create_report,
+    m20250927_090000_create_user_suspension_and_audit_log,
+    m20250928_090000_create_role_permission,
+    m20250929_090000_create_user_totp,
+    m20250930_090000_create_user_identity,
+    m20251001_090000_create_notification,
+    m20251002_090000_create_webhook,
+    m20251003_090000_create_song_localized_title_trgm_index,
 ];
 
 macro_rules! migration {
@@ -0,0 +1,3 @@
+DROP TABLE "public"."correction_vote";
+
+DROP TYPE "public"."CorrectionVoteType";
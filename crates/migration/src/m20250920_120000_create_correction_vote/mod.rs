@@ -0,0 +1 @@
+super::migration!(m20250920_120000_create_correction_vote);
@@ -0,0 +1,10 @@
+CREATE TYPE "public"."CorrectionVoteType" AS ENUM ('Approve', 'Reject');
+
+CREATE TABLE "public"."correction_vote" (
+  "correction_id" INTEGER NOT NULL REFERENCES "public"."correction" ("id"),
+  "user_id" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "vote" "public"."CorrectionVoteType" NOT NULL,
+  "comment" TEXT NULL,
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  PRIMARY KEY ("correction_id", "user_id")
+);
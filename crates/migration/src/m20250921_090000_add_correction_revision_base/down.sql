@@ -0,0 +1,3 @@
+ALTER TABLE
+  "public"."correction_revision"
+  DROP COLUMN "base_history_id";
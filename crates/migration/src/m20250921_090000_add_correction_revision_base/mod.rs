@@ -0,0 +1 @@
+super::migration!(m20250921_090000_add_correction_revision_base);
@@ -0,0 +1,4 @@
+ALTER TABLE
+  "public"."correction_revision"
+ADD
+  COLUMN "base_history_id" INTEGER NULL;
@@ -0,0 +1,3 @@
+DROP TABLE "public"."correction_changeset_item";
+
+DROP TABLE "public"."correction_changeset";
@@ -0,0 +1 @@
+super::migration!(m20250922_100000_create_correction_changeset);
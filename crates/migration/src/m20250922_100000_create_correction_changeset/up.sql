@@ -0,0 +1,12 @@
+CREATE TABLE "public"."correction_changeset" (
+  "id" SERIAL PRIMARY KEY,
+  "created_at" timestamptz NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE "public"."correction_changeset_item" (
+  "correction_id" INTEGER PRIMARY KEY REFERENCES "public"."correction" ("id"),
+  "changeset_id" INTEGER NOT NULL REFERENCES "public"."correction_changeset" ("id"),
+  "temp_id" TEXT NOT NULL,
+  "position" SMALLINT NOT NULL,
+  UNIQUE ("changeset_id", "temp_id")
+);
@@ -0,0 +1 @@
+super::migration!(m20250923_090000_create_correction_draft);
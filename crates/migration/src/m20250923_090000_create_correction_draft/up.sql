@@ -0,0 +1,16 @@
+CREATE TABLE "public"."correction_draft" (
+  "id" SERIAL PRIMARY KEY,
+  "author_id" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "entity_type" "public"."EntityType" NOT NULL,
+  "entity_id" INTEGER,
+  "base_history_id" INTEGER,
+  "description" TEXT NOT NULL DEFAULT '',
+  "data" JSONB NOT NULL,
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  "updated_at" timestamptz NOT NULL DEFAULT NOW(),
+  "expires_at" timestamptz NOT NULL
+);
+
+CREATE INDEX "correction_draft_author_id_idx" ON "public"."correction_draft" ("author_id");
+
+CREATE INDEX "correction_draft_expires_at_idx" ON "public"."correction_draft" ("expires_at");
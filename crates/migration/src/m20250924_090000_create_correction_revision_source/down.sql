@@ -0,0 +1,5 @@
+ALTER TABLE "public"."correction_draft" DROP COLUMN "sources";
+
+DROP TABLE "public"."correction_revision_source";
+
+DROP TYPE "public"."SourceType";
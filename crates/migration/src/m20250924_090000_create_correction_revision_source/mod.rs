@@ -0,0 +1 @@
+super::migration!(m20250924_090000_create_correction_revision_source);
@@ -0,0 +1,21 @@
+CREATE TYPE "public"."SourceType" AS ENUM (
+  'Booklet',
+  'OfficialSite',
+  'EventCatalog',
+  'ShopPage'
+);
+
+CREATE TABLE "public"."correction_revision_source" (
+  "id" SERIAL PRIMARY KEY,
+  "correction_id" INTEGER NOT NULL,
+  "entity_history_id" INTEGER NOT NULL,
+  "url" TEXT NOT NULL,
+  "type" "public"."SourceType" NOT NULL,
+  "note" TEXT,
+  FOREIGN KEY ("correction_id", "entity_history_id") REFERENCES "public"."correction_revision" ("correction_id", "entity_history_id")
+);
+
+CREATE INDEX "correction_revision_source_revision_idx" ON "public"."correction_revision_source" ("correction_id", "entity_history_id");
+
+ALTER TABLE "public"."correction_draft"
+  ADD COLUMN "sources" JSONB NOT NULL DEFAULT '[]';
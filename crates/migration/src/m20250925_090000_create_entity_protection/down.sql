@@ -0,0 +1,3 @@
+DROP TABLE "public"."entity_protection";
+
+DROP TYPE "public"."ProtectionLevel";
@@ -0,0 +1 @@
+super::migration!(m20250925_090000_create_entity_protection);
@@ -0,0 +1,15 @@
+CREATE TYPE "public"."ProtectionLevel" AS ENUM (
+  'AutoApprovalDisabled',
+  'ModeratorOnly'
+);
+
+CREATE TABLE "public"."entity_protection" (
+  "entity_type" "public"."EntityType" NOT NULL,
+  "entity_id" INTEGER NOT NULL,
+  "level" "public"."ProtectionLevel" NOT NULL,
+  "reason" TEXT NOT NULL,
+  "expires_at" timestamptz,
+  "created_by" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  PRIMARY KEY ("entity_type", "entity_id")
+);
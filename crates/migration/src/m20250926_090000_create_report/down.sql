@@ -0,0 +1,9 @@
+DROP TABLE "public"."report";
+
+DROP TYPE "public"."ReportAction";
+
+DROP TYPE "public"."ReportState";
+
+DROP TYPE "public"."ReportReason";
+
+DROP TYPE "public"."ReportTarget";
@@ -0,0 +1 @@
+super::migration!(m20250926_090000_create_report);
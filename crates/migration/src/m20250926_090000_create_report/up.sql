@@ -0,0 +1,55 @@
+CREATE TYPE "public"."ReportTarget" AS ENUM (
+  'Artist',
+  'Label',
+  'Release',
+  'Song',
+  'Tag',
+  'Event',
+  'SongLyrics',
+  'CreditRole',
+  'Comment',
+  'User'
+);
+
+CREATE TYPE "public"."ReportReason" AS ENUM (
+  'IncorrectData',
+  'Duplicate',
+  'Vandalism',
+  'Spam',
+  'Abuse',
+  'Other'
+);
+
+CREATE TYPE "public"."ReportState" AS ENUM (
+  'Open',
+  'Claimed',
+  'Resolved',
+  'Dismissed'
+);
+
+CREATE TYPE "public"."ReportAction" AS ENUM (
+  'Correction',
+  'CommentHidden',
+  'UserSuspended',
+  'Other'
+);
+
+CREATE TABLE "public"."report" (
+  "id" SERIAL PRIMARY KEY,
+  "reporter_id" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "target" "public"."ReportTarget" NOT NULL,
+  "target_id" INTEGER NOT NULL,
+  "reason" "public"."ReportReason" NOT NULL,
+  "description" TEXT NOT NULL,
+  "state" "public"."ReportState" NOT NULL DEFAULT 'Open',
+  "assignee_id" INTEGER REFERENCES "public"."user" ("id"),
+  "action" "public"."ReportAction",
+  "action_id" INTEGER,
+  "note" TEXT,
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  "handled_at" timestamptz
+);
+
+CREATE INDEX "report_state_idx" ON "public"."report" ("state");
+
+CREATE INDEX "report_reporter_id_created_at_idx" ON "public"."report" ("reporter_id", "created_at");
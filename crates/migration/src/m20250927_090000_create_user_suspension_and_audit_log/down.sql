@@ -0,0 +1,5 @@
+DROP TABLE "public"."audit_log";
+
+DROP TYPE "public"."AuditAction";
+
+DROP TABLE "public"."user_suspension";
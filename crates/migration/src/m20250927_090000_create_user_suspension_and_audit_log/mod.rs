@@ -0,0 +1 @@
+super::migration!(m20250927_090000_create_user_suspension_and_audit_log);
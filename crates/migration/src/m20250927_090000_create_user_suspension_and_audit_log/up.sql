@@ -0,0 +1,31 @@
+CREATE TABLE "public"."user_suspension" (
+  "id" SERIAL PRIMARY KEY,
+  "user_id" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "reason" TEXT NOT NULL,
+  "suspended_until" timestamptz NOT NULL,
+  "created_by" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  "lifted_at" timestamptz
+);
+
+CREATE INDEX "user_suspension_user_id_idx" ON "public"."user_suspension" ("user_id");
+
+CREATE TYPE "public"."AuditAction" AS ENUM (
+  'GrantRole',
+  'RevokeRole',
+  'SuspendUser',
+  'LiftSuspension'
+);
+
+CREATE TABLE "public"."audit_log" (
+  "id" SERIAL PRIMARY KEY,
+  "actor_id" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "action" "public"."AuditAction" NOT NULL,
+  "target_user_id" INTEGER NOT NULL REFERENCES "public"."user" ("id"),
+  "data" jsonb NOT NULL,
+  "created_at" timestamptz NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX "audit_log_actor_id_idx" ON "public"."audit_log" ("actor_id");
+
+CREATE INDEX "audit_log_target_user_id_idx" ON "public"."audit_log" ("target_user_id");
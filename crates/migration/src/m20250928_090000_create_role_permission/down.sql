@@ -0,0 +1,25 @@
+DELETE FROM "public"."audit_log"
+WHERE "action" IN ('CreateRole', 'GrantPermission', 'RevokePermission');
+
+ALTER TABLE "public"."audit_log" ALTER COLUMN "target_user_id" SET NOT NULL;
+
+ALTER TYPE "public"."AuditAction" RENAME TO "AuditAction_old";
+
+CREATE TYPE "public"."AuditAction" AS ENUM (
+  'GrantRole',
+  'RevokeRole',
+  'SuspendUser',
+  'LiftSuspension'
+);
+
+ALTER TABLE "public"."audit_log"
+  ALTER COLUMN "action" TYPE "public"."AuditAction"
+  USING "action"::TEXT::"public"."AuditAction";
+
+DROP TYPE "public"."AuditAction_old";
+
+DROP TABLE "public"."role_permission";
+
+DROP TYPE "public"."Permission";
+
+DROP INDEX "public"."role_name_idx";
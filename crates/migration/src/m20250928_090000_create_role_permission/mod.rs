@@ -0,0 +1 @@
+super::migration!(m20250928_090000_create_role_permission);
@@ -0,0 +1,42 @@
+-- The built-in roles are synced on startup, seed them here so their
+-- permissions can be
+INSERT INTO "public"."role" ("id", "name")
+VALUES (1, 'Admin'), (2, 'Moderator'), (3, 'User')
+ON CONFLICT DO NOTHING;
+
+-- Keep the ids of the built-in roles free of custom ones
+ALTER TABLE "public"."role" ALTER COLUMN "id" RESTART WITH 100;
+
+CREATE UNIQUE INDEX "role_name_idx" ON "public"."role" ("name");
+
+CREATE TYPE "public"."Permission" AS ENUM (
+  'ApproveCorrection',
+  'EditProtected',
+  'ProtectEntity',
+  'ModerateImage',
+  'ModerateReport',
+  'ManageUser'
+);
+
+CREATE TABLE "public"."role_permission" (
+  "id" SERIAL PRIMARY KEY,
+  "role_id" INTEGER NOT NULL REFERENCES "public"."role" ("id") ON DELETE CASCADE,
+  "permission" "public"."Permission" NOT NULL,
+  -- NULL grants the permission for every entity type
+  "entity_type" "public"."EntityType",
+  UNIQUE NULLS NOT DISTINCT ("role_id", "permission", "entity_type")
+);
+
+INSERT INTO "public"."role_permission" ("role_id", "permission")
+SELECT 1, p FROM unnest(enum_range(NULL::"public"."Permission")) AS p;
+
+INSERT INTO "public"."role_permission" ("role_id", "permission")
+SELECT 2, p FROM unnest(enum_range(NULL::"public"."Permission")) AS p
+WHERE p <> 'ManageUser';
+
+ALTER TYPE "public"."AuditAction" ADD VALUE 'CreateRole';
+ALTER TYPE "public"."AuditAction" ADD VALUE 'GrantPermission';
+ALTER TYPE "public"."AuditAction" ADD VALUE 'RevokePermission';
+
+-- Role changes have no target user
+ALTER TABLE "public"."audit_log" ALTER COLUMN "target_user_id" DROP NOT NULL;
@@ -0,0 +1,3 @@
+DROP TABLE "public"."user_recovery_code";
+
+DROP TABLE "public"."user_totp";
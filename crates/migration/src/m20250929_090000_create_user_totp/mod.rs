@@ -0,0 +1 @@
+super::migration!(m20250929_090000_create_user_totp);
@@ -0,0 +1,20 @@
+CREATE TABLE "public"."user_totp" (
+  "user_id" INTEGER PRIMARY KEY REFERENCES "public"."user" ("id") ON DELETE CASCADE,
+  -- Base32, as shown to authenticator apps
+  "secret" TEXT NOT NULL,
+  -- NULL until the first code is confirmed
+  "enabled_at" timestamptz,
+  -- The time step of the last accepted code, which can't be used again
+  "last_used_step" BIGINT,
+  "created_at" timestamptz NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE "public"."user_recovery_code" (
+  "id" SERIAL PRIMARY KEY,
+  "user_id" INTEGER NOT NULL REFERENCES "public"."user" ("id") ON DELETE CASCADE,
+  -- SHA-256 hex
+  "code_hash" TEXT NOT NULL,
+  "used_at" timestamptz
+);
+
+CREATE INDEX "user_recovery_code_user_id_idx" ON "public"."user_recovery_code" ("user_id");
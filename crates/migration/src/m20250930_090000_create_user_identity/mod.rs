@@ -0,0 +1 @@
+super::migration!(m20250930_090000_create_user_identity);
@@ -0,0 +1,13 @@
+CREATE TABLE "public"."user_identity" (
+  "id" SERIAL PRIMARY KEY,
+  "user_id" INTEGER NOT NULL REFERENCES "public"."user" ("id") ON DELETE CASCADE,
+  -- The key of the provider in the config
+  "provider" TEXT NOT NULL,
+  -- The `sub` claim, unique per provider
+  "subject" TEXT NOT NULL,
+  "email" TEXT,
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  UNIQUE ("provider", "subject")
+);
+
+CREATE INDEX "user_identity_user_id_idx" ON "public"."user_identity" ("user_id");
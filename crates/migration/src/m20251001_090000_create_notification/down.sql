@@ -0,0 +1,5 @@
+DROP TABLE "public"."notification_preference";
+
+DROP TABLE "public"."notification";
+
+DROP TYPE "public"."NotificationKind";
@@ -0,0 +1 @@
+super::migration!(m20251001_090000_create_notification);
@@ -0,0 +1,30 @@
+CREATE TYPE "public"."NotificationKind" AS ENUM (
+  'CorrectionApproved',
+  'CorrectionRejected',
+  'ImageApproved',
+  'ImageRejected',
+  'ImageReverted'
+);
+
+CREATE TABLE "public"."notification" (
+  "id" SERIAL PRIMARY KEY,
+  "user_id" INTEGER NOT NULL REFERENCES "public"."user" ("id") ON DELETE CASCADE,
+  "kind" "public"."NotificationKind" NOT NULL,
+  -- The ids of the entities involved, depends on the kind
+  "data" jsonb NOT NULL,
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  "read_at" timestamptz
+);
+
+CREATE INDEX "notification_user_id_idx" ON "public"."notification" ("user_id", "id");
+
+CREATE INDEX "notification_unread_idx" ON "public"."notification" ("user_id")
+WHERE "read_at" IS NULL;
+
+-- Kinds without a row are enabled
+CREATE TABLE "public"."notification_preference" (
+  "user_id" INTEGER NOT NULL REFERENCES "public"."user" ("id") ON DELETE CASCADE,
+  "kind" "public"."NotificationKind" NOT NULL,
+  "enabled" BOOLEAN NOT NULL,
+  PRIMARY KEY ("user_id", "kind")
+);
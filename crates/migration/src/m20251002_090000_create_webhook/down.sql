@@ -0,0 +1,7 @@
+DROP TABLE "public"."webhook_delivery";
+
+DROP TABLE "public"."webhook";
+
+DROP TYPE "public"."WebhookDeliveryStatus";
+
+DROP TYPE "public"."WebhookEventKind";
@@ -0,0 +1 @@
+super::migration!(m20251002_090000_create_webhook);
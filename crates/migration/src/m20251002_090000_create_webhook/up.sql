@@ -0,0 +1,46 @@
+CREATE TYPE "public"."WebhookEventKind" AS ENUM (
+  'Ping',
+  'CorrectionApproved',
+  'ReleaseCreated',
+  'ImageApproved'
+);
+
+CREATE TYPE "public"."WebhookDeliveryStatus" AS ENUM (
+  'Pending',
+  'Succeeded',
+  'Failed'
+);
+
+CREATE TABLE "public"."webhook" (
+  "id" SERIAL PRIMARY KEY,
+  "owner_id" INTEGER NOT NULL REFERENCES "public"."user" ("id") ON DELETE CASCADE,
+  "url" TEXT NOT NULL,
+  -- The key of the HMAC signatures
+  "secret" TEXT NOT NULL,
+  -- Lists of `WebhookEventKind` and `EntityType`, no entity types for all
+  "events" jsonb NOT NULL,
+  "entity_types" jsonb NOT NULL,
+  "created_at" timestamptz NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX "webhook_owner_id_idx" ON "public"."webhook" ("owner_id");
+
+CREATE TABLE "public"."webhook_delivery" (
+  "id" SERIAL PRIMARY KEY,
+  "webhook_id" INTEGER NOT NULL REFERENCES "public"."webhook" ("id") ON DELETE CASCADE,
+  "event" "public"."WebhookEventKind" NOT NULL,
+  "payload" jsonb NOT NULL,
+  "status" "public"."WebhookDeliveryStatus" NOT NULL DEFAULT 'Pending',
+  "attempts" INTEGER NOT NULL DEFAULT 0,
+  "next_attempt_at" timestamptz NOT NULL DEFAULT NOW(),
+  -- The outcome of the last attempt
+  "response_status" INTEGER,
+  "error" TEXT,
+  "created_at" timestamptz NOT NULL DEFAULT NOW(),
+  "delivered_at" timestamptz
+);
+
+CREATE INDEX "webhook_delivery_webhook_id_idx" ON "public"."webhook_delivery" ("webhook_id", "id");
+
+CREATE INDEX "webhook_delivery_due_idx" ON "public"."webhook_delivery" ("next_attempt_at")
+WHERE "status" = 'Pending';
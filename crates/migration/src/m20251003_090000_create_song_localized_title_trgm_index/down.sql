@@ -0,0 +1 @@
+DROP INDEX IF EXISTS idx_song_localized_title_gist;
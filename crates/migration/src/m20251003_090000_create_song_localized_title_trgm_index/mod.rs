@@ -0,0 +1 @@
+super::migration!(m20251003_090000_create_song_localized_title_trgm_index);
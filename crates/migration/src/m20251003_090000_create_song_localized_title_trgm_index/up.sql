@@ -0,0 +1,3 @@
+-- Song localized titles, which are searched for duplicates like the other
+-- names indexed in m20250727_120000_enable_pg_trgm
+CREATE INDEX IF NOT EXISTS idx_song_localized_title_gist ON "public"."song_localized_title" USING gist ("title" gist_trgm_ops);
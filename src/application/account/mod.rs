@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use macros::{ApiError, IntoErrorSchema};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::domain::account::{AccountExport, Repo, TxRepo};
+use crate::domain::image::repository::TxRepo as ImageTxRepo;
+use crate::domain::image::{self, AsyncFileStorage, Image};
+use crate::domain::model::auth::{AuthCredential, AuthnError};
+use crate::domain::repository::{Transaction, TransactionManager};
+use crate::domain::user::{SessionRepo, User};
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R, S, Sess> {
+    pub repo: R,
+    pub storage: S,
+    pub sessions: Sess,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(transparent)]
+    Authn { source: AuthnError },
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct DeleteAccount {
+    /// Required unless the user signs in through identity providers only
+    pub password: Option<String>,
+}
+
+impl<R, S, Sess> Service<R, S, Sess>
+where
+    R: Repo + image::Repo + TransactionManager,
+    R::TransactionRepository: TxRepo + ImageTxRepo,
+    S: AsyncFileStorage,
+    Sess: SessionRepo,
+{
+    /// A zip archive of the profile, images, corrections, comments and lists
+    pub async fn export(&self, user: User) -> Result<Vec<u8>, Error> {
+        let mut images = vec![];
+
+        for (name, id) in [
+            ("avatar", user.avatar_id),
+            ("banner", user.profile_banner_id),
+        ] {
+            let Some(id) = id else { continue };
+
+            if let Some(image) = image::Repo::find_by_id(&self.repo, id).await?
+            {
+                let file_name =
+                    Path::new(&image.filename).extension().map_or_else(
+                        || name.to_owned(),
+                        |ext| format!("{name}.{}", ext.to_string_lossy()),
+                    );
+
+                images.push((file_name, self.storage.read(&image).await?));
+            }
+        }
+
+        let export = AccountExport {
+            corrections: self.repo.export_corrections(user.id).await?,
+            comments: self.repo.export_comments(user.id).await?,
+            lists: self.repo.export_lists(user.id).await?,
+            profile: user.into(),
+        };
+
+        Ok(export.into_archive(images)?)
+    }
+
+    /// Anonymizes the user, signs out all of their sessions and removes
+    /// their images
+    pub async fn delete(
+        &self,
+        user: &User,
+        input: DeleteAccount,
+    ) -> Result<(), Error> {
+        if let Some(hash) = user.password_hash() {
+            AuthCredential::try_new(
+                user.name.clone(),
+                input.password.unwrap_or_default(),
+            )
+            .map_err(|_| AuthnError::authentication_failed())?
+            .verify_credentials(Some(hash))
+            .await?;
+        }
+
+        let tx_repo = self.repo.begin().await?;
+
+        tx_repo.anonymize_user(user.id).await?;
+
+        // Shared images stay until nothing shows them
+        let mut unused_images: Vec<Image> = vec![];
+
+        for id in [user.avatar_id, user.profile_banner_id]
+            .into_iter()
+            .flatten()
+        {
+            if let Some(image) = image::Repo::find_by_id(&tx_repo, id).await?
+                && !image::Repo::is_in_use(&tx_repo, id).await?
+                && !unused_images.iter().any(|unused| unused.id == id)
+            {
+                tx_repo.delete(id).await?;
+                unused_images.push(image);
+            }
+        }
+
+        tx_repo.commit().await?;
+
+        self.sessions.revoke_sessions(user.id).await?;
+
+        // Files that fail to be removed are queued by the storage
+        for image in unused_images {
+            if let Err(err) = self.storage.remove(image).await {
+                tracing::warn!(
+                    "Failed to remove an image of a deleted user: {}",
+                    err.into()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
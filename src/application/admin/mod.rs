@@ -0,0 +1,374 @@
+use axum::http::StatusCode;
+use chrono::Utc;
+use entity::enums::AuditAction;
+use macros::{ApiError, IntoErrorSchema};
+use serde_json::json;
+
+use super::authz::authorize;
+use super::error::Unauthorized;
+use crate::domain::admin::{
+    AuditLogEntry, AuditLogFilter, NewAuditLogEntry, NewRole,
+    NewUserSuspension, Repo, Role, UserSuspension,
+};
+use crate::domain::model::auth::{Permission, PermissionGrant, UserRoleEnum};
+use crate::domain::repository::{
+    Cursor, Paginated, Transaction, TransactionManager,
+};
+use crate::domain::user::{self, SessionRepo, User};
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R, S> {
+    pub repo: R,
+    pub sessions: S,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(transparent)]
+    Unauthorized { source: Unauthorized },
+    #[snafu(display("User #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    UserNotFound { id: i32 },
+    #[snafu(display(
+        "Admins can't suspend themselves or revoke their own roles"
+    ))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    OwnAccount,
+    #[snafu(display("Suspension must end in the future"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    AlreadyEnded,
+    #[snafu(display("User #{id} is not suspended"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotSuspended { id: i32 },
+    #[snafu(display("Role #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    RoleNotFound { id: i32 },
+    #[snafu(display("Role {name} already exists"))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    RoleExists { name: String },
+    #[snafu(display("The permissions of the Admin role can't be changed"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    AdminRole,
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<R, S> Service<R, S>
+where
+    R: Repo + user::Repository + TransactionManager,
+    R::TransactionRepository: Repo,
+    S: SessionRepo,
+{
+    pub async fn find_roles(&self, admin: &User) -> Result<Vec<Role>, Error> {
+        authorize(admin, Permission::ManageUser)?;
+
+        Ok(self.repo.find_roles().await?)
+    }
+
+    pub async fn create_role(
+        &self,
+        admin: &User,
+        role: NewRole,
+    ) -> Result<Role, Error> {
+        authorize(admin, Permission::ManageUser)?;
+
+        if self.repo.role_name_exists(&role.name).await? {
+            return Err(Error::RoleExists { name: role.name });
+        }
+
+        let tx_repo = self.repo.begin().await?;
+
+        let role = tx_repo.create_role(role).await?;
+
+        tx_repo
+            .create_audit_log(NewAuditLogEntry {
+                actor_id: admin.id,
+                action: AuditAction::CreateRole,
+                target_user_id: None,
+                data: json!({ "role_id": role.id, "name": role.name }),
+            })
+            .await?;
+
+        tx_repo.commit().await?;
+
+        Ok(role)
+    }
+
+    pub async fn grant_permission(
+        &self,
+        admin: &User,
+        role_id: i32,
+        grant: PermissionGrant,
+    ) -> Result<(), Error> {
+        self.check_role(admin, role_id).await?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        if tx_repo.grant_permission(role_id, grant).await? {
+            tx_repo
+                .create_audit_log(NewAuditLogEntry {
+                    actor_id: admin.id,
+                    action: AuditAction::GrantPermission,
+                    target_user_id: None,
+                    data: json!({ "role_id": role_id, "grant": grant }),
+                })
+                .await?;
+        }
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_permission(
+        &self,
+        admin: &User,
+        role_id: i32,
+        grant: PermissionGrant,
+    ) -> Result<(), Error> {
+        self.check_role(admin, role_id).await?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        if tx_repo.revoke_permission(role_id, grant).await? {
+            tx_repo
+                .create_audit_log(NewAuditLogEntry {
+                    actor_id: admin.id,
+                    action: AuditAction::RevokePermission,
+                    target_user_id: None,
+                    data: json!({ "role_id": role_id, "grant": grant }),
+                })
+                .await?;
+        }
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn grant_role(
+        &self,
+        admin: &User,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<(), Error> {
+        self.check_target(admin, user_id).await?;
+        check_admin_role(admin, role_id)?;
+
+        let role = self.find_role(role_id).await?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        if tx_repo.grant_role(user_id, role.id).await? {
+            tx_repo
+                .create_audit_log(NewAuditLogEntry {
+                    actor_id: admin.id,
+                    action: AuditAction::GrantRole,
+                    target_user_id: Some(user_id),
+                    data: json!({ "role_id": role.id, "role": role.name }),
+                })
+                .await?;
+        }
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_role(
+        &self,
+        admin: &User,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<(), Error> {
+        if user_id == admin.id {
+            return Err(Error::OwnAccount);
+        }
+
+        self.check_target(admin, user_id).await?;
+        check_admin_role(admin, role_id)?;
+
+        let role = self.find_role(role_id).await?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        if tx_repo.revoke_role(user_id, role.id).await? {
+            tx_repo
+                .create_audit_log(NewAuditLogEntry {
+                    actor_id: admin.id,
+                    action: AuditAction::RevokeRole,
+                    target_user_id: Some(user_id),
+                    data: json!({ "role_id": role.id, "role": role.name }),
+                })
+                .await?;
+        }
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    /// Signs the user out everywhere. A new suspension replaces the current
+    /// one
+    pub async fn suspend(
+        &self,
+        admin: &User,
+        user_id: i32,
+        suspension: NewUserSuspension,
+    ) -> Result<UserSuspension, Error> {
+        if user_id == admin.id {
+            return Err(Error::OwnAccount);
+        }
+
+        if suspension.suspended_until <= Utc::now() {
+            return Err(Error::AlreadyEnded);
+        }
+
+        self.check_target(admin, user_id).await?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        if let Some(current) = tx_repo.find_active_suspension(user_id).await? {
+            tx_repo.lift_suspension(current.id).await?;
+        }
+
+        let data = json!({
+            "reason": suspension.reason,
+            "suspended_until": suspension.suspended_until,
+        });
+
+        let suspension = tx_repo
+            .create_suspension(user_id, admin.id, suspension)
+            .await?;
+
+        tx_repo
+            .create_audit_log(NewAuditLogEntry {
+                actor_id: admin.id,
+                action: AuditAction::SuspendUser,
+                target_user_id: Some(user_id),
+                data,
+            })
+            .await?;
+
+        tx_repo.commit().await?;
+
+        self.sessions.revoke_sessions(user_id).await?;
+
+        Ok(suspension)
+    }
+
+    pub async fn lift_suspension(
+        &self,
+        admin: &User,
+        user_id: i32,
+    ) -> Result<(), Error> {
+        authorize(admin, Permission::ManageUser)?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        let suspension = tx_repo
+            .find_active_suspension(user_id)
+            .await?
+            .ok_or(Error::NotSuspended { id: user_id })?;
+
+        tx_repo.lift_suspension(suspension.id).await?;
+
+        tx_repo
+            .create_audit_log(NewAuditLogEntry {
+                actor_id: admin.id,
+                action: AuditAction::LiftSuspension,
+                target_user_id: Some(user_id),
+                data: json!({ "suspension_id": suspension.id }),
+            })
+            .await?;
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn find_audit_log(
+        &self,
+        admin: &User,
+        filter: AuditLogFilter,
+        pagination: Cursor,
+    ) -> Result<Paginated<AuditLogEntry>, Error> {
+        authorize(admin, Permission::ManageUser)?;
+
+        Ok(self.repo.find_audit_log(filter, pagination).await?)
+    }
+
+    async fn check_target(
+        &self,
+        admin: &User,
+        user_id: i32,
+    ) -> Result<(), Error> {
+        authorize(admin, Permission::ManageUser)?;
+
+        if self.repo.find_by_id(user_id).await?.is_none() {
+            return Err(Error::UserNotFound { id: user_id });
+        }
+
+        Ok(())
+    }
+
+    async fn find_role(&self, id: i32) -> Result<Role, Error> {
+        self.repo
+            .find_role(id)
+            .await?
+            .ok_or(Error::RoleNotFound { id })
+    }
+
+    /// Admins could lock themselves out by changing their own role
+    async fn check_role(
+        &self,
+        admin: &User,
+        role_id: i32,
+    ) -> Result<(), Error> {
+        authorize(admin, Permission::ManageUser)?;
+
+        if role_id == i32::from(UserRoleEnum::Admin) {
+            return Err(Error::AdminRole);
+        }
+
+        self.find_role(role_id).await.map(|_| ())
+    }
+}
+
+/// `ManageUser` can be granted to other roles, but only admins can hand out
+/// or take away the Admin role
+fn check_admin_role(admin: &User, role_id: i32) -> Result<(), Unauthorized> {
+    let admin_role = i32::from(UserRoleEnum::Admin);
+
+    if role_id == admin_role
+        && !admin.roles.iter().any(|role| role.id == admin_role)
+    {
+        return Err(Unauthorized::new());
+    }
+
+    Ok(())
+}
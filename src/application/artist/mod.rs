@@ -1,11 +1,15 @@
-use entity::enums::CorrectionStatus;
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{CorrectionStatus, EntityType};
 use eros::{IntoUnionResult, ReshapeUnionResult};
 
+use super::correction::{InvalidReferences, PossibleDuplicates, Protected};
 use super::error::Unauthorized;
 use crate::domain::artist;
-use crate::domain::artist::model::{NewArtist, ValidationError};
+use crate::domain::artist::model::{Artist, NewArtist, ValidationError};
 use crate::domain::correction::{
-    NewCorrection, NewCorrectionMeta, {self},
+    CorrectionPolicy, NewCorrection, NewCorrectionMeta, SourceError, {self},
 };
 use crate::domain::repository::TransactionManager;
 use crate::infra;
@@ -13,6 +17,29 @@ use crate::infra;
 #[derive(Clone)]
 pub struct Service<A> {
     pub conn: A,
+    pub correction_policy: Arc<CorrectionPolicy>,
+}
+
+impl<Conn> Service<Conn>
+where
+    Conn: artist::Repo + correction::Repo,
+{
+    /// The artist as it was at the moment, `None` if it wasn't created yet
+    pub async fn find_as_of(
+        &self,
+        id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<Artist>, infra::Error> {
+        let Some(history_id) = self
+            .conn
+            .find_history_id_at(EntityType::Artist, id, at)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.conn.find_history(id, history_id).await?)
+    }
 }
 
 impl<Conn, Repo> Service<Conn>
@@ -23,8 +50,21 @@ where
     pub async fn create(
         &self,
         correction: NewCorrection<NewArtist>,
-    ) -> eros::UnionResult<(), (infra::Error, ValidationError)> {
+    ) -> eros::UnionResult<
+        (),
+        (
+            infra::Error,
+            ValidationError,
+            InvalidReferences,
+            PossibleDuplicates,
+            SourceError,
+        ),
+    > {
         correction.data.validate().union()?;
+        self.correction_policy
+            .source
+            .validate(EntityType::Artist, &correction.sources)
+            .union()?;
 
         let tx_repo = self
             .conn
@@ -33,6 +73,18 @@ where
             .map_err(infra::Error::from)
             .union()?;
 
+        super::correction::check_references2(&tx_repo, &correction.data)
+            .await
+            .widen()?;
+        super::correction::check_duplicates2(
+            &tx_repo,
+            &self.correction_policy.duplicate,
+            &correction.data,
+            correction.acknowledge_duplicates,
+        )
+        .await
+        .widen()?;
+
         let entity_id = artist::TxRepo::create(&tx_repo, &correction.data)
             .await
             .map_err(infra::Error::from)
@@ -44,7 +96,10 @@ where
             .map_err(infra::Error::from)
             .union()?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create2(NewCorrectionMeta::<NewArtist> {
@@ -52,8 +107,11 @@ where
                 r#type: correction.r#type,
                 entity_id,
                 history_id,
+                base_history_id: None,
                 status: CorrectionStatus::Approved,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await
@@ -73,9 +131,22 @@ where
         &self,
         id: i32,
         correction: NewCorrection<NewArtist>,
-    ) -> eros::UnionResult<(), (infra::Error, ValidationError, Unauthorized)>
-    {
+    ) -> eros::UnionResult<
+        (),
+        (
+            infra::Error,
+            ValidationError,
+            Unauthorized,
+            InvalidReferences,
+            SourceError,
+            Protected,
+        ),
+    > {
         correction.data.validate().union()?;
+        self.correction_policy
+            .source
+            .validate(EntityType::Artist, &correction.sources)
+            .union()?;
 
         let tx_repo = self
             .conn
@@ -84,12 +155,19 @@ where
             .map_err(infra::Error::from)
             .union()?;
 
+        super::correction::check_references2(&tx_repo, &correction.data)
+            .await
+            .widen()?;
+
         let history_id = tx_repo
             .create_history(&correction.data)
             .await
             .map_err(infra::Error::from)
             .union()?;
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .upsert2(NewCorrectionMeta::<NewArtist> {
@@ -98,7 +176,10 @@ where
                 entity_id: id,
                 status: CorrectionStatus::Pending,
                 history_id,
+                base_history_id: correction.base_history_id,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await
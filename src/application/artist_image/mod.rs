@@ -17,6 +17,7 @@ use crate::domain::artist_image_queue::{
 use crate::domain::image::{
     AsyncFileStorage, CreateImageMeta, ParseOption, Parser,
 };
+use crate::domain::live::{self, LiveEvent};
 use crate::domain::repository::{Transaction, TransactionManager};
 use crate::domain::user::User;
 use crate::domain::{image, image_queue};
@@ -67,7 +68,8 @@ where
         + Transaction
         + image::TxRepo
         + image_queue::Repo
-        + artist_image_queue::Repository,
+        + artist_image_queue::Repository
+        + live::TxRepo,
     Storage: AsyncFileStorage + Clone,
 {
     /// Warn: Make sure inner transaction is wrapped in Arc
@@ -110,6 +112,10 @@ where
         artist_image_queue::Repository::create(&tx_repo, artist_image_queue)
             .await?;
 
+        tx_repo.publish(LiveEvent::ImageSubmitted {
+            queue_id: image_queue.id,
+        });
+
         drop(image_service);
 
         tx_repo.commit().await?;
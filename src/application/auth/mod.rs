@@ -1,22 +1,34 @@
 use std::backtrace::Backtrace;
+use std::sync::Arc;
 
 use axum::http::StatusCode;
 use axum_login::{AuthUser, AuthnBackend, UserId};
+use chrono::{DateTime, FixedOffset, Utc};
 use macros::{ApiError, IntoErrorSchema};
+use serde::Deserialize;
+use utoipa::ToSchema;
 
+use super::two_factor::verify_code;
+use crate::domain::login_throttle::{LoginThrottle, Throttled};
 use crate::domain::model::auth::{
     AuthCredential, AuthnError, ValidateCredsError,
 };
 use crate::domain::repository::{Transaction, TransactionManager};
+use crate::domain::two_factor::{
+    PendingSignIn, TwoFactorCode, TwoFactorPolicy, UserTotp, {self},
+};
 use crate::domain::user::{
     TxRepo, User, {self},
 };
+use crate::domain::{admin, login_throttle};
 use crate::infra;
 use crate::infra::error::Error;
 
 #[derive(Clone)]
-pub struct AuthService<R> {
+pub struct AuthService<R, T> {
     repo: R,
+    throttle: LoginThrottle<T>,
+    two_factor: Arc<TwoFactorPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -57,6 +69,41 @@ pub enum SignInError {
     Infra { source: infra::Error },
     #[snafu(transparent)]
     Validate { source: ValidateCredsError },
+    #[snafu(display("Suspended until {suspended_until}: {reason}"))]
+    #[api_error(
+        status_code = StatusCode::FORBIDDEN,
+    )]
+    Suspended {
+        suspended_until: DateTime<FixedOffset>,
+        reason: String,
+    },
+    #[snafu(display(
+        "Two-factor code required, submit it to /sign-in/two-factor"
+    ))]
+    #[api_error(
+        status_code = StatusCode::UNAUTHORIZED,
+    )]
+    TwoFactorRequired,
+    #[snafu(display("Invalid two-factor code"))]
+    #[api_error(
+        status_code = StatusCode::UNAUTHORIZED,
+    )]
+    InvalidTwoFactorCode,
+    #[snafu(display("Too many failed sign ins, try again at {retry_at}"))]
+    #[api_error(
+        status_code = StatusCode::TOO_MANY_REQUESTS,
+    )]
+    TooManyAttempts { retry_at: DateTime<Utc> },
+    #[snafu(display("Too many failed sign ins, locked until {until}"))]
+    #[api_error(
+        status_code = StatusCode::LOCKED,
+    )]
+    LockedOut { until: DateTime<Utc> },
+    #[snafu(display("Two-factor sign in expired, sign in again"))]
+    #[api_error(
+        status_code = StatusCode::UNAUTHORIZED,
+    )]
+    TwoFactorExpired,
 }
 
 impl SignInError {
@@ -74,6 +121,40 @@ where
     }
 }
 
+pub enum SignInOutcome {
+    Complete(User),
+    /// The password was right, the user still has to enter a code
+    TwoFactorRequired {
+        user_id: i32,
+        username: String,
+    },
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum ChangePasswordError {
+    #[snafu(transparent)]
+    Authn { source: AuthnError },
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(transparent)]
+    Validate { source: ValidateCredsError },
+}
+
+impl<E> From<E> for ChangePasswordError
+where
+    E: Into<infra::Error>,
+{
+    default fn from(err: E) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangePassword {
+    pub current_password: String,
+    pub new_password: String,
+}
+
 #[derive(Debug, snafu::Snafu, ApiError)]
 #[snafu(display("Session error: {source}"))]
 #[api_error(
@@ -94,11 +175,11 @@ impl SessionError {
     }
 }
 
-impl<R> From<axum_login::Error<AuthService<R>>> for SessionBackendError
+impl<R, T> From<axum_login::Error<AuthService<R, T>>> for SessionBackendError
 where
-    AuthService<R>: axum_login::AuthnBackend<Error = AuthnBackendError>,
+    AuthService<R, T>: axum_login::AuthnBackend<Error = AuthnBackendError>,
 {
-    fn from(value: axum_login::Error<AuthService<R>>) -> Self {
+    fn from(value: axum_login::Error<AuthService<R, T>>) -> Self {
         match value {
             axum_login::Error::Session(err) => Self::Session {
                 source: SessionError::new(err),
@@ -130,43 +211,118 @@ pub enum AuthnBackendError {
 
 pub trait AuthServiceTrait<R>: Send + Sync
 where
-    R: user::Repository,
+    R: user::Repository + admin::Repo + two_factor::Repo,
 {
-    async fn sign_in(&self, creds: AuthCredential)
-    -> Result<User, SignInError>;
+    /// Failed attempts are throttled by username and by `ip`
+    async fn sign_in(
+        &self,
+        creds: AuthCredential,
+        ip: Option<&str>,
+    ) -> Result<SignInOutcome, SignInError>;
+
+    /// For users verified by an identity provider, which replaces the
+    /// password but not the second factor
+    async fn sign_in_with_identity(
+        &self,
+        user: User,
+    ) -> Result<SignInOutcome, SignInError>;
+
+    /// The second step of signing in users with two-factor authentication,
+    /// wrong codes are throttled like wrong passwords
+    async fn sign_in_two_factor(
+        &self,
+        pending: &PendingSignIn,
+        code: TwoFactorCode,
+    ) -> Result<User, SignInError>;
 
     async fn sign_up(&self, creds: AuthCredential)
     -> Result<User, SignUpError>;
+
+    /// Returns the user with the new password
+    async fn change_password(
+        &self,
+        user: User,
+        change: ChangePassword,
+    ) -> Result<User, ChangePasswordError>;
 }
 
-impl<R> AuthService<R> {
-    pub const fn new(repo: R) -> Self {
-        Self { repo }
+impl<R, T> AuthService<R, T> {
+    pub const fn new(
+        repo: R,
+        throttle: LoginThrottle<T>,
+        two_factor: Arc<TwoFactorPolicy>,
+    ) -> Self {
+        Self {
+            repo,
+            throttle,
+            two_factor,
+        }
     }
 }
 
 trait AuthServiceTraitBounds<R> = where
-    R: TransactionManager + user::Repository,
+    R: TransactionManager + user::Repository + admin::Repo + two_factor::Repo,
     R::TransactionRepository: user::TxRepo;
 
-impl<R> AuthServiceTrait<R> for AuthService<R>
+impl<R, T> AuthServiceTrait<R> for AuthService<R, T>
 where
-    R: TransactionManager + user::Repository,
+    R: TransactionManager + user::Repository + admin::Repo + two_factor::Repo,
     R::TransactionRepository: user::TxRepo,
+    T: login_throttle::Repo + Sync,
 {
     async fn sign_in(
         &self,
         creds: AuthCredential,
-    ) -> Result<User, SignInError> {
+        ip: Option<&str>,
+    ) -> Result<SignInOutcome, SignInError> {
+        // Before the password hash is verified, that's the expensive part
+        self.check_throttle(&creds.username, ip).await?;
+
         let user = self.repo.find_by_name(&creds.username).await?;
 
-        creds
-            .verify_credentials(user.as_ref().map(|u| u.password.as_str()))
-            .await?;
+        if let Err(err) = creds
+            .verify_credentials(user.as_ref().and_then(User::password_hash))
+            .await
+        {
+            if matches!(err, AuthnError::AuthenticationFailed { .. }) {
+                self.record_failure(&creds.username, ip).await?;
+            }
 
-        Ok(user.ok_or_else(|| AuthnError::AuthenticationFailed {
+            return Err(err.into());
+        }
+
+        let user = user.ok_or_else(|| AuthnError::AuthenticationFailed {
             backtrace: std::backtrace::Backtrace::capture(),
-        })?)
+        })?;
+
+        self.finish_sign_in(user).await
+    }
+
+    async fn sign_in_with_identity(
+        &self,
+        user: User,
+    ) -> Result<SignInOutcome, SignInError> {
+        self.finish_sign_in(user).await
+    }
+
+    async fn sign_in_two_factor(
+        &self,
+        pending: &PendingSignIn,
+        code: TwoFactorCode,
+    ) -> Result<User, SignInError> {
+        self.check_suspension(pending.user_id).await?;
+
+        self.verify_second_factor(pending, &code).await?;
+
+        let user = self
+            .repo
+            .find_by_id(pending.user_id)
+            .await?
+            .ok_or(SignInError::TwoFactorExpired)?;
+
+        self.throttle.record_success(&pending.username).await?;
+
+        Ok(self.apply_two_factor_policy(user).await?)
     }
 
     async fn sign_up(
@@ -186,8 +342,172 @@ where
 
         tx_repo.commit().await?;
 
+        Ok(self.apply_two_factor_policy(user).await?)
+    }
+
+    async fn change_password(
+        &self,
+        mut user: User,
+        change: ChangePassword,
+    ) -> Result<User, ChangePasswordError> {
+        // Passwords that don't pass validation can't be the current one
+        AuthCredential::try_new(user.name.clone(), change.current_password)
+            .map_err(|_| AuthnError::authentication_failed())?
+            .verify_credentials(user.password_hash())
+            .await?;
+
+        let mut creds =
+            AuthCredential::try_new(user.name.clone(), change.new_password)?;
+
+        user.password =
+            creds.password_hash().map_err(AuthnError::from)?.to_owned();
+
+        let tx_repo = self.repo.begin().await?;
+
+        let user = tx_repo.update(user).await?;
+
+        tx_repo.commit().await?;
+
+        Ok(self.apply_two_factor_policy(user).await?)
+    }
+}
+
+impl<R, T> AuthService<R, T>
+where
+    R: admin::Repo + two_factor::Repo + Sync,
+    T: login_throttle::Repo + Sync,
+{
+    async fn finish_sign_in(
+        &self,
+        user: User,
+    ) -> Result<SignInOutcome, SignInError> {
+        self.check_suspension(user.id).await?;
+
+        if self
+            .repo
+            .find_totp(user.id)
+            .await?
+            .is_some_and(|totp| totp.is_enabled())
+        {
+            return Ok(SignInOutcome::TwoFactorRequired {
+                user_id: user.id,
+                username: user.name,
+            });
+        }
+
+        // Only once nothing is left to guess
+        self.throttle.record_success(&user.name).await?;
+
+        Ok(SignInOutcome::Complete(
+            self.apply_two_factor_policy(user).await?,
+        ))
+    }
+
+    /// Withholds the permissions that require two-factor authentication
+    /// from users who haven't enabled it
+    async fn apply_two_factor_policy(
+        &self,
+        mut user: User,
+    ) -> Result<User, Error> {
+        if self.two_factor.required_for.is_empty() {
+            return Ok(user);
+        }
+
+        let enabled = self
+            .repo
+            .find_totp(user.id)
+            .await?
+            .is_some_and(|totp| totp.is_enabled());
+
+        user.permissions = self
+            .two_factor
+            .restrict(std::mem::take(&mut user.permissions), enabled);
+
         Ok(user)
     }
+
+    async fn check_suspension(&self, user_id: i32) -> Result<(), SignInError> {
+        if let Some(suspension) =
+            self.repo.find_active_suspension(user_id).await?
+        {
+            return Err(SignInError::Suspended {
+                suspended_until: suspension.suspended_until,
+                reason: suspension.reason,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<R, T> AuthService<R, T>
+where
+    R: two_factor::Repo + Sync,
+    T: login_throttle::Repo + Sync,
+{
+    async fn check_throttle(
+        &self,
+        username: &str,
+        ip: Option<&str>,
+    ) -> Result<(), SignInError> {
+        match self.throttle.check(username, ip).await? {
+            Some(Throttled::Backoff { retry_at }) => {
+                Err(SignInError::TooManyAttempts { retry_at })
+            }
+            Some(Throttled::LockedOut { until }) => {
+                Err(SignInError::LockedOut { until })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Wrong codes count as failed sign ins of the username and ip that
+    /// passed the first step
+    async fn verify_second_factor(
+        &self,
+        pending: &PendingSignIn,
+        code: &TwoFactorCode,
+    ) -> Result<(), SignInError> {
+        let ip = pending.ip.as_deref();
+
+        self.check_throttle(&pending.username, ip).await?;
+
+        let totp = self
+            .repo
+            .find_totp(pending.user_id)
+            .await?
+            .filter(UserTotp::is_enabled)
+            .ok_or(SignInError::TwoFactorExpired)?;
+
+        if !verify_code(&self.repo, &totp, code).await? {
+            self.record_failure(&pending.username, ip).await?;
+
+            return Err(SignInError::InvalidTwoFactorCode);
+        }
+
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        username: &str,
+        ip: Option<&str>,
+    ) -> Result<(), SignInError> {
+        tracing::warn!(
+            target: "security",
+            "Failed sign in as {username} from {}",
+            ip.unwrap_or("unknown ip")
+        );
+
+        for key in self.throttle.record_failure(username, ip).await? {
+            tracing::warn!(
+                target: "security",
+                "Sign ins of {key} are locked out after too many failures"
+            );
+        }
+
+        Ok(())
+    }
 }
 
 impl AuthUser for user::User {
@@ -201,10 +521,11 @@ impl AuthUser for user::User {
     }
 }
 
-impl<R> AuthnBackend for AuthService<R>
+impl<R, T> AuthnBackend for AuthService<R, T>
 where
     Self: AuthServiceTraitBounds<R>,
-    R: Clone + user::Repository,
+    R: Clone + user::Repository + admin::Repo + two_factor::Repo,
+    T: Clone + login_throttle::Repo + Sync + 'static,
 {
     type User = user::User;
     type Credentials = AuthCredential;
@@ -214,17 +535,209 @@ where
         &self,
         creds: Self::Credentials,
     ) -> Result<Option<Self::User>, Self::Error> {
-        let user = self.sign_in(creds).await?;
-        Ok(Some(user))
+        // Users with two-factor authentication sign in through
+        // `sign_in_two_factor`
+        // Only throttled by username, the ip isn't known here
+        match self.sign_in(creds, None).await? {
+            SignInOutcome::Complete(user) => Ok(Some(user)),
+            SignInOutcome::TwoFactorRequired { .. } => Ok(None),
+        }
     }
 
+    /// Suspended users are signed out
     async fn get_user(
         &self,
         user_id: &UserId<Self>,
     ) -> Result<Option<Self::User>, Self::Error> {
-        self.repo
-            .find_by_id(*user_id)
+        let suspension = self
+            .repo
+            .find_active_suspension(*user_id)
             .await
-            .map_err(|e| Error::from(e).into())
+            .map_err(Error::from)?;
+
+        if suspension.is_some() {
+            return Ok(None);
+        }
+
+        let Some(user) =
+            self.repo.find_by_id(*user_id).await.map_err(Error::from)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(self.apply_two_factor_policy(user).await?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use chrono::TimeDelta;
+
+    use super::*;
+    use crate::domain::login_throttle::{
+        FailedLogins, LoginThrottleConfig, LoginThrottlePolicy, SystemClock,
+        ThrottleKey,
+    };
+    use crate::domain::repository::Connection;
+    use crate::domain::two_factor::TotpSecret;
+
+    type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+    /// Every user has two-factor authentication, no code is right
+    struct TotpRepo;
+
+    impl Connection for TotpRepo {
+        type Conn = Self;
+
+        fn conn(&self) -> &Self::Conn {
+            self
+        }
+    }
+
+    impl two_factor::Repo for TotpRepo {
+        fn find_totp(
+            &self,
+            user_id: i32,
+        ) -> impl Future<Output = Result<Option<UserTotp>, BoxError>> {
+            std::future::ready(Ok(Some(UserTotp {
+                user_id,
+                secret: TotpSecret::generate(),
+                enabled_at: Some(Utc::now().into()),
+                last_used_step: None,
+            })))
+        }
+
+        fn save_pending_totp(
+            &self,
+            _: i32,
+            _: &TotpSecret,
+        ) -> impl Future<Output = Result<(), BoxError>> {
+            std::future::ready(Ok(()))
+        }
+
+        fn enable_totp(
+            &self,
+            _: i32,
+            _: u64,
+        ) -> impl Future<Output = Result<(), BoxError>> {
+            std::future::ready(Ok(()))
+        }
+
+        fn use_totp_step(
+            &self,
+            _: i32,
+            _: u64,
+        ) -> impl Future<Output = Result<bool, BoxError>> {
+            std::future::ready(Ok(false))
+        }
+
+        fn delete_totp(
+            &self,
+            _: i32,
+        ) -> impl Future<Output = Result<(), BoxError>> {
+            std::future::ready(Ok(()))
+        }
+
+        fn replace_recovery_codes(
+            &self,
+            _: i32,
+            _: Vec<String>,
+        ) -> impl Future<Output = Result<(), BoxError>> {
+            std::future::ready(Ok(()))
+        }
+
+        fn use_recovery_code(
+            &self,
+            _: i32,
+            _: &str,
+        ) -> impl Future<Output = Result<bool, BoxError>> {
+            std::future::ready(Ok(false))
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryRepo(Mutex<HashMap<String, FailedLogins>>);
+
+    impl login_throttle::Repo for MemoryRepo {
+        fn find_failures(
+            &self,
+            key: &ThrottleKey,
+        ) -> impl Future<Output = Result<Option<FailedLogins>, BoxError>>
+        {
+            std::future::ready(Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .get(&key.to_string())
+                .copied()))
+        }
+
+        fn save_failures(
+            &self,
+            key: &ThrottleKey,
+            failures: FailedLogins,
+            _: TimeDelta,
+        ) -> impl Future<Output = Result<(), BoxError>> {
+            self.0.lock().unwrap().insert(key.to_string(), failures);
+
+            std::future::ready(Ok(()))
+        }
+
+        fn clear_failures(
+            &self,
+            key: &ThrottleKey,
+        ) -> impl Future<Output = Result<(), BoxError>> {
+            self.0.lock().unwrap().remove(&key.to_string());
+
+            std::future::ready(Ok(()))
+        }
+    }
+
+    const POLICY: LoginThrottlePolicy = LoginThrottlePolicy {
+        free_attempts: 0,
+        base_delay_seconds: 0,
+        max_delay_seconds: 0,
+        lockout_threshold: 3,
+        lockout_minutes: 15,
+    };
+
+    #[tokio::test]
+    async fn wrong_codes_lock_out() {
+        let service = AuthService::new(
+            TotpRepo,
+            LoginThrottle::new(
+                MemoryRepo::default(),
+                SystemClock,
+                LoginThrottleConfig {
+                    username: POLICY,
+                    ip: POLICY,
+                },
+            ),
+            Arc::default(),
+        );
+        let pending = PendingSignIn::new(
+            1,
+            "alice".to_owned(),
+            Some("::1".to_owned()),
+            Utc::now(),
+        );
+        let code = TwoFactorCode {
+            code: "wrong-code".to_owned(),
+        };
+
+        for _ in 0..POLICY.lockout_threshold {
+            assert!(matches!(
+                service.verify_second_factor(&pending, &code).await,
+                Err(SignInError::InvalidTwoFactorCode)
+            ));
+        }
+
+        assert!(matches!(
+            service.verify_second_factor(&pending, &code).await,
+            Err(SignInError::LockedOut { .. })
+        ));
     }
 }
@@ -0,0 +1,31 @@
+//! Permission checks of the services. Permissions are granted to roles in
+//! the database, so services never check roles themselves
+
+use entity::enums::EntityType;
+
+use super::error::Unauthorized;
+use crate::domain::model::auth::Permission;
+use crate::domain::user::User;
+
+pub fn authorize(
+    user: &User,
+    permission: Permission,
+) -> Result<(), Unauthorized> {
+    if user.can(permission) {
+        Ok(())
+    } else {
+        Err(Unauthorized::new())
+    }
+}
+
+pub fn authorize_for(
+    user: &User,
+    permission: Permission,
+    entity_type: EntityType,
+) -> Result<(), Unauthorized> {
+    if user.can_for(permission, entity_type) {
+        Ok(())
+    } else {
+        Err(Unauthorized::new())
+    }
+}
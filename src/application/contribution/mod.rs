@@ -0,0 +1,79 @@
+use chrono::{TimeDelta, Utc};
+
+use crate::domain::contribution::{
+    Cache, ContributorStats, LeaderboardEntry, LeaderboardWindow, Repo,
+};
+use crate::infra::error::Error;
+
+/// Stats lag behind by up to this long
+const STATS_TTL: TimeDelta = TimeDelta::minutes(10);
+
+const LEADERBOARD_TTL: TimeDelta = TimeDelta::minutes(15);
+
+const LEADERBOARD_SIZE: u64 = 50;
+
+#[derive(Clone)]
+pub struct Service<R, C> {
+    pub repo: R,
+    pub cache: C,
+}
+
+impl<R, C> Service<R, C>
+where
+    R: Repo + Sync,
+    C: Cache + Sync,
+{
+    /// Falls back to the database if the cache is unavailable
+    pub async fn stats(&self, user_id: i32) -> Result<ContributorStats, Error> {
+        match self.cache.find_stats(user_id).await {
+            Ok(Some(stats)) => return Ok(stats),
+            Ok(None) => {}
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to read cached contributor stats: {err}"
+                );
+            }
+        }
+
+        let stats = ContributorStats::tally(
+            self.repo.find_correction_tallies(user_id).await?,
+            self.repo.find_image_tally(user_id).await?,
+        );
+
+        if let Err(err) =
+            self.cache.save_stats(user_id, &stats, STATS_TTL).await
+        {
+            tracing::warn!("Failed to cache contributor stats: {err}");
+        }
+
+        Ok(stats)
+    }
+
+    pub async fn leaderboard(
+        &self,
+        window: LeaderboardWindow,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        match self.cache.find_leaderboard(window).await {
+            Ok(Some(entries)) => return Ok(entries),
+            Ok(None) => {}
+            Err(err) => {
+                tracing::warn!("Failed to read cached leaderboard: {err}");
+            }
+        }
+
+        let entries = self
+            .repo
+            .find_leaderboard(window.since(Utc::now()), LEADERBOARD_SIZE)
+            .await?;
+
+        if let Err(err) = self
+            .cache
+            .save_leaderboard(window, &entries, LEADERBOARD_TTL)
+            .await
+        {
+            tracing::warn!("Failed to cache leaderboard: {err}");
+        }
+
+        Ok(entries)
+    }
+}
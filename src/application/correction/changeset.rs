@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use entity::enums::{CorrectionStatus, CorrectionType, EntityType};
+use garde::Validate;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::{
+    Error, InvalidReferences, Service, approve_unit, check_duplicates,
+    find_reference_problems,
+};
+use crate::domain::artist::model::NewArtist;
+use crate::domain::correction::{
+    self, Changeset, ChangesetError, ChangesetRepo, CorrectionEntity,
+    CorrectionEntityRepo, CorrectionPolicy, CorrectionSource, NewChangeset,
+    NewCorrectionMeta, ReferenceProblem, creation_order, resolve_temp_ids,
+};
+use crate::domain::credit_role::NewCreditRole;
+use crate::domain::event::NewEvent;
+use crate::domain::label::NewLabel;
+use crate::domain::live::LiveEvent;
+use crate::domain::notification;
+use crate::domain::release::NewRelease;
+use crate::domain::repository::{Transaction, TransactionManager};
+use crate::domain::song::model::NewSong;
+use crate::domain::song_lyrics::NewSongLyrics;
+use crate::domain::tag::NewTag;
+use crate::domain::user::User;
+use crate::infra::error::Error as InfraError;
+
+impl<R, TR> Service<R>
+where
+    R: TransactionManager<TransactionRepository = TR>,
+    TR: ChangesetRepo + notification::Repo + Transaction,
+{
+    /// Creates the entities of the changeset in dependency order, each with
+    /// its own correction. The corrections are approved together, either
+    /// right away by the auto approval policy or later by review
+    pub async fn create_changeset(
+        &self,
+        author: User,
+        changeset: NewChangeset,
+    ) -> Result<Changeset, Error> {
+        let order = creation_order(&changeset.entities)?;
+        let mut entities =
+            changeset.entities.into_iter().map(Some).collect::<Vec<_>>();
+
+        let tx_repo = self.repo.begin().await?;
+
+        let mut ids = HashMap::new();
+        let mut corrections = Vec::with_capacity(order.len());
+
+        for entity in order.into_iter().filter_map(|idx| entities[idx].take()) {
+            let mut data = entity.data;
+            resolve_temp_ids(&mut data, &ids);
+
+            let new = NewEntity {
+                repo: &tx_repo,
+                policy: &self.policy,
+                temp_id: &entity.temp_id,
+                author: &author,
+                description: &changeset.description,
+                acknowledge_duplicates: changeset.acknowledge_duplicates,
+                sources: &changeset.sources,
+            };
+
+            let (entity_id, correction_id) = match entity.entity_type {
+                EntityType::Artist => {
+                    let data: NewArtist = new.parse(data)?;
+                    data.validate().map_err(|err| new.invalid(err))?;
+                    new.create(&data).await?
+                }
+                EntityType::Label => {
+                    new.create::<NewLabel>(&new.parse(data)?).await?
+                }
+                EntityType::Release => {
+                    let data: NewRelease = new.parse(data)?;
+                    data.validate().map_err(|err| new.invalid(err))?;
+                    new.create(&data).await?
+                }
+                EntityType::Song => {
+                    new.create::<NewSong>(&new.parse(data)?).await?
+                }
+                EntityType::Tag => {
+                    new.create::<NewTag>(&new.parse(data)?).await?
+                }
+                EntityType::Event => {
+                    new.create::<NewEvent>(&new.parse(data)?).await?
+                }
+                EntityType::SongLyrics => {
+                    let data: NewSongLyrics = new.parse(data)?;
+                    data.validate().map_err(|err| new.invalid(err))?;
+                    new.create(&data).await?
+                }
+                EntityType::CreditRole => {
+                    new.create::<NewCreditRole>(&new.parse(data)?).await?
+                }
+            };
+
+            ids.insert(entity.temp_id.clone(), entity_id);
+            corrections.push((entity.temp_id, correction_id));
+        }
+
+        let changeset_id = tx_repo.create_changeset(&corrections).await?;
+
+        let service = Service::new(tx_repo, self.policy.clone());
+
+        let unit = find_changeset(&service.repo, changeset_id)
+            .await?
+            .entities
+            .into_iter()
+            .map(|entity| entity.correction)
+            .collect::<Vec<_>>();
+
+        // The whole changeset is approved only if every entity of it would be
+        let mut approver = None;
+
+        for correction in &unit {
+            approver = service
+                .auto_approver(
+                    &author,
+                    correction.entity_type,
+                    correction.entity_id,
+                )
+                .await?;
+
+            if approver.is_none() {
+                break;
+            }
+        }
+
+        if let Some(approver) = approver {
+            approve_unit(&service.repo, &unit, &approver).await?;
+        } else {
+            for correction in &unit {
+                service.repo.publish(LiveEvent::CorrectionPending {
+                    correction_id: correction.id,
+                    entity_type: correction.entity_type,
+                    entity_id: correction.entity_id,
+                });
+            }
+        }
+
+        let changeset = find_changeset(&service.repo, changeset_id).await?;
+
+        service.repo.commit().await?;
+
+        Ok(changeset)
+    }
+}
+
+async fn find_changeset(
+    repo: &impl correction::Repo,
+    id: i32,
+) -> Result<Changeset, InfraError> {
+    repo.find_changeset(id).await?.ok_or_else(|| {
+        InfraError::custom(&format!("Changeset #{id} not found after creation"))
+    })
+}
+
+/// Creates an entity of a changeset along with its correction
+struct NewEntity<'a, R> {
+    repo: &'a R,
+    policy: &'a CorrectionPolicy,
+    temp_id: &'a str,
+    author: &'a User,
+    description: &'a str,
+    acknowledge_duplicates: bool,
+    sources: &'a [CorrectionSource],
+}
+
+impl<R> NewEntity<'_, R>
+where
+    R: correction::TxRepo,
+{
+    fn parse<T: DeserializeOwned>(
+        &self,
+        data: Value,
+    ) -> Result<T, ChangesetError> {
+        serde_json::from_value(data).map_err(|err| self.invalid(err))
+    }
+
+    fn invalid(&self, err: impl Display) -> ChangesetError {
+        ChangesetError::InvalidEntity {
+            temp_id: self.temp_id.to_owned(),
+            message: err.to_string(),
+        }
+    }
+
+    /// Returns the id of the entity and the id of its correction
+    async fn create<T>(&self, data: &T) -> Result<(i32, i32), Error>
+    where
+        T: CorrectionEntity + Sync,
+        R: CorrectionEntityRepo<T>,
+    {
+        let problems = find_reference_problems(self.repo, data).await?;
+
+        if !problems.is_empty() {
+            return Err(InvalidReferences {
+                problems: problems
+                    .into_iter()
+                    .map(|problem| ReferenceProblem {
+                        path: format!("{}.{}", self.temp_id, problem.path),
+                        ..problem
+                    })
+                    .collect(),
+            }
+            .into());
+        }
+
+        check_duplicates(
+            self.repo,
+            &self.policy.duplicate,
+            data,
+            self.acknowledge_duplicates,
+        )
+        .await?;
+        self.policy
+            .source
+            .validate(T::entity_type(), self.sources)?;
+
+        let entity_id = CorrectionEntityRepo::create(self.repo, data).await?;
+        let history_id = self.repo.create_history(data).await?;
+
+        let correction_id = correction::TxRepo::create(
+            self.repo,
+            NewCorrectionMeta::<T> {
+                author: self.author.clone(),
+                r#type: CorrectionType::Create,
+                entity_id,
+                history_id,
+                base_history_id: None,
+                status: CorrectionStatus::Pending,
+                description: self.description.to_owned(),
+                sources: self.sources.to_vec(),
+                draft_id: None,
+                phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
+
+        Ok((entity_id, correction_id))
+    }
+}
@@ -0,0 +1,113 @@
+use axum::http::StatusCode;
+use chrono::Utc;
+use macros::{ApiError, IntoErrorSchema};
+
+use super::{Service, UntypedCorrection};
+use crate::domain::correction::{
+    CorrectionDraft, DraftRepo, NewCorrectionDraft,
+};
+use crate::domain::user::User;
+use crate::infra;
+use crate::infra::error::Error as InfraError;
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum DraftError {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(display("Draft #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: i32 },
+    #[snafu(display("Draft #{id} is incomplete: {message}"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    Incomplete { id: i32, message: String },
+}
+
+impl<A> From<A> for DraftError
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<R> Service<R>
+where
+    R: DraftRepo,
+{
+    pub async fn create_draft(
+        &self,
+        author: &User,
+        draft: NewCorrectionDraft,
+    ) -> Result<CorrectionDraft, InfraError> {
+        let expires_at = self.policy.draft.expires_at(Utc::now().into());
+
+        Ok(self.repo.create_draft(author.id, draft, expires_at).await?)
+    }
+
+    /// Replaces the content of the draft and postpones its expiry
+    pub async fn update_draft(
+        &self,
+        author: &User,
+        id: i32,
+        draft: NewCorrectionDraft,
+    ) -> Result<CorrectionDraft, DraftError> {
+        self.find_draft(author, id).await?;
+
+        let expires_at = self.policy.draft.expires_at(Utc::now().into());
+
+        Ok(self.repo.update_draft(id, draft, expires_at).await?)
+    }
+
+    /// Drafts are only visible to their author
+    pub async fn find_draft(
+        &self,
+        author: &User,
+        id: i32,
+    ) -> Result<CorrectionDraft, DraftError> {
+        self.repo
+            .find_draft(id)
+            .await?
+            .filter(|draft| draft.author_id == author.id)
+            .ok_or(DraftError::NotFound { id })
+    }
+
+    pub async fn find_drafts(
+        &self,
+        author: &User,
+    ) -> Result<Vec<CorrectionDraft>, InfraError> {
+        Ok(self.repo.find_drafts_by_author(author.id).await?)
+    }
+
+    pub async fn delete_draft(
+        &self,
+        author: &User,
+        id: i32,
+    ) -> Result<(), DraftError> {
+        self.find_draft(author, id).await?;
+        self.repo.delete_draft(id).await?;
+
+        Ok(())
+    }
+}
+
+/// The correction the draft is submitted as
+pub fn draft_correction(
+    draft: CorrectionDraft,
+    acknowledge_duplicates: bool,
+) -> UntypedCorrection {
+    UntypedCorrection {
+        entity_type: draft.entity_type,
+        entity_id: draft.entity_id,
+        base_history_id: draft.base_history_id,
+        description: draft.description,
+        data: draft.data,
+        acknowledge_duplicates,
+        sources: draft.sources,
+        draft_id: Some(draft.id),
+    }
+}
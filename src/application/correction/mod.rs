@@ -1,19 +1,40 @@
-use entity::enums::CorrectionStatus;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use entity::enums::{CorrectionStatus, CorrectionUserType, EntityType};
 use eros::IntoUnionResult;
 use macros::{ApiError, IntoErrorSchema};
+use serde::de::DeserializeOwned;
 
 use crate::domain::correction::{
-    self, ApproveCorrectionContext, CorrectionEntity, CorrectionFilter,
-    NewCorrectionMeta,
+    self, ChangesetError, Correction, CorrectionConflict, CorrectionDetail,
+    CorrectionEntity, CorrectionFilter, CorrectionPolicy, DuplicateCandidate,
+    DuplicatePolicy, EntityProtection, NewCorrectionMeta, NewCorrectionVote,
+    ReferenceProblem, ReviewPolicy, SourceError, find_problems, group_ids,
+    three_way_diff,
 };
-use crate::domain::model::auth::{CorrectionApprover, UserRoleEnum};
+use crate::domain::credit_role::{self, NewCreditRole};
+use crate::domain::live::LiveEvent;
+use crate::domain::model::auth::{CorrectionApprover, Permission};
+use crate::domain::notification::{self, NewNotification, NotificationEvent};
 use crate::domain::repository::{Transaction, TransactionManager};
-use crate::domain::user::User;
+use crate::domain::tag::{self, NewTag};
+use crate::domain::user::{self, User};
+use crate::domain::webhook::WebhookEvent;
 use crate::infra;
 use crate::infra::error::Error as InfraError;
+use crate::presentation::api_response::{ErrorWithData, IntoApiResponse};
 
+mod changeset;
+mod draft;
 mod model;
+mod patch;
+mod protection;
+pub use draft::{DraftError, draft_correction};
 pub use model::*;
+pub use patch::{NewPatchCorrection, PatchError};
+pub use protection::ProtectionError;
 
 use super::error::Unauthorized;
 
@@ -23,6 +44,64 @@ pub enum Error {
     Infra { source: infra::Error },
     #[snafu(transparent)]
     Unauthorized { source: Unauthorized },
+    #[snafu(display("Correction #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: i32 },
+    #[snafu(display("Correction #{id} is not pending"))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    NotPending { id: i32 },
+    #[snafu(display(
+        "Correction #{id} changes a high impact entity and must be approved by review votes"
+    ))]
+    #[api_error(
+        status_code = StatusCode::FORBIDDEN,
+    )]
+    ReviewRequired { id: i32 },
+    #[snafu(transparent)]
+    Stale { source: StaleCorrection },
+    #[snafu(transparent)]
+    Changeset { source: ChangesetError },
+    #[snafu(transparent)]
+    InvalidReferences { source: InvalidReferences },
+    #[snafu(transparent)]
+    PossibleDuplicates { source: PossibleDuplicates },
+    #[snafu(transparent)]
+    Sources { source: SourceError },
+    #[snafu(transparent)]
+    Protected { source: Protected },
+    #[snafu(display("Authors can't vote on their own correction"))]
+    #[api_error(
+        status_code = StatusCode::FORBIDDEN,
+    )]
+    OwnCorrection,
+    #[snafu(display("Authors can't add themselves as co-author"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    OwnCoAuthor,
+    #[snafu(display("User #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    UserNotFound { id: i32 },
+    #[snafu(display(
+        "Correction #{id} relates the tag to tag #{related_tag_id}, which would create a cycle"
+    ))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    CyclicTagRelation { id: i32, related_tag_id: i32 },
+    #[snafu(display(
+        "Correction #{id} makes the credit role inherit from credit role #{super_id}, which would create a cycle"
+    ))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    CyclicCreditRoleInheritance { id: i32, super_id: i32 },
 }
 
 impl<A> From<A> for Error
@@ -34,33 +113,242 @@ where
     }
 }
 
+#[derive(Debug, snafu::Snafu, ApiError)]
+#[api_error(
+    status_code = StatusCode::CONFLICT,
+)]
+#[snafu(display(
+    "The entity changed since correction #{} was edited, amend it against the current version",
+    conflict.correction_id
+))]
+pub struct StaleCorrection {
+    pub conflict: CorrectionConflict,
+}
+
+impl IntoApiResponse for StaleCorrection {
+    fn into_api_response(self) -> axum::response::Response {
+        use axum::response::IntoResponse;
+
+        ErrorWithData::from_api_error(&self, &self.conflict).into_response()
+    }
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[api_error(
+    status_code = StatusCode::FORBIDDEN,
+)]
+#[snafu(display(
+    "{:?} #{} is protected and can only be edited by moderators: {}",
+    protection.entity_type,
+    protection.entity_id,
+    protection.reason
+))]
+pub struct Protected {
+    pub protection: EntityProtection,
+}
+
+impl IntoApiResponse for Protected {
+    fn into_api_response(self) -> axum::response::Response {
+        use axum::response::IntoResponse;
+
+        ErrorWithData::from_api_error(&self, &self.protection).into_response()
+    }
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST,
+)]
+#[snafu(display("The correction references entities that don't exist"))]
+pub struct InvalidReferences {
+    pub problems: Vec<ReferenceProblem>,
+}
+
+impl IntoApiResponse for InvalidReferences {
+    fn into_api_response(self) -> axum::response::Response {
+        use axum::response::IntoResponse;
+
+        ErrorWithData::from_api_error(&self, &self.problems).into_response()
+    }
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[api_error(
+    status_code = StatusCode::CONFLICT,
+)]
+#[snafu(display(
+    "Similar entities already exist, resubmit with `acknowledge_duplicates` if it is none of them"
+))]
+pub struct PossibleDuplicates {
+    pub candidates: Vec<DuplicateCandidate>,
+}
+
+impl IntoApiResponse for PossibleDuplicates {
+    fn into_api_response(self) -> axum::response::Response {
+        use axum::response::IntoResponse;
+
+        ErrorWithData::from_api_error(&self, &self.candidates).into_response()
+    }
+}
+
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub policy: Arc<CorrectionPolicy>,
+}
+
+impl<R> Service<R> {
+    pub const fn new(repo: R, policy: Arc<CorrectionPolicy>) -> Self {
+        Self { repo, policy }
+    }
 }
 
 impl<R> Service<R>
 where
-    R: correction::TxRepo,
+    R: correction::Repo,
 {
-    pub const fn new(repo: R) -> Self {
-        Self { repo }
+    pub async fn find_detail(
+        &self,
+        id: i32,
+    ) -> Result<Option<CorrectionDetail>, InfraError> {
+        let Some(correction) = self.repo.find_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(CorrectionDetail {
+            correction,
+            users: self.repo.find_users(id).await?,
+            votes: self.repo.find_votes(id).await?,
+            revisions: self.repo.find_revisions(id).await?,
+        }))
     }
+}
 
-    // TODO: Add self approval
+impl<R> Service<R>
+where
+    R: correction::TxRepo,
+{
     pub async fn create<T: CorrectionEntity>(
         &self,
         meta: impl Into<NewCorrectionMeta<T>>,
     ) -> Result<(), Error> {
-        self.repo.create(meta.into()).await?;
-        Ok(())
+        let meta = meta.into();
+        self.policy
+            .source
+            .validate(T::entity_type(), &meta.sources)?;
+
+        Ok(self.create2(meta).await?)
     }
 
     pub async fn create2<T: CorrectionEntity>(
         &self,
         meta: impl Into<NewCorrectionMeta<T>>,
     ) -> Result<(), InfraError> {
-        self.repo.create(meta.into()).await?;
+        let meta = meta.into();
+        let (entity_type, entity_id) = (meta.entity_type(), meta.entity_id);
+        let approver = self
+            .auto_approver(&meta.author, entity_type, entity_id)
+            .await?;
+
+        let draft_id = meta.draft_id;
+        let correction_id = self.repo.create(meta).await?;
+
+        self.delete_submitted_draft(draft_id).await?;
+
+        self.auto_approve(correction_id, entity_type, entity_id, approver)
+            .await
+    }
+
+    /// Deletes the draft the correction was submitted from. A draft which is
+    /// already gone was submitted concurrently
+    async fn delete_submitted_draft(
+        &self,
+        draft_id: Option<i32>,
+    ) -> Result<(), InfraError> {
+        if let Some(id) = draft_id
+            && !self.repo.delete_draft(id).await?
+        {
+            return Err(InfraError::custom(&format!(
+                "Draft #{id} was already submitted"
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn auto_approver(
+        &self,
+        author: &User,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<Option<CorrectionApprover>, InfraError> {
+        if is_high_impact(
+            &self.repo,
+            &self.policy.review,
+            entity_type,
+            entity_id,
+        )
+        .await?
+        {
+            return Ok(None);
+        }
+
+        if self
+            .repo
+            .find_protection(entity_type, entity_id)
+            .await?
+            .is_some_and(|protection| !protection.allows_auto_approval())
+        {
+            return Ok(None);
+        }
+
+        let approved_count =
+            if self.policy.auto_approval.needs_history(author, entity_type) {
+                Some(self.repo.count_approved(author.id).await?)
+            } else {
+                None
+            };
+
+        Ok(self.policy.auto_approval.approver(
+            author,
+            entity_type,
+            approved_count,
+        ))
+    }
+
+    /// Corrections which aren't approved are pushed to the moderators, the
+    /// approved ones are sent to the webhooks
+    async fn auto_approve(
+        &self,
+        correction_id: i32,
+        entity_type: EntityType,
+        entity_id: i32,
+        approver: Option<CorrectionApprover>,
+    ) -> Result<(), InfraError> {
+        // Stale corrections stay pending, approving them would overwrite the
+        // changes made since
+        if let Some(approver) = approver
+            && find_conflict(&self.repo, correction_id).await?.is_none()
+        {
+            self.repo
+                .approve(correction_id, approver, self.repo.clone())
+                .await?;
+
+            if let Some(correction) =
+                self.repo.find_by_id(correction_id).await?
+            {
+                self.repo
+                    .enqueue(WebhookEvent::approved(&correction))
+                    .await?;
+            }
+        } else {
+            self.repo.publish(LiveEvent::CorrectionPending {
+                correction_id,
+                entity_type,
+                entity_id,
+            });
+        }
+
         Ok(())
     }
 
@@ -68,6 +356,20 @@ where
         &self,
         meta: NewCorrectionMeta<T>,
     ) -> Result<(), Error> {
+        self.policy
+            .source
+            .validate(T::entity_type(), &meta.sources)?;
+        if let Some(protection) = blocking_protection(
+            &self.repo,
+            &meta.author,
+            T::entity_type(),
+            meta.entity_id,
+        )
+        .await?
+        {
+            return Err(Protected { protection }.into());
+        }
+
         let prev_correction = self
             .repo
             .find_one(CorrectionFilter::latest(
@@ -80,7 +382,7 @@ where
         if prev_correction.status == CorrectionStatus::Pending {
             let is_author_or_admin = if meta
                 .author
-                .has_roles(&[UserRoleEnum::Admin, UserRoleEnum::Moderator])
+                .can_for(Permission::ApproveCorrection, T::entity_type())
             {
                 true
             } else {
@@ -90,19 +392,48 @@ where
             if !is_author_or_admin {
                 Err(Unauthorized::new())?;
             }
-
-            self.repo.create(meta).await?;
-        } else {
-            self.repo.update(prev_correction.id, meta).await?;
         }
 
+        let entity_id = meta.entity_id;
+        let approver = self
+            .auto_approver(&meta.author, T::entity_type(), entity_id)
+            .await?;
+
+        let draft_id = meta.draft_id;
+
+        // Amend the pending correction, otherwise start a new one
+        let correction_id =
+            if prev_correction.status == CorrectionStatus::Pending {
+                self.repo.update(prev_correction.id, meta).await?;
+                prev_correction.id
+            } else {
+                self.repo.create(meta).await?
+            };
+
+        self.delete_submitted_draft(draft_id).await?;
+
+        self.auto_approve(correction_id, T::entity_type(), entity_id, approver)
+            .await?;
+
         Ok(())
     }
 
     pub async fn upsert2<T: CorrectionEntity>(
         &self,
         meta: NewCorrectionMeta<T>,
-    ) -> eros::UnionResult<(), (InfraError, Unauthorized)> {
+    ) -> eros::UnionResult<(), (InfraError, Unauthorized, Protected)> {
+        if let Some(protection) = blocking_protection(
+            &self.repo,
+            &meta.author,
+            T::entity_type(),
+            meta.entity_id,
+        )
+        .await
+        .union()?
+        {
+            return Err(Protected { protection }).union();
+        }
+
         let prev_correction = self
             .repo
             .find_one(CorrectionFilter::latest(
@@ -117,7 +448,7 @@ where
         if prev_correction.status == CorrectionStatus::Pending {
             let is_author_or_admin = if meta
                 .author
-                .has_roles(&[UserRoleEnum::Admin, UserRoleEnum::Moderator])
+                .can_for(Permission::ApproveCorrection, T::entity_type())
             {
                 true
             } else {
@@ -131,13 +462,34 @@ where
             if !is_author_or_admin {
                 Err(Unauthorized::new()).union()?;
             }
-
-            self.repo.create(meta).await
-        } else {
-            self.repo.update(prev_correction.id, meta).await
         }
-        .map_err(InfraError::from)
-        .union()?;
+
+        let entity_id = meta.entity_id;
+        let approver = self
+            .auto_approver(&meta.author, T::entity_type(), entity_id)
+            .await
+            .union()?;
+
+        let draft_id = meta.draft_id;
+
+        // Amend the pending correction, otherwise start a new one
+        let correction_id =
+            if prev_correction.status == CorrectionStatus::Pending {
+                self.repo
+                    .update(prev_correction.id, meta)
+                    .await
+                    .map(|()| prev_correction.id)
+            } else {
+                self.repo.create(meta).await
+            }
+            .map_err(InfraError::from)
+            .union()?;
+
+        self.delete_submitted_draft(draft_id).await.union()?;
+
+        self.auto_approve(correction_id, T::entity_type(), entity_id, approver)
+            .await
+            .union()?;
 
         Ok(())
     }
@@ -146,26 +498,516 @@ where
 impl<R, TR> Service<R>
 where
     R: TransactionManager<TransactionRepository = TR>,
-    TR: correction::TxRepo + Transaction,
+    TR: correction::TxRepo
+        + notification::Repo
+        + user::Repository
+        + Transaction,
 {
-    pub async fn approve<Ctx>(
+    pub async fn approve(
         &self,
         correction_id: i32,
         user: User,
-        context: Ctx,
-    ) -> Result<(), Error>
-    where
-        Ctx: ApproveCorrectionContext,
-    {
-        let approver = CorrectionApprover::from_user(user)
-            .ok_or_else(Unauthorized::new)?;
+    ) -> Result<(), Error> {
+        let tx_repo = self.repo.begin().await?;
 
+        let correction = find_pending(&tx_repo, correction_id).await?;
+        let unit = find_unit(&tx_repo, correction).await?;
+
+        let approver = CorrectionApprover::from_user(
+            user,
+            unit.iter().map(|correction| correction.entity_type),
+        )
+        .ok_or_else(Unauthorized::new)?;
+
+        for correction in &unit {
+            if is_high_impact(
+                &tx_repo,
+                &self.policy.review,
+                correction.entity_type,
+                correction.entity_id,
+            )
+            .await?
+            {
+                return Err(Error::ReviewRequired { id: correction.id });
+            }
+        }
+
+        approve_unit(&tx_repo, &unit, &approver).await?;
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    /// Casts a review vote, and approves or rejects the correction once the
+    /// votes reach the threshold of the review policy. The outcome applies to
+    /// the whole changeset of the correction
+    pub async fn vote(
+        &self,
+        correction_id: i32,
+        user: User,
+        vote: NewCorrectionVote,
+    ) -> Result<(), Error> {
+        let tx_repo = self.repo.begin().await?;
+
+        let correction = find_pending(&tx_repo, correction_id).await?;
+
+        if tx_repo.is_author(&user, &correction).await? {
+            return Err(Error::OwnCorrection);
+        }
+
+        let unit = find_unit(&tx_repo, correction).await?;
+
+        // Only reviewers who could approve the changeset by themselves count
+        // towards the thresholds
+        let approver = CorrectionApprover::from_user(
+            user,
+            unit.iter().map(|correction| correction.entity_type),
+        )
+        .ok_or_else(Unauthorized::new)?;
+
+        tx_repo.vote(correction_id, approver.0.id, vote).await?;
+
+        let votes = tx_repo.find_votes(correction_id).await?;
+
+        match self.policy.review.outcome(&votes) {
+            Some(CorrectionStatus::Approved) => {
+                approve_unit(&tx_repo, &unit, &approver).await?;
+            }
+            Some(CorrectionStatus::Rejected) => {
+                for correction in &unit {
+                    tx_repo.reject(correction.id).await?;
+                }
+
+                notify_authors(
+                    &tx_repo,
+                    &unit,
+                    approver.0.id,
+                    NotificationEvent::correction_rejected,
+                )
+                .await?;
+            }
+            Some(CorrectionStatus::Pending) | None => {}
+        }
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    /// Invites a co-author to a pending correction, who can amend it
+    /// afterwards. Only the original author can invite
+    pub async fn add_co_author(
+        &self,
+        correction_id: i32,
+        user: User,
+        co_author_id: i32,
+    ) -> Result<(), Error> {
         let tx_repo = self.repo.begin().await?;
 
-        tx_repo.approve(correction_id, approver, context).await?;
+        find_pending(&tx_repo, correction_id).await?;
+
+        let users = tx_repo.find_users(correction_id).await?;
+
+        let is_original_author = users.iter().any(|correction_user| {
+            correction_user.user_id == user.id
+                && correction_user.user_type == CorrectionUserType::Author
+        });
+
+        if !is_original_author {
+            Err(Unauthorized::new())?;
+        }
+
+        if co_author_id == user.id {
+            return Err(Error::OwnCoAuthor);
+        }
+
+        if user::Repository::find_by_id(&tx_repo, co_author_id)
+            .await?
+            .is_none()
+        {
+            return Err(Error::UserNotFound { id: co_author_id });
+        }
+
+        if !users
+            .iter()
+            .any(|correction_user| correction_user.user_id == co_author_id)
+        {
+            tx_repo.add_co_author(correction_id, co_author_id).await?;
+        }
 
         tx_repo.commit().await?;
 
         Ok(())
     }
 }
+
+/// Looks up every id the payload references, with one query per kind of
+/// entity
+pub async fn find_reference_problems(
+    repo: &impl correction::Repo,
+    data: &(impl CorrectionEntity + Sync),
+) -> Result<Vec<ReferenceProblem>, InfraError> {
+    let references = data.references();
+    let mut existing = HashSet::new();
+
+    for (kind, ids) in group_ids(&references) {
+        existing.extend(
+            repo.find_existing_ids(kind, &ids)
+                .await?
+                .into_iter()
+                .map(|id| (kind, id)),
+        );
+    }
+
+    Ok(find_problems(&references, &existing))
+}
+
+pub async fn check_references(
+    repo: &impl correction::Repo,
+    data: &(impl CorrectionEntity + Sync),
+) -> Result<(), Error> {
+    let problems = find_reference_problems(repo, data).await?;
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(InvalidReferences { problems }.into())
+    }
+}
+
+pub async fn check_references2(
+    repo: &impl correction::Repo,
+    data: &(impl CorrectionEntity + Sync),
+) -> eros::UnionResult<(), (InfraError, InvalidReferences)> {
+    let problems = find_reference_problems(repo, data).await.union()?;
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(InvalidReferences { problems }).union()
+    }
+}
+
+/// Existing entities with names similar to those of the new entity, none if
+/// the author acknowledged them already
+async fn find_duplicates<T>(
+    repo: &impl correction::Repo,
+    policy: &DuplicatePolicy,
+    data: &T,
+    acknowledged: bool,
+) -> Result<Vec<DuplicateCandidate>, InfraError>
+where
+    T: CorrectionEntity + Sync,
+{
+    if acknowledged {
+        return Ok(vec![]);
+    }
+
+    Ok(repo
+        .find_duplicates(T::entity_type(), &data.names(), policy)
+        .await?)
+}
+
+pub async fn check_duplicates<T>(
+    repo: &impl correction::Repo,
+    policy: &DuplicatePolicy,
+    data: &T,
+    acknowledged: bool,
+) -> Result<(), Error>
+where
+    T: CorrectionEntity + Sync,
+{
+    let candidates = find_duplicates(repo, policy, data, acknowledged).await?;
+
+    if candidates.is_empty() {
+        Ok(())
+    } else {
+        Err(PossibleDuplicates { candidates }.into())
+    }
+}
+
+pub async fn check_duplicates2<T>(
+    repo: &impl correction::Repo,
+    policy: &DuplicatePolicy,
+    data: &T,
+    acknowledged: bool,
+) -> eros::UnionResult<(), (InfraError, PossibleDuplicates)>
+where
+    T: CorrectionEntity + Sync,
+{
+    let candidates = find_duplicates(repo, policy, data, acknowledged)
+        .await
+        .union()?;
+
+    if candidates.is_empty() {
+        Ok(())
+    } else {
+        Err(PossibleDuplicates { candidates }).union()
+    }
+}
+
+/// The protection of the entity if it doesn't let the author edit it
+async fn blocking_protection(
+    repo: &impl correction::Repo,
+    author: &User,
+    entity_type: EntityType,
+    entity_id: i32,
+) -> Result<Option<EntityProtection>, InfraError> {
+    Ok(repo
+        .find_protection(entity_type, entity_id)
+        .await?
+        .filter(|protection| !protection.allows(author)))
+}
+
+async fn is_high_impact(
+    repo: &impl correction::Repo,
+    review: &ReviewPolicy,
+    entity_type: EntityType,
+    entity_id: i32,
+) -> Result<bool, InfraError> {
+    if review.impact_threshold(entity_type).is_none() {
+        return Ok(false);
+    }
+
+    let size = repo.entity_size(entity_type, entity_id).await?;
+
+    Ok(review.is_high_impact(entity_type, size))
+}
+
+async fn find_pending(
+    repo: &impl correction::Repo,
+    id: i32,
+) -> Result<correction::Correction, Error> {
+    let correction =
+        repo.find_by_id(id).await?.ok_or(Error::NotFound { id })?;
+
+    if correction.status != CorrectionStatus::Pending {
+        return Err(Error::NotPending { id });
+    }
+
+    Ok(correction)
+}
+
+/// The corrections reviewed together with the correction, which are the
+/// pending corrections of its changeset in the order they are applied
+async fn find_unit(
+    repo: &impl correction::Repo,
+    correction: Correction,
+) -> Result<Vec<Correction>, InfraError> {
+    let Some(changeset_id) = repo.find_changeset_id(correction.id).await?
+    else {
+        return Ok(vec![correction]);
+    };
+
+    let entities = repo
+        .find_changeset(changeset_id)
+        .await?
+        .map_or_else(Vec::new, |changeset| changeset.entities);
+
+    Ok(entities
+        .into_iter()
+        .map(|entity| entity.correction)
+        .filter(|correction| correction.status == CorrectionStatus::Pending)
+        .collect())
+}
+
+async fn approve_unit(
+    repo: &(impl correction::TxRepo + notification::Repo),
+    unit: &[Correction],
+    approver: &CorrectionApprover,
+) -> Result<(), Error> {
+    for correction in unit {
+        ensure_fresh(repo, correction.id).await?;
+    }
+
+    // Checked right before each approval, so relations approved earlier in
+    // the unit or since the submission are taken into account
+    for correction in unit {
+        ensure_acyclic(repo, correction).await?;
+        repo.approve(correction.id, approver.clone(), repo.clone())
+            .await?;
+    }
+
+    repo.enqueue(unit.iter().flat_map(WebhookEvent::approved).collect())
+        .await?;
+
+    notify_authors(
+        repo,
+        unit,
+        approver.0.id,
+        NotificationEvent::correction_approved,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Tells the authors and co-authors about the outcome of the review, except
+/// the user who made it
+async fn notify_authors(
+    repo: &(impl correction::TxRepo + notification::Repo),
+    unit: &[Correction],
+    reviewer_id: i32,
+    event: fn(&Correction) -> NotificationEvent,
+) -> Result<(), InfraError> {
+    let mut notifications = vec![];
+
+    for correction in unit {
+        notifications.extend(
+            repo.find_users(correction.id)
+                .await?
+                .into_iter()
+                .filter(|user| {
+                    matches!(
+                        user.user_type,
+                        CorrectionUserType::Author
+                            | CorrectionUserType::CoAuthor
+                    ) && user.user_id != reviewer_id
+                })
+                .map(|user| NewNotification {
+                    user_id: user.user_id,
+                    event: event(correction),
+                }),
+        );
+    }
+
+    for notification in repo.create_notifications(notifications).await? {
+        repo.publish(LiveEvent::Notification(notification));
+    }
+
+    Ok(())
+}
+
+/// Compares the entity the correction was edited from with the current one,
+/// `None` if the correction has no base or the entity didn't change since
+async fn find_conflict(
+    repo: &impl correction::Repo,
+    correction_id: i32,
+) -> Result<Option<CorrectionConflict>, InfraError> {
+    let Some(revision) = repo.find_latest_revision(correction_id).await? else {
+        return Ok(None);
+    };
+    let Some(base_history_id) = revision.base_history_id else {
+        return Ok(None);
+    };
+    let Some(correction) = repo.find_by_id(correction_id).await? else {
+        return Ok(None);
+    };
+    let entity_type = correction.entity_type;
+
+    let Some(current_history_id) = repo
+        .find_current_history_id(entity_type, correction.entity_id)
+        .await?
+        .filter(|id| *id != base_history_id)
+    else {
+        return Ok(None);
+    };
+
+    let snapshot = async |history_id| -> Result<_, InfraError> {
+        Ok(repo
+            .find_history_snapshot(entity_type, history_id)
+            .await?
+            .unwrap_or_default())
+    };
+
+    let diff = three_way_diff(
+        &snapshot(base_history_id).await?,
+        &snapshot(current_history_id).await?,
+        &snapshot(revision.entity_history_id).await?,
+    );
+
+    Ok(Some(CorrectionConflict {
+        correction_id,
+        base_history_id,
+        current_history_id,
+        diff,
+    }))
+}
+
+/// Tag relations and credit role inheritance are checked for cycles on
+/// submission, but two corrections which are acyclic on their own can still
+/// form one once both are approved
+async fn ensure_acyclic(
+    repo: &impl correction::TxRepo,
+    correction: &Correction,
+) -> Result<(), Error> {
+    match correction.entity_type {
+        EntityType::Tag => {
+            let Some(data) = find_input::<NewTag>(repo, correction).await?
+            else {
+                return Ok(());
+            };
+
+            let descendants = tag::Repo::find_descendants(
+                &repo.clone().tag_repo(),
+                correction.entity_id,
+            )
+            .await?
+            .map_or_else(HashSet::new, |tree| {
+                tree.tags.into_iter().map(|tag| tag.id).collect()
+            });
+
+            data.find_cyclic_relation(correction.entity_id, &descendants)
+                .map_or(Ok(()), |related_tag_id| {
+                    Err(Error::CyclicTagRelation {
+                        id: correction.id,
+                        related_tag_id,
+                    })
+                })
+        }
+        EntityType::CreditRole => {
+            let Some(data) =
+                find_input::<NewCreditRole>(repo, correction).await?
+            else {
+                return Ok(());
+            };
+
+            let sub_roles = credit_role::Repo::find_sub_role_ids(
+                &repo.clone().credit_role_repo(),
+                correction.entity_id,
+            )
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+            data.find_cyclic_super_role(correction.entity_id, &sub_roles)
+                .map_or(Ok(()), |super_id| {
+                    Err(Error::CyclicCreditRoleInheritance {
+                        id: correction.id,
+                        super_id,
+                    })
+                })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The data of the latest revision, as it was submitted
+async fn find_input<T: DeserializeOwned>(
+    repo: &impl correction::Repo,
+    correction: &Correction,
+) -> Result<Option<T>, Error> {
+    let Some(revision) = repo.find_latest_revision(correction.id).await? else {
+        return Ok(None);
+    };
+    let Some(input) = repo
+        .find_history_input(correction.entity_type, revision.entity_history_id)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    serde_json::from_value(input)
+        .map(Some)
+        .map_err(|err| InfraError::custom(&err.to_string()).into())
+}
+
+async fn ensure_fresh(
+    repo: &impl correction::Repo,
+    correction_id: i32,
+) -> Result<(), Error> {
+    find_conflict(repo, correction_id)
+        .await?
+        .map_or(Ok(()), |conflict| Err(StaleCorrection { conflict }.into()))
+}
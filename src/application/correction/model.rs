@@ -1,9 +1,13 @@
 #![expect(clippy::option_if_let_else, reason = "macro")]
-use entity::enums::CorrectionType;
+use entity::enums::{CorrectionType, EntityType};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 use utoipa::ToSchema;
 
-use crate::domain::correction::{CorrectionEntity, NewCorrection};
+use crate::domain::correction::{
+    CorrectionEntity, CorrectionSource, NewCorrection,
+};
 use crate::domain::user::User;
 
 #[derive(Deserialize, ToSchema)]
@@ -18,6 +22,14 @@ where
     pub data: T,
     pub description: String,
     pub r#type: CorrectionType,
+    /// Id of the entity history this correction was edited from, see
+    /// `/{entity_type}/{id}/latest-history`
+    pub base_history_id: Option<i32>,
+    /// Create the entity even if similar entities exist
+    #[serde(default)]
+    pub acknowledge_duplicates: bool,
+    #[serde(default)]
+    pub sources: Vec<CorrectionSource>,
 }
 
 impl<T> NewCorrectionDto<T>
@@ -30,6 +42,49 @@ where
             author,
             description: self.description,
             r#type: self.r#type,
+            base_history_id: self.base_history_id,
+            acknowledge_duplicates: self.acknowledge_duplicates,
+            sources: self.sources,
+            draft_id: None,
         }
     }
 }
+
+/// A correction whose data is not yet parsed into the input form of its
+/// entity type, eg. a submitted draft
+pub struct UntypedCorrection {
+    pub entity_type: EntityType,
+    /// `None` creates a new entity
+    pub entity_id: Option<i32>,
+    pub base_history_id: Option<i32>,
+    pub description: String,
+    pub data: Value,
+    pub acknowledge_duplicates: bool,
+    pub sources: Vec<CorrectionSource>,
+    pub draft_id: Option<i32>,
+}
+
+impl UntypedCorrection {
+    pub fn typed<T>(
+        self,
+        author: User,
+    ) -> Result<NewCorrection<T>, serde_json::Error>
+    where
+        T: CorrectionEntity + DeserializeOwned,
+    {
+        Ok(NewCorrection {
+            data: serde_json::from_value(self.data)?,
+            author,
+            description: self.description,
+            r#type: if self.entity_id.is_some() {
+                CorrectionType::Update
+            } else {
+                CorrectionType::Create
+            },
+            base_history_id: self.base_history_id,
+            acknowledge_duplicates: self.acknowledge_duplicates,
+            sources: self.sources,
+            draft_id: self.draft_id,
+        })
+    }
+}
@@ -0,0 +1,102 @@
+use axum::http::StatusCode;
+use entity::enums::EntityType;
+use macros::{ApiError, IntoErrorSchema};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::{Service, UntypedCorrection};
+use crate::domain::correction::{self, CorrectionPatch, CorrectionSource};
+use crate::infra;
+use crate::infra::error::Error as InfraError;
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewPatchCorrection {
+    pub patch: CorrectionPatch,
+    pub description: String,
+    #[serde(default)]
+    pub sources: Vec<CorrectionSource>,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum PatchError {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(display("{entity_type:?} #{id} has no approved version to patch"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { entity_type: EntityType, id: i32 },
+    #[snafu(display("Failed to apply patch: {message}"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    Apply { message: String },
+    #[snafu(display("Patched data is invalid: {message}"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    InvalidData { message: String },
+}
+
+impl<A> From<A> for PatchError
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<R> Service<R>
+where
+    R: correction::Repo,
+{
+    /// Applies the patch to the input form of the current version of the
+    /// entity, the result is submitted as a normal update correction edited
+    /// from that version
+    pub async fn patch_correction(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+        patch: NewPatchCorrection,
+    ) -> Result<UntypedCorrection, PatchError> {
+        let Some(history_id) = self
+            .repo
+            .find_current_history_id(entity_type, entity_id)
+            .await?
+        else {
+            return Err(PatchError::NotFound {
+                entity_type,
+                id: entity_id,
+            });
+        };
+
+        let mut data = self
+            .repo
+            .find_history_input(entity_type, history_id)
+            .await?
+            .ok_or_else(|| {
+                InfraError::custom(&format!(
+                    "History #{history_id} of {entity_type:?} #{entity_id} not found"
+                ))
+            })?;
+
+        patch
+            .patch
+            .apply(&mut data)
+            .map_err(|err| PatchError::Apply {
+                message: err.to_string(),
+            })?;
+
+        Ok(UntypedCorrection {
+            entity_type,
+            entity_id: Some(entity_id),
+            base_history_id: Some(history_id),
+            description: patch.description,
+            data,
+            acknowledge_duplicates: false,
+            sources: patch.sources,
+            draft_id: None,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use axum::http::StatusCode;
+use chrono::Utc;
+use entity::enums::EntityType;
+use macros::{ApiError, IntoErrorSchema};
+
+use super::Service;
+use crate::application::authz::authorize_for;
+use crate::application::error::Unauthorized;
+use crate::domain::correction::{
+    EntityProtection, NewEntityProtection, ProtectionRepo,
+};
+use crate::domain::model::auth::Permission;
+use crate::domain::user::User;
+use crate::infra;
+use crate::infra::error::Error as InfraError;
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum ProtectionError {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(transparent)]
+    Unauthorized { source: Unauthorized },
+    #[snafu(display("Protection can't expire in the past"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    AlreadyExpired,
+}
+
+impl<A> From<A> for ProtectionError
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<R> Service<R>
+where
+    R: ProtectionRepo,
+{
+    pub async fn find_protection(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<Option<EntityProtection>, InfraError> {
+        Ok(self.repo.find_protection(entity_type, entity_id).await?)
+    }
+
+    /// Needs the `ProtectEntity` permission for the entity type
+    pub async fn set_protection(
+        &self,
+        user: &User,
+        entity_type: EntityType,
+        entity_id: i32,
+        protection: NewEntityProtection,
+    ) -> Result<EntityProtection, ProtectionError> {
+        authorize_for(user, Permission::ProtectEntity, entity_type)?;
+
+        if protection
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+        {
+            return Err(ProtectionError::AlreadyExpired);
+        }
+
+        Ok(self
+            .repo
+            .set_protection(entity_type, entity_id, user.id, protection)
+            .await?)
+    }
+
+    pub async fn delete_protection(
+        &self,
+        user: &User,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<(), ProtectionError> {
+        authorize_for(user, Permission::ProtectEntity, entity_type)?;
+
+        Ok(self.repo.delete_protection(entity_type, entity_id).await?)
+    }
+}
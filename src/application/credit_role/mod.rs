@@ -1,10 +1,17 @@
-use entity::enums::CorrectionStatus;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{CorrectionStatus, EntityType};
 use macros::{ApiError, IntoErrorSchema};
 
 use crate::domain::correction::{
-    NewCorrection, NewCorrectionMeta, {self},
+    CorrectionPolicy, NewCorrection, NewCorrectionMeta, {self},
+};
+use crate::domain::credit_role::model::{
+    CreditRole, CreditRoleTree, NewCreditRole,
 };
-use crate::domain::credit_role::model::NewCreditRole;
 use crate::domain::credit_role::repo::{
     CommonFilter, FindManyFilter, QueryKind,
 };
@@ -15,6 +22,7 @@ use crate::infra::Error;
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub correction_policy: Arc<CorrectionPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -45,6 +53,13 @@ pub enum UpsertCorrectionError {
     Correction {
         source: crate::application::correction::Error,
     },
+    #[snafu(display(
+        "Inheriting from credit role #{super_id} would create a cycle"
+    ))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    CyclicInheritance { super_id: i32 },
 }
 
 impl<A> From<A> for UpsertCorrectionError
@@ -75,6 +90,33 @@ where
     ) -> Result<Vec<K::Output>, Error> {
         Ok(self.repo.find_many::<K>(filter, common).await?)
     }
+
+    pub async fn find_tree(&self) -> Result<CreditRoleTree, Error> {
+        Ok(self.repo.find_tree().await?)
+    }
+}
+
+impl<R> Service<R>
+where
+    R: Repo + correction::Repo,
+{
+    /// The credit role as it was at the moment, `None` if it wasn't created
+    /// yet
+    pub async fn find_as_of(
+        &self,
+        id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<CreditRole>, Error> {
+        let Some(history_id) = self
+            .repo
+            .find_history_id_at(EntityType::CreditRole, id, at)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.repo.find_history(id, history_id).await?)
+    }
 }
 
 impl<R, TR> Service<R>
@@ -88,11 +130,16 @@ where
     ) -> Result<(), CreateError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         let entity_id = TxRepo::create(&tx_repo, &correction.data).await?;
 
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create(NewCorrectionMeta::<NewCreditRole> {
@@ -100,8 +147,11 @@ where
                 r#type: correction.r#type,
                 entity_id,
                 history_id,
+                base_history_id: None,
                 status: CorrectionStatus::Approved,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -118,8 +168,25 @@ where
     ) -> Result<(), UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
+        let sub_roles = tx_repo
+            .find_sub_role_ids(id)
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        if let Some(super_id) =
+            correction.data.find_cyclic_super_role(id, &sub_roles)
+        {
+            return Err(UpsertCorrectionError::CyclicInheritance { super_id });
+        }
+
         let history_id = tx_repo.create_history(&correction.data).await?;
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .upsert(NewCorrectionMeta::<NewCreditRole> {
@@ -128,7 +195,10 @@ where
                 entity_id: id,
                 status: CorrectionStatus::Pending,
                 history_id,
+                base_history_id: correction.base_history_id,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
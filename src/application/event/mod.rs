@@ -1,7 +1,12 @@
-use entity::enums::CorrectionStatus;
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{CorrectionStatus, EntityType};
 use macros::{ApiError, IntoErrorSchema};
 
-use crate::domain::correction::{self, NewCorrection, NewCorrectionMeta};
+use crate::domain::correction::{
+    self, CorrectionPolicy, NewCorrection, NewCorrectionMeta,
+};
 use crate::domain::event;
 use crate::domain::event::NewEvent;
 use crate::domain::repository::TransactionManager;
@@ -11,6 +16,7 @@ use crate::infra::error::Error;
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub correction_policy: Arc<CorrectionPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -71,6 +77,28 @@ where
     }
 }
 
+impl<R> Service<R>
+where
+    R: event::Repo + correction::Repo,
+{
+    /// The event as it was at the moment, `None` if it wasn't created yet
+    pub async fn find_as_of(
+        &self,
+        id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<event::Event>, Error> {
+        let Some(history_id) = self
+            .repo
+            .find_history_id_at(EntityType::Event, id, at)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.repo.find_history(id, history_id).await?)
+    }
+}
+
 impl<R, TR> Service<R>
 where
     R: TransactionManager<TransactionRepository = TR>,
@@ -82,13 +110,25 @@ where
     ) -> Result<(), CreateError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+        super::correction::check_duplicates(
+            &tx_repo,
+            &self.correction_policy.duplicate,
+            &correction.data,
+            correction.acknowledge_duplicates,
+        )
+        .await?;
+
         // TODO: Create entity in event repo, create correction in correction repo
         let entity_id =
             event::TxRepo::create(&tx_repo, &correction.data).await?;
         let history_id =
             event::TxRepo::create_history(&tx_repo, &correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create(NewCorrectionMeta::<NewEvent> {
@@ -96,8 +136,11 @@ where
                 r#type: correction.r#type,
                 entity_id,
                 history_id,
+                base_history_id: None,
                 status: CorrectionStatus::Approved,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -116,9 +159,14 @@ where
     ) -> Result<(), UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .upsert(NewCorrectionMeta::<NewEvent> {
@@ -126,8 +174,11 @@ where
                 r#type: correction.r#type,
                 entity_id,
                 history_id,
+                base_history_id: correction.base_history_id,
                 description: correction.description,
+                sources: correction.sources,
                 status: CorrectionStatus::Pending,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -0,0 +1,216 @@
+use axum::http::StatusCode;
+use macros::{ApiError, IntoErrorSchema};
+
+use crate::domain::identity::{
+    AuthorizationRequest, ExternalIdentity, IdentityProvider, OidcProvider,
+    PendingAuthorization, Repo, UserIdentity,
+};
+use crate::domain::repository::{Transaction, TransactionManager};
+use crate::domain::user::{self, NewUser, TxRepo, User};
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R, P> {
+    pub repo: R,
+    pub provider: P,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(display("Unknown identity provider {provider}"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    UnknownProvider { provider: String },
+    #[snafu(display("Sign in with the identity provider expired, try again"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    InvalidState,
+    #[snafu(display("Sign in with {provider} failed"))]
+    #[api_error(
+        status_code = StatusCode::UNAUTHORIZED,
+    )]
+    ProviderFailed { provider: String },
+    #[snafu(display("This account is linked to another user"))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    LinkedToOtherUser,
+    #[snafu(display("Identity #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: i32 },
+    #[snafu(display(
+        "Can't unlink the only way to sign in to an account without a password"
+    ))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    LastSignInMethod,
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+pub enum CallbackOutcome {
+    /// Still has to pass the checks of a sign in, e.g. two-factor
+    /// authentication
+    SignIn(User),
+    Linked,
+}
+
+/// Usernames are deduplicated with a suffix up to this many times
+const MAX_USERNAME_ATTEMPTS: usize = 20;
+
+impl<R, P> Service<R, P>
+where
+    R: Repo + user::Repository + TransactionManager + Sync,
+    R::TransactionRepository: Repo + TxRepo,
+    P: IdentityProvider + Sync,
+{
+    pub fn providers(&self) -> Vec<OidcProvider> {
+        self.provider.providers()
+    }
+
+    /// Signed in users link the identity to their account, others sign in
+    /// with it
+    pub async fn authorize(
+        &self,
+        provider: &str,
+        current_user: Option<&User>,
+    ) -> Result<AuthorizationRequest, Error> {
+        if !self
+            .provider
+            .providers()
+            .iter()
+            .any(|known| known.key == provider)
+        {
+            return Err(Error::UnknownProvider {
+                provider: provider.to_owned(),
+            });
+        }
+
+        Ok(self
+            .provider
+            .authorize(provider, current_user.map(|user| user.id))
+            .await?)
+    }
+
+    /// Signing in with an identity that isn't linked yet creates a user
+    pub async fn callback(
+        &self,
+        pending: PendingAuthorization,
+        current_user: Option<&User>,
+        provider: &str,
+        state: &str,
+        code: String,
+    ) -> Result<CallbackOutcome, Error> {
+        // Linking has to finish in the session of the same user
+        if pending.provider != provider
+            || pending.state != state
+            || pending.link_user_id != current_user.map(|user| user.id)
+        {
+            return Err(Error::InvalidState);
+        }
+
+        let identity =
+            self.provider
+                .exchange(&pending, code)
+                .await
+                .map_err(|err| {
+                    tracing::warn!("Sign in with {provider} failed: {err}");
+
+                    Error::ProviderFailed {
+                        provider: provider.to_owned(),
+                    }
+                })?;
+
+        let owner = self
+            .repo
+            .find_identity_owner(&identity.provider, &identity.subject)
+            .await?;
+
+        if let Some(user_id) = pending.link_user_id {
+            return match owner {
+                Some(owner) if owner != user_id => {
+                    Err(Error::LinkedToOtherUser)
+                }
+                Some(_) => Ok(CallbackOutcome::Linked),
+                None => {
+                    self.repo.link_identity(user_id, identity).await?;
+
+                    Ok(CallbackOutcome::Linked)
+                }
+            };
+        }
+
+        let user = match owner {
+            Some(owner) => self.repo.find_by_id(owner).await?,
+            None => Some(self.sign_up(identity).await?),
+        };
+
+        // The user was deleted in between
+        user.map(CallbackOutcome::SignIn).ok_or(Error::InvalidState)
+    }
+
+    async fn sign_up(&self, identity: ExternalIdentity) -> Result<User, Error> {
+        let mut name = None;
+
+        for candidate in
+            identity.username_candidates().take(MAX_USERNAME_ATTEMPTS)
+        {
+            if self.repo.find_by_name(&candidate).await?.is_none() {
+                name = Some(candidate);
+                break;
+            }
+        }
+
+        let name = name.ok_or_else(|| {
+            infra::Error::custom(&format!(
+                "No free username for the identity {} of {}",
+                identity.subject, identity.provider
+            ))
+        })?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        let user = tx_repo.create(NewUser::without_password(name)).await?;
+
+        tx_repo.link_identity(user.id, identity).await?;
+
+        tx_repo.commit().await?;
+
+        Ok(user)
+    }
+
+    pub async fn find_identities(
+        &self,
+        user: &User,
+    ) -> Result<Vec<UserIdentity>, Error> {
+        Ok(self.repo.find_identities(user.id).await?)
+    }
+
+    pub async fn unlink(&self, user: &User, id: i32) -> Result<(), Error> {
+        if user.password_hash().is_none()
+            && self.repo.find_identities(user.id).await?.len() <= 1
+        {
+            return Err(Error::LastSignInMethod);
+        }
+
+        if self.repo.unlink_identity(user.id, id).await? {
+            Ok(())
+        } else {
+            Err(Error::NotFound { id })
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use axum::http::StatusCode;
+use entity::enums::ImageQueueStatus;
+use macros::{ApiError, IntoErrorSchema};
+
+use crate::domain::image_queue::{self, ImageQueue};
+use crate::domain::live::{self, LiveEvent};
+use crate::domain::notification::{self, NewNotification, NotificationEvent};
+use crate::domain::repository::TransactionManager;
+use crate::domain::user::User;
+use crate::domain::webhook::{self, WebhookEvent};
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R> {
+    pub repo: R,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(transparent)]
+    Queue { source: image_queue::Error },
+    #[snafu(display("Image queue entry #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: i32 },
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<R, TR> Service<R>
+where
+    R: TransactionManager<TransactionRepository = TR> + Sync,
+    TR: image_queue::Repo + live::TxRepo + notification::Repo + webhook::Repo,
+{
+    pub async fn approve(&self, id: i32, user: &User) -> Result<(), Error> {
+        self.handle(
+            id,
+            user,
+            ImageQueue::approve,
+            Some(NotificationEvent::ImageApproved { queue_id: id }),
+        )
+        .await
+    }
+
+    pub async fn reject(&self, id: i32, user: &User) -> Result<(), Error> {
+        self.handle(
+            id,
+            user,
+            ImageQueue::reject,
+            Some(NotificationEvent::ImageRejected { queue_id: id }),
+        )
+        .await
+    }
+
+    pub async fn revert(&self, id: i32, user: &User) -> Result<(), Error> {
+        self.handle(
+            id,
+            user,
+            ImageQueue::revert,
+            Some(NotificationEvent::ImageReverted { queue_id: id }),
+        )
+        .await
+    }
+
+    pub async fn cancel(&self, id: i32, user: &User) -> Result<(), Error> {
+        self.handle(id, user, ImageQueue::cancel, None).await
+    }
+
+    /// Applies the action and notifies the uploader about it, unless they did
+    /// it themselves. Approvals are also sent to the webhooks
+    async fn handle(
+        &self,
+        id: i32,
+        user: &User,
+        action: fn(ImageQueue, &User) -> Result<ImageQueue, image_queue::Error>,
+        event: Option<NotificationEvent>,
+    ) -> Result<(), Error> {
+        let tx_repo = self.repo.begin().await?;
+
+        let queue = image_queue::Repo::find_by_id(&tx_repo, id)
+            .await?
+            .ok_or(Error::NotFound { id })?;
+        let uploader = queue.creaded_by;
+
+        let queue = action(queue, user)?;
+
+        if queue.status == ImageQueueStatus::Approved {
+            tx_repo
+                .enqueue(vec![WebhookEvent::ImageApproved {
+                    queue_id: id,
+                    image_id: queue.image_id,
+                }])
+                .await?;
+        }
+
+        image_queue::Repo::update(&tx_repo, queue).await?;
+
+        if let Some(event) = event
+            && uploader != user.id
+        {
+            for notification in tx_repo
+                .create_notifications(vec![NewNotification {
+                    user_id: uploader,
+                    event,
+                }])
+                .await?
+            {
+                tx_repo.publish(LiveEvent::Notification(notification));
+            }
+        }
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,12 @@
-use entity::enums::CorrectionStatus;
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{CorrectionStatus, EntityType};
 use macros::{ApiError, IntoErrorSchema};
 
-use crate::domain::correction::{self, NewCorrection, NewCorrectionMeta};
+use crate::domain::correction::{
+    self, CorrectionPolicy, NewCorrection, NewCorrectionMeta,
+};
 use crate::domain::label::model::NewLabel;
 use crate::domain::label::repo::{Repo, TxRepo};
 use crate::domain::label::{
@@ -14,6 +19,7 @@ use crate::infra::error::Error;
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub correction_policy: Arc<CorrectionPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -70,6 +76,28 @@ where
     }
 }
 
+impl<R> Service<R>
+where
+    R: label::Repo + correction::Repo,
+{
+    /// The label as it was at the moment, `None` if it wasn't created yet
+    pub async fn find_as_of(
+        &self,
+        id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<Label>, Error> {
+        let Some(history_id) = self
+            .repo
+            .find_history_id_at(EntityType::Label, id, at)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.repo.find_history(id, history_id).await?)
+    }
+}
+
 impl<R, TR> Service<R>
 where
     R: Repo + TransactionManager<TransactionRepository = TR>,
@@ -81,10 +109,22 @@ where
     ) -> Result<(), CreateError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+        super::correction::check_duplicates(
+            &tx_repo,
+            &self.correction_policy.duplicate,
+            &correction.data,
+            correction.acknowledge_duplicates,
+        )
+        .await?;
+
         let entity_id = TxRepo::create(&tx_repo, &correction.data).await?;
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create(NewCorrectionMeta::<NewLabel> {
@@ -93,7 +133,10 @@ where
                 entity_id,
                 status: CorrectionStatus::Approved,
                 history_id,
+                base_history_id: None,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -110,12 +153,16 @@ where
     ) -> Result<(), UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         // Create label history from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
         {
-            let correction_service =
-                super::correction::Service::new(tx_repo.clone());
+            let correction_service = super::correction::Service::new(
+                tx_repo.clone(),
+                self.correction_policy.clone(),
+            );
 
             correction_service
                 .upsert(NewCorrectionMeta::<NewLabel> {
@@ -124,7 +171,10 @@ where
                     entity_id: id,
                     status: CorrectionStatus::Pending,
                     history_id,
+                    base_history_id: correction.base_history_id,
                     description: correction.description,
+                    sources: correction.sources,
+                    draft_id: correction.draft_id,
                     phantom: std::marker::PhantomData,
                 })
                 .await?;
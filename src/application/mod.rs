@@ -1,15 +1,26 @@
+pub mod account;
+pub mod admin;
 pub mod artist;
 pub mod artist_image;
 pub mod auth;
+pub mod authz;
+pub mod contribution;
 pub mod correction;
 pub mod credit_role;
 pub mod error;
 pub mod event;
+pub mod identity;
+pub mod image_queue;
 pub mod label;
+pub mod notification;
 pub mod release;
 pub mod release_image;
+pub mod report;
+pub mod session;
 pub mod song;
 pub mod song_lyrics;
 pub mod tag;
+pub mod two_factor;
 pub mod user_image;
 pub mod user_profile;
+pub mod webhook;
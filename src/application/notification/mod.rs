@@ -0,0 +1,96 @@
+use axum::http::StatusCode;
+use enumset::EnumSet;
+use macros::{ApiError, IntoErrorSchema};
+
+use crate::domain::notification::{
+    Notification, NotificationFilter, NotificationPreference, Repo,
+};
+use crate::domain::repository::{Cursor, Paginated};
+use crate::domain::user::User;
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R> {
+    pub repo: R,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(display("Notification #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: i32 },
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<R> Service<R>
+where
+    R: Repo + Sync,
+{
+    pub async fn find(
+        &self,
+        user: &User,
+        filter: NotificationFilter,
+        pagination: Cursor,
+    ) -> Result<Paginated<Notification>, Error> {
+        Ok(self
+            .repo
+            .find_notifications(user.id, filter, pagination)
+            .await?)
+    }
+
+    pub async fn count_unread(&self, user: &User) -> Result<u64, Error> {
+        Ok(self.repo.count_unread(user.id).await?)
+    }
+
+    pub async fn mark_read(&self, user: &User, id: i32) -> Result<(), Error> {
+        if self.repo.mark_read(user.id, id).await? {
+            Ok(())
+        } else {
+            Err(Error::NotFound { id })
+        }
+    }
+
+    pub async fn mark_all_read(&self, user: &User) -> Result<(), Error> {
+        Ok(self.repo.mark_all_read(user.id).await?)
+    }
+
+    pub async fn find_preferences(
+        &self,
+        user: &User,
+    ) -> Result<Vec<NotificationPreference>, Error> {
+        let saved = self.repo.find_preferences(user.id).await?;
+
+        Ok(NotificationPreference::complete(&saved))
+    }
+
+    /// Kinds missing from the input keep their preference
+    pub async fn update_preferences(
+        &self,
+        user: &User,
+        preferences: Vec<NotificationPreference>,
+    ) -> Result<Vec<NotificationPreference>, Error> {
+        // The last one wins if a kind is given twice
+        let mut seen = EnumSet::new();
+        let preferences = preferences
+            .into_iter()
+            .rev()
+            .filter(|preference| seen.insert(preference.kind))
+            .collect();
+
+        self.repo.save_preferences(user.id, preferences).await?;
+
+        self.find_preferences(user).await
+    }
+}
@@ -1,18 +1,22 @@
-use entity::enums::CorrectionStatus;
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{CorrectionStatus, EntityType};
 use garde::Validate;
 use macros::{ApiError, IntoErrorSchema};
 
 use crate::domain::correction::{
-    NewCorrection, NewCorrectionMeta, {self},
+    CorrectionPolicy, NewCorrection, NewCorrectionMeta, {self},
 };
 use crate::domain::release::repo::Filter;
 use crate::domain::release::{NewRelease, Release, Repo, TxRepo};
-use crate::domain::repository::TransactionManager;
+use crate::domain::repository::{Cursor, Paginated, TransactionManager};
 use crate::infra::error::Error;
 
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub correction_policy: Arc<CorrectionPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -79,6 +83,40 @@ where
     ) -> Result<Vec<Release>, Error> {
         Ok(self.repo.find_many(filter).await?)
     }
+
+    pub async fn find_by_credit(
+        &self,
+        role_id: i32,
+        artist_id: Option<i32>,
+        pagination: Cursor,
+    ) -> Result<Paginated<Release>, Error> {
+        Ok(self
+            .repo
+            .find_by_credit(role_id, artist_id, pagination)
+            .await?)
+    }
+}
+
+impl<R> Service<R>
+where
+    R: Repo + correction::Repo,
+{
+    /// The release as it was at the moment, `None` if it wasn't created yet
+    pub async fn find_as_of(
+        &self,
+        id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<Release>, Error> {
+        let Some(history_id) = self
+            .repo
+            .find_history_id_at(EntityType::Release, id, at)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.repo.find_history(id, history_id).await?)
+    }
 }
 
 impl<R, TR> Service<R>
@@ -99,10 +137,22 @@ where
 
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+        super::correction::check_duplicates(
+            &tx_repo,
+            &self.correction_policy.duplicate,
+            &correction.data,
+            correction.acknowledge_duplicates,
+        )
+        .await?;
+
         let entity_id = TxRepo::create(&tx_repo, &correction.data).await?;
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create(NewCorrectionMeta::<NewRelease> {
@@ -112,7 +162,10 @@ where
                 status: CorrectionStatus::Approved,
                 entity_id,
                 history_id,
+                base_history_id: None,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -135,10 +188,15 @@ where
 
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         // Create Releasehistory from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .upsert(NewCorrectionMeta::<NewRelease> {
@@ -147,7 +205,10 @@ where
                 status: CorrectionStatus::Pending,
                 entity_id: id,
                 history_id,
+                base_history_id: correction.base_history_id,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -11,6 +11,7 @@ use super::error::EntityNotFound;
 use crate::domain::image::{
     AsyncFileStorage, CreateImageMeta, ParseOption, Parser,
 };
+use crate::domain::live::{self, LiveEvent};
 use crate::domain::release_image::{self};
 use crate::domain::release_image_queue::{self, ReleaseImageQueue};
 use crate::domain::repository::{Transaction, TransactionManager};
@@ -76,6 +77,7 @@ where
         + Transaction
         + image::TxRepo
         + image_queue::Repo
+        + live::TxRepo
         + release_image::Repo
         + release_image_queue::Repo,
     Storage: AsyncFileStorage + Clone,
@@ -123,6 +125,10 @@ where
         release_image_queue::Repo::create(&tx_repo, release_image_queue_entry)
             .await?;
 
+        tx_repo.publish(LiveEvent::ImageSubmitted {
+            queue_id: image_queue_entry.id,
+        });
+
         drop(image_service);
 
         tx_repo.commit().await?;
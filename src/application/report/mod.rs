@@ -0,0 +1,257 @@
+use axum::http::StatusCode;
+use chrono::Utc;
+use entity::enums::{ReportAction, ReportState, ReportTarget};
+use macros::{ApiError, IntoErrorSchema};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::authz::authorize;
+use super::error::Unauthorized;
+use crate::domain::model::auth::Permission;
+use crate::domain::report::{
+    NewReport, Repo, Report, ReportFilter, ReportPolicy, ReportResolution,
+};
+use crate::domain::repository::{
+    Cursor, Paginated, Transaction, TransactionManager,
+};
+use crate::domain::user::User;
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R> {
+    pub repo: R,
+    pub policy: ReportPolicy,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(transparent)]
+    Unauthorized { source: Unauthorized },
+    #[snafu(display("Report #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: i32 },
+    #[snafu(display("{target:?} #{target_id} not found"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    TargetNotFound {
+        target: ReportTarget,
+        target_id: i32,
+    },
+    #[snafu(display(
+        "Too many reports, try again in {window_minutes} minutes"
+    ))]
+    #[api_error(
+        status_code = StatusCode::TOO_MANY_REQUESTS,
+    )]
+    RateLimited { window_minutes: u32 },
+    #[snafu(display("Report #{id} is already closed"))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    Closed { id: i32 },
+    #[snafu(display("Report #{id} is claimed by another moderator"))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    ClaimedByOther { id: i32 },
+    #[snafu(display("{action:?} must refer to what was changed"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    MissingActionId { action: ReportAction },
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ResolveReport {
+    pub action: ReportAction,
+    /// The correction, comment or user the action refers to, optional for
+    /// `Other`
+    pub action_id: Option<i32>,
+    pub note: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct DismissReport {
+    pub note: Option<String>,
+}
+
+impl<R, TR> Service<R>
+where
+    R: TransactionManager<TransactionRepository = TR>,
+    TR: Repo + Transaction,
+{
+    pub async fn create(
+        &self,
+        reporter: &User,
+        report: NewReport,
+    ) -> Result<Report, Error> {
+        let policy = self.policy;
+        let tx_repo = self.repo.begin().await?;
+
+        // Concurrent submissions would all pass the count otherwise
+        tx_repo.lock_reporter(reporter.id).await?;
+
+        let submitted = tx_repo
+            .count_by_reporter_since(
+                reporter.id,
+                policy.window_start(Utc::now().into()),
+            )
+            .await?;
+
+        if policy.is_exceeded(submitted) {
+            return Err(Error::RateLimited {
+                window_minutes: policy.window_minutes,
+            });
+        }
+
+        if !tx_repo
+            .target_exists(report.target, report.target_id)
+            .await?
+        {
+            return Err(Error::TargetNotFound {
+                target: report.target,
+                target_id: report.target_id,
+            });
+        }
+
+        let report = tx_repo.create(reporter.id, report).await?;
+
+        tx_repo.commit().await?;
+
+        Ok(report)
+    }
+}
+
+impl<R> Service<R>
+where
+    R: Repo,
+{
+    pub async fn find_by_id(
+        &self,
+        user: &User,
+        id: i32,
+    ) -> Result<Report, Error> {
+        authorize(user, Permission::ModerateReport)?;
+
+        self.repo
+            .find_by_id(id)
+            .await?
+            .ok_or(Error::NotFound { id })
+    }
+
+    /// The moderation queue
+    pub async fn find_many(
+        &self,
+        user: &User,
+        filter: ReportFilter,
+        pagination: Cursor,
+    ) -> Result<Paginated<Report>, Error> {
+        authorize(user, Permission::ModerateReport)?;
+
+        Ok(self.repo.find_many(filter, pagination).await?)
+    }
+
+    /// Assigns an open report to the moderator so others don't handle it
+    /// at the same time
+    pub async fn claim(&self, user: &User, id: i32) -> Result<Report, Error> {
+        let report = self.find_by_id(user, id).await?;
+
+        if report.state == ReportState::Claimed
+            && report.assignee_id == Some(user.id)
+        {
+            return Ok(report);
+        }
+
+        if !self.repo.claim(id, user.id).await? {
+            return Err(if report.is_open() {
+                Error::ClaimedByOther { id }
+            } else {
+                Error::Closed { id }
+            });
+        }
+
+        self.find_by_id(user, id).await
+    }
+
+    pub async fn resolve(
+        &self,
+        user: &User,
+        id: i32,
+        resolution: ResolveReport,
+    ) -> Result<Report, Error> {
+        if resolution.action_id.is_none()
+            && resolution.action != ReportAction::Other
+        {
+            return Err(Error::MissingActionId {
+                action: resolution.action,
+            });
+        }
+
+        self.close(
+            user,
+            id,
+            ReportResolution {
+                state: ReportState::Resolved,
+                action: Some(resolution.action),
+                action_id: resolution.action_id,
+                note: resolution.note,
+            },
+        )
+        .await
+    }
+
+    pub async fn dismiss(
+        &self,
+        user: &User,
+        id: i32,
+        dismissal: DismissReport,
+    ) -> Result<Report, Error> {
+        self.close(
+            user,
+            id,
+            ReportResolution {
+                state: ReportState::Dismissed,
+                action: None,
+                action_id: None,
+                note: dismissal.note,
+            },
+        )
+        .await
+    }
+
+    async fn close(
+        &self,
+        user: &User,
+        id: i32,
+        resolution: ReportResolution,
+    ) -> Result<Report, Error> {
+        let report = self.find_by_id(user, id).await?;
+
+        if !report.is_open() {
+            return Err(Error::Closed { id });
+        }
+
+        if report
+            .assignee_id
+            .is_some_and(|assignee_id| assignee_id != user.id)
+        {
+            return Err(Error::ClaimedByOther { id });
+        }
+
+        Ok(self.repo.close(id, user.id, resolution).await?)
+    }
+}
@@ -0,0 +1,66 @@
+use axum::http::StatusCode;
+use macros::{ApiError, IntoErrorSchema};
+
+use crate::domain::user::{SessionRepo, User, UserSession};
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<S> {
+    pub sessions: S,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(display("Session {id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: String },
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<S> Service<S>
+where
+    S: SessionRepo,
+{
+    pub async fn find_sessions(
+        &self,
+        user: &User,
+        current_session_id: String,
+    ) -> Result<Vec<UserSession>, Error> {
+        Ok(self
+            .sessions
+            .find_sessions(user.id, current_session_id)
+            .await?)
+    }
+
+    /// Revoking the current session signs the user out
+    pub async fn revoke(&self, user: &User, id: String) -> Result<(), Error> {
+        if self.sessions.revoke_session(user.id, id.clone()).await? {
+            Ok(())
+        } else {
+            Err(Error::NotFound { id })
+        }
+    }
+
+    pub async fn revoke_others(
+        &self,
+        user: &User,
+        current_session_id: String,
+    ) -> Result<(), Error> {
+        Ok(self
+            .sessions
+            .revoke_other_sessions(user.id, current_session_id)
+            .await?)
+    }
+}
@@ -1,8 +1,11 @@
-use entity::enums::CorrectionStatus;
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{CorrectionStatus, EntityType};
 use macros::{ApiError, IntoErrorSchema};
 
 use crate::domain::correction::{
-    NewCorrection, NewCorrectionMeta, {self},
+    CorrectionPolicy, NewCorrection, NewCorrectionMeta, {self},
 };
 use crate::domain::repository::TransactionManager;
 use crate::domain::song::model::{NewSong, Song};
@@ -12,6 +15,7 @@ use crate::infra::error::Error;
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub correction_policy: Arc<CorrectionPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -67,6 +71,28 @@ where
     }
 }
 
+impl<R> Service<R>
+where
+    R: Repo + correction::Repo,
+{
+    /// The song as it was at the moment, `None` if it wasn't created yet
+    pub async fn find_as_of(
+        &self,
+        id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<Song>, Error> {
+        let Some(history_id) = self
+            .repo
+            .find_history_id_at(EntityType::Song, id, at)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.repo.find_history(id, history_id).await?)
+    }
+}
+
 impl<R, TR> Service<R>
 where
     R: TransactionManager<TransactionRepository = TR>,
@@ -78,10 +104,22 @@ where
     ) -> Result<(), CreateError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+        super::correction::check_duplicates(
+            &tx_repo,
+            &self.correction_policy.duplicate,
+            &correction.data,
+            correction.acknowledge_duplicates,
+        )
+        .await?;
+
         let entity_id = TxRepo::create(&tx_repo, &correction.data).await?;
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create(NewCorrectionMeta::<NewSong> {
@@ -91,7 +129,10 @@ where
                 status: CorrectionStatus::Approved,
                 entity_id,
                 history_id,
+                base_history_id: None,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -108,10 +149,15 @@ where
     ) -> Result<(), UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         // Create song history from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .upsert(NewCorrectionMeta::<NewSong> {
@@ -120,7 +166,10 @@ where
                 status: CorrectionStatus::Pending,
                 entity_id: id,
                 history_id,
+                base_history_id: correction.base_history_id,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use entity::enums::CorrectionStatus;
 use macros::{ApiError, IntoErrorSchema};
 
 use crate::domain::correction::{
-    NewCorrection, NewCorrectionMeta, {self},
+    CorrectionPolicy, NewCorrection, NewCorrectionMeta, {self},
 };
 use crate::domain::repository::TransactionManager;
 use crate::domain::song_lyrics::model::{
@@ -16,6 +18,7 @@ use crate::infra::error::Error;
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub correction_policy: Arc<CorrectionPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -94,10 +97,15 @@ where
 
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         let entity_id = TxRepo::create(&tx_repo, &correction.data).await?;
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create(NewCorrectionMeta::<NewSongLyrics> {
@@ -107,7 +115,10 @@ where
                 status: CorrectionStatus::Approved,
                 entity_id,
                 history_id,
+                base_history_id: None,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -127,10 +138,15 @@ where
 
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         // Create song lyrics history from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .upsert(NewCorrectionMeta::<NewSongLyrics> {
@@ -139,7 +155,10 @@ where
                 status: CorrectionStatus::Pending,
                 entity_id: id,
                 history_id,
+                base_history_id: correction.base_history_id,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
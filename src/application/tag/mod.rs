@@ -1,18 +1,25 @@
-use entity::enums::CorrectionStatus;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{CorrectionStatus, EntityType};
+use entity::sea_orm_active_enums::TagType;
 use macros::{ApiError, IntoErrorSchema};
 
 use crate::domain::correction::{
-    NewCorrection, NewCorrectionMeta, {self},
+    CorrectionPolicy, NewCorrection, NewCorrectionMeta, {self},
 };
 use crate::domain::repository::TransactionManager;
 use crate::domain::tag::Tag;
-use crate::domain::tag::model::NewTag;
+use crate::domain::tag::model::{NewTag, TagTree};
 use crate::domain::tag::repo::{Repo, TxRepo};
 use crate::infra::error::Error;
 
 #[derive(Clone)]
 pub struct Service<R> {
     pub repo: R,
+    pub correction_policy: Arc<CorrectionPolicy>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -35,6 +42,11 @@ pub enum UpsertCorrectionError {
     },
     #[snafu(transparent)]
     Infra { source: crate::infra::Error },
+    #[snafu(display("Relation to tag #{related_tag_id} would create a cycle"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    CyclicRelation { related_tag_id: i32 },
 }
 
 impl<E> From<E> for CreateError
@@ -56,8 +68,14 @@ where
 }
 
 impl<R> Service<R> {
-    pub const fn new(repo: R) -> Self {
-        Self { repo }
+    pub const fn new(
+        repo: R,
+        correction_policy: Arc<CorrectionPolicy>,
+    ) -> Self {
+        Self {
+            repo,
+            correction_policy,
+        }
     }
 }
 
@@ -78,6 +96,46 @@ where
             .await
             .map_err(Error::from)
     }
+
+    pub async fn find_ancestors(
+        &self,
+        id: i32,
+    ) -> Result<Option<TagTree>, Error> {
+        self.repo.find_ancestors(id).await.map_err(Error::from)
+    }
+
+    pub async fn find_descendants(
+        &self,
+        id: i32,
+    ) -> Result<Option<TagTree>, Error> {
+        self.repo.find_descendants(id).await.map_err(Error::from)
+    }
+
+    pub async fn find_tree(&self, r#type: TagType) -> Result<TagTree, Error> {
+        self.repo.find_tree(r#type).await.map_err(Error::from)
+    }
+}
+
+impl<R> Service<R>
+where
+    R: Repo + correction::Repo,
+{
+    /// The tag as it was at the moment, `None` if it wasn't created yet
+    pub async fn find_as_of(
+        &self,
+        id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<Tag>, Error> {
+        let Some(history_id) = self
+            .repo
+            .find_history_id_at(EntityType::Tag, id, at)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.repo.find_history(id, history_id).await?)
+    }
 }
 
 impl<R, TR> Service<R>
@@ -91,11 +149,16 @@ where
     ) -> Result<(), CreateError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
         let entity_id = TxRepo::create(&tx_repo, &correction.data).await?;
 
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo);
+        let correction_service = super::correction::Service::new(
+            tx_repo,
+            self.correction_policy.clone(),
+        );
 
         correction_service
             .create(NewCorrectionMeta::<NewTag> {
@@ -103,8 +166,11 @@ where
                 r#type: correction.r#type,
                 entity_id,
                 history_id,
+                base_history_id: None,
                 status: CorrectionStatus::Approved,
                 description: correction.description,
+                sources: correction.sources,
+                draft_id: correction.draft_id,
                 phantom: std::marker::PhantomData,
             })
             .await?;
@@ -121,12 +187,31 @@ where
     ) -> Result<(), UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        super::correction::check_references(&tx_repo, &correction.data).await?;
+
+        let descendants = tx_repo
+            .find_descendants(id)
+            .await?
+            .map_or_else(HashSet::new, |tree| {
+                tree.tags.into_iter().map(|tag| tag.id).collect()
+            });
+
+        if let Some(related_tag_id) =
+            correction.data.find_cyclic_relation(id, &descendants)
+        {
+            return Err(UpsertCorrectionError::CyclicRelation {
+                related_tag_id,
+            });
+        }
+
         // Create tag history from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
         {
-            let correction_service =
-                super::correction::Service::new(tx_repo.clone());
+            let correction_service = super::correction::Service::new(
+                tx_repo.clone(),
+                self.correction_policy.clone(),
+            );
 
             correction_service
                 .upsert(NewCorrectionMeta::<NewTag> {
@@ -134,8 +219,11 @@ where
                     r#type: correction.r#type,
                     entity_id: id,
                     history_id,
+                    base_history_id: correction.base_history_id,
                     status: CorrectionStatus::Pending,
                     description: correction.description,
+                    sources: correction.sources,
+                    draft_id: correction.draft_id,
                     phantom: std::marker::PhantomData,
                 })
                 .await?;
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use chrono::Utc;
+use macros::{ApiError, IntoErrorSchema};
+
+use crate::domain::repository::{Transaction, TransactionManager};
+use crate::domain::two_factor::{
+    RecoveryCodes, Repo, TotpEnrollment, TotpSecret, TwoFactorCode,
+    TwoFactorPolicy, UserTotp, hash_recovery_code,
+};
+use crate::domain::user::User;
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R> {
+    pub repo: R,
+    pub policy: Arc<TwoFactorPolicy>,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(display("Two-factor authentication is already enabled"))]
+    #[api_error(
+        status_code = StatusCode::CONFLICT,
+    )]
+    AlreadyEnabled,
+    #[snafu(display("Two-factor authentication is not enabled"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    NotEnabled,
+    #[snafu(display("No two-factor enrollment to confirm"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    NotEnrolled,
+    #[snafu(display("Invalid two-factor code"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    InvalidCode,
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+/// Accepts a code from the authenticator app or an unused recovery code,
+/// either only once
+pub(crate) async fn verify_code(
+    repo: &impl Repo,
+    totp: &UserTotp,
+    code: &TwoFactorCode,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    if code.is_recovery_code() {
+        return repo
+            .use_recovery_code(totp.user_id, &hash_recovery_code(&code.code))
+            .await;
+    }
+
+    match totp.secret.verify(code.code.trim(), unix_time()) {
+        Some(step) => repo.use_totp_step(totp.user_id, step).await,
+        None => Ok(false),
+    }
+}
+
+fn unix_time() -> u64 {
+    Utc::now().timestamp().unsigned_abs()
+}
+
+impl<R> Service<R>
+where
+    R: Repo + TransactionManager,
+    R::TransactionRepository: Repo,
+{
+    /// Replaces an enrollment that wasn't confirmed
+    pub async fn enroll(&self, user: &User) -> Result<TotpEnrollment, Error> {
+        if self
+            .repo
+            .find_totp(user.id)
+            .await?
+            .is_some_and(|totp| totp.is_enabled())
+        {
+            return Err(Error::AlreadyEnabled);
+        }
+
+        let secret = TotpSecret::generate();
+
+        self.repo.save_pending_totp(user.id, &secret).await?;
+
+        Ok(TotpEnrollment {
+            secret: secret.to_base32(),
+            provisioning_uri: secret
+                .provisioning_uri(&self.policy.issuer, &user.name),
+        })
+    }
+
+    /// Enables two-factor authentication once the authenticator app shows
+    /// the right code
+    pub async fn confirm(
+        &self,
+        user: &User,
+        code: TwoFactorCode,
+    ) -> Result<RecoveryCodes, Error> {
+        let totp = self
+            .repo
+            .find_totp(user.id)
+            .await?
+            .ok_or(Error::NotEnrolled)?;
+
+        if totp.is_enabled() {
+            return Err(Error::AlreadyEnabled);
+        }
+
+        let step = totp
+            .secret
+            .verify(code.code.trim(), unix_time())
+            .ok_or(Error::InvalidCode)?;
+
+        let recovery_codes = RecoveryCodes::generate();
+
+        let tx_repo = self.repo.begin().await?;
+
+        tx_repo.enable_totp(user.id, step).await?;
+        tx_repo
+            .replace_recovery_codes(user.id, recovery_codes.hashes())
+            .await?;
+
+        tx_repo.commit().await?;
+
+        Ok(recovery_codes)
+    }
+
+    /// Invalidates the previous recovery codes
+    pub async fn regenerate_recovery_codes(
+        &self,
+        user: &User,
+        code: TwoFactorCode,
+    ) -> Result<RecoveryCodes, Error> {
+        self.verify_enabled(user, &code).await?;
+
+        let recovery_codes = RecoveryCodes::generate();
+
+        self.repo
+            .replace_recovery_codes(user.id, recovery_codes.hashes())
+            .await?;
+
+        Ok(recovery_codes)
+    }
+
+    pub async fn disable(
+        &self,
+        user: &User,
+        code: TwoFactorCode,
+    ) -> Result<(), Error> {
+        self.verify_enabled(user, &code).await?;
+
+        let tx_repo = self.repo.begin().await?;
+
+        tx_repo.delete_totp(user.id).await?;
+
+        tx_repo.commit().await?;
+
+        Ok(())
+    }
+
+    async fn verify_enabled(
+        &self,
+        user: &User,
+        code: &TwoFactorCode,
+    ) -> Result<(), Error> {
+        let totp = self
+            .repo
+            .find_totp(user.id)
+            .await?
+            .filter(UserTotp::is_enabled)
+            .ok_or(Error::NotEnabled)?;
+
+        if verify_code(&self.repo, &totp, code).await? {
+            Ok(())
+        } else {
+            Err(Error::InvalidCode)
+        }
+    }
+}
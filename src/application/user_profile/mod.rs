@@ -1,29 +1,45 @@
 use libfp::BifunctorExt;
 
+use super::contribution;
+use crate::domain::contribution::{Cache, Repo};
 use crate::domain::user;
 use crate::domain::user::{User, UserProfile};
 use crate::infra::error::Error;
 
 #[derive(Clone)]
-pub struct Service<R> {
+pub struct Service<R, C> {
     repo: R,
+    contributions: contribution::Service<R, C>,
 }
 
-impl<R> Service<R> {
-    pub const fn new(repo: R) -> Self {
-        Self { repo }
+impl<R: Clone, C> Service<R, C> {
+    pub fn new(repo: R, cache: C) -> Self {
+        Self {
+            contributions: contribution::Service {
+                repo: repo.clone(),
+                cache,
+            },
+            repo,
+        }
     }
 }
 
-impl<R> Service<R>
+impl<R, C> Service<R, C>
 where
-    R: user::ProfileRepository,
+    R: user::ProfileRepository + Repo + Sync,
+    C: Cache + Sync,
 {
     pub async fn find_by_name(
         &self,
         name: &str,
     ) -> Result<Option<UserProfile>, Error> {
-        self.repo.find_by_name(name).await.bimap_into()
+        let Some(mut profile) = self.repo.find_by_name(name).await? else {
+            return Ok(None);
+        };
+
+        profile.contributions = self.contributions.stats(profile.id).await?;
+
+        Ok(Some(profile))
     }
 
     pub async fn with_following(
@@ -0,0 +1,140 @@
+use axum::http::StatusCode;
+use macros::{ApiError, IntoErrorSchema};
+
+use super::authz::authorize;
+use super::error::Unauthorized;
+use crate::domain::model::auth::Permission;
+use crate::domain::repository::{Cursor, Paginated};
+use crate::domain::user::User;
+use crate::domain::webhook::model::generate_secret;
+use crate::domain::webhook::{
+    CreatedWebhook, NewWebhook, Repo, Webhook, WebhookDelivery, WebhookEvent,
+    WebhookPolicy,
+};
+use crate::infra;
+
+#[derive(Clone)]
+pub struct Service<R> {
+    pub repo: R,
+    pub policy: WebhookPolicy,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+    #[snafu(transparent)]
+    Unauthorized { source: Unauthorized },
+    #[snafu(display("Webhook #{id} not found"))]
+    #[api_error(
+        status_code = StatusCode::NOT_FOUND,
+    )]
+    NotFound { id: i32 },
+    #[snafu(display("Invalid webhook url, expected an http or https url"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    InvalidUrl,
+    #[snafu(display("Webhooks must subscribe to at least one event"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    NoEvents,
+    #[snafu(display("Users can't have more than {max} webhooks"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    TooManyWebhooks { max: u32 },
+}
+
+impl<A> From<A> for Error
+where
+    A: Into<infra::Error>,
+{
+    default fn from(err: A) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+impl<R> Service<R>
+where
+    R: Repo + Sync,
+{
+    pub async fn create(
+        &self,
+        user: &User,
+        webhook: NewWebhook,
+    ) -> Result<CreatedWebhook, Error> {
+        let is_http = url::Url::parse(&webhook.url)
+            .is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+
+        if !is_http {
+            return Err(Error::InvalidUrl);
+        }
+
+        if webhook.events.is_empty() {
+            return Err(Error::NoEvents);
+        }
+
+        let max = self.policy.max_webhooks_per_owner;
+        let count = self.repo.find_webhooks(user.id).await?.len();
+
+        if count >= max as usize {
+            return Err(Error::TooManyWebhooks { max });
+        }
+
+        let secret = generate_secret();
+        let webhook = self
+            .repo
+            .create_webhook(user.id, secret.clone(), webhook)
+            .await?;
+
+        Ok(CreatedWebhook { webhook, secret })
+    }
+
+    pub async fn find(&self, user: &User) -> Result<Vec<Webhook>, Error> {
+        Ok(self.repo.find_webhooks(user.id).await?)
+    }
+
+    pub async fn delete(&self, user: &User, id: i32) -> Result<(), Error> {
+        self.find_owned(user, id).await?;
+
+        Ok(self.repo.delete_webhook(id).await?)
+    }
+
+    pub async fn find_deliveries(
+        &self,
+        user: &User,
+        id: i32,
+        pagination: Cursor,
+    ) -> Result<Paginated<WebhookDelivery>, Error> {
+        self.find_owned(user, id).await?;
+
+        Ok(self.repo.find_deliveries(id, pagination).await?)
+    }
+
+    /// Queues a ping, even if the webhook isn't subscribed to them
+    pub async fn ping(&self, user: &User, id: i32) -> Result<(), Error> {
+        self.find_owned(user, id).await?;
+
+        Ok(self
+            .repo
+            .enqueue_for(id, WebhookEvent::Ping { webhook_id: id })
+            .await?)
+    }
+
+    /// Webhooks of other users can be managed by admins only
+    async fn find_owned(&self, user: &User, id: i32) -> Result<Webhook, Error> {
+        let webhook = self
+            .repo
+            .find_webhook(id)
+            .await?
+            .ok_or(Error::NotFound { id })?;
+
+        if webhook.owner_id != user.id {
+            authorize(user, Permission::ManageUser)?;
+        }
+
+        Ok(webhook)
+    }
+}
@@ -32,6 +32,9 @@ mod share {
     pub const RELEASE_COVER_IMAGE_MIN_RATIO: f64 = 1.0;
     pub const RELEASE_COVER_IMAGE_MAX_RATIO: f64 = 1.0;
 
+    // Correction
+    pub const CORRECTION_SOURCE_NOTE_MAX_LENGTH: usize = 1000;
+
     // User
     pub const AVATAR_MAX_FILE_SIZE: u64 = 10 * 1024 * 1024; // 10 mib
     pub const AVATAR_MIN_FILE_SIZE: u64 = 10 * 1024; // 10 kib
@@ -0,0 +1,7 @@
+pub mod model;
+pub use model::{
+    AccountExport, ExportedComment, ExportedCorrection, ExportedList,
+    ExportedListItem, ExportedRevision, anonymized_name,
+};
+pub mod repo;
+pub use repo::{Repo, TxRepo};
@@ -0,0 +1,220 @@
+use std::io::{Cursor, Write};
+
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{
+    CommentState, CommentTarget, CorrectionStatus, CorrectionType, EntityType,
+};
+use serde::Serialize;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::domain::model::auth::UserRole;
+use crate::domain::user::User;
+
+/// Hyphens aren't allowed in usernames, so nobody can sign up as a deleted
+/// user
+pub fn anonymized_name(user_id: i32) -> String {
+    format!("deleted-{user_id}")
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportedProfile {
+    pub id: i32,
+    pub name: String,
+    pub bio: Option<String>,
+    pub last_login: DateTime<FixedOffset>,
+    pub roles: Vec<UserRole>,
+}
+
+impl From<User> for ExportedProfile {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+            bio: user.bio.map(Into::into),
+            last_login: user.last_login,
+            roles: user.roles,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportedRevision {
+    pub entity_history_id: i32,
+    pub description: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportedCorrection {
+    pub id: i32,
+    pub status: CorrectionStatus,
+    pub r#type: CorrectionType,
+    pub entity_type: EntityType,
+    pub entity_id: i32,
+    pub created_at: DateTime<FixedOffset>,
+    pub handled_at: Option<DateTime<FixedOffset>>,
+    /// Only the revisions written by the user
+    pub revisions: Vec<ExportedRevision>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportedComment {
+    pub id: i32,
+    pub content: String,
+    pub state: CommentState,
+    pub target: CommentTarget,
+    pub target_id: i32,
+    pub parent_id: Option<i32>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportedListItem {
+    pub entity_type: EntityType,
+    pub entity_id: Option<i32>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportedList {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub is_public: bool,
+    pub items: Vec<ExportedListItem>,
+}
+
+/// Everything a user has written, to be handed out on request
+#[derive(Clone, Debug)]
+pub struct AccountExport {
+    pub profile: ExportedProfile,
+    pub corrections: Vec<ExportedCorrection>,
+    pub comments: Vec<ExportedComment>,
+    pub lists: Vec<ExportedList>,
+}
+
+impl AccountExport {
+    /// A zip archive with a JSON file per kind of data, and the images under
+    /// `images/` by their file name
+    pub fn into_archive(
+        self,
+        images: Vec<(String, Vec<u8>)>,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+
+        let files = [
+            ("profile.json", serde_json::to_vec_pretty(&self.profile)?),
+            (
+                "corrections.json",
+                serde_json::to_vec_pretty(&self.corrections)?,
+            ),
+            ("comments.json", serde_json::to_vec_pretty(&self.comments)?),
+            ("lists.json", serde_json::to_vec_pretty(&self.lists)?),
+        ];
+
+        for (name, bytes) in files {
+            zip.start_file(name, options)?;
+            zip.write_all(&bytes)?;
+        }
+
+        for (name, bytes) in images {
+            // Already compressed
+            zip.start_file(
+                format!("images/{name}"),
+                options.compression_method(zip::CompressionMethod::Stored),
+            )?;
+            zip.write_all(&bytes)?;
+        }
+
+        Ok(zip.finish()?.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+
+    use zip::ZipArchive;
+
+    use super::*;
+
+    #[test]
+    fn archive_has_data_and_images() {
+        let export = AccountExport {
+            profile: ExportedProfile {
+                id: 1,
+                name: "alice".to_owned(),
+                bio: Some("Hi".to_owned()),
+                last_login: DateTime::parse_from_rfc3339(
+                    "2025-01-15T12:00:00Z",
+                )
+                .unwrap(),
+                roles: vec![],
+            },
+            corrections: vec![],
+            comments: vec![],
+            lists: vec![ExportedList {
+                id: 2,
+                name: "Favorites".to_owned(),
+                description: String::new(),
+                is_public: true,
+                items: vec![ExportedListItem {
+                    entity_type: EntityType::Song,
+                    entity_id: Some(3),
+                    description: None,
+                }],
+            }],
+        };
+
+        let archive = export
+            .into_archive(vec![("avatar.webp".to_owned(), vec![1, 2, 3])])
+            .unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(archive)).unwrap();
+
+        let mut names = archive.file_names().collect::<Vec<_>>();
+        names.sort_unstable();
+
+        assert_eq!(
+            names,
+            [
+                "comments.json",
+                "corrections.json",
+                "images/avatar.webp",
+                "lists.json",
+                "profile.json"
+            ]
+        );
+
+        let mut profile = String::new();
+        archive
+            .by_name("profile.json")
+            .unwrap()
+            .read_to_string(&mut profile)
+            .unwrap();
+        let profile: serde_json::Value =
+            serde_json::from_str(&profile).unwrap();
+
+        assert_eq!(profile["name"], "alice");
+
+        let mut lists = String::new();
+        archive
+            .by_name("lists.json")
+            .unwrap()
+            .read_to_string(&mut lists)
+            .unwrap();
+        let lists: serde_json::Value = serde_json::from_str(&lists).unwrap();
+
+        assert_eq!(lists[0]["items"][0]["entity_id"], 3);
+
+        let mut avatar = vec![];
+        archive
+            .by_name("images/avatar.webp")
+            .unwrap()
+            .read_to_end(&mut avatar)
+            .unwrap();
+
+        assert_eq!(avatar, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,30 @@
+use super::model::{ExportedComment, ExportedCorrection, ExportedList};
+use crate::domain::repository::{Connection, Transaction};
+
+pub trait Repo: Connection {
+    /// The corrections the user is the author of
+    async fn export_corrections(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExportedCorrection>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn export_comments(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExportedComment>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn export_lists(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExportedList>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+pub trait TxRepo: Transaction {
+    /// Removes the personal data of the user and renames them, but keeps the
+    /// row. Corrections, comments and moderation records still refer to it,
+    /// so their history stays intact
+    async fn anonymize_user(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
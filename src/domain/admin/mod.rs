@@ -0,0 +1,7 @@
+pub mod model;
+pub use model::{
+    AuditLogEntry, AuditLogFilter, NewAuditLogEntry, NewRole,
+    NewUserSuspension, Role, UserSuspension,
+};
+pub mod repo;
+pub use repo::Repo;
@@ -0,0 +1,68 @@
+use chrono::{DateTime, FixedOffset};
+use entity::enums::AuditAction;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::model::auth::PermissionGrant;
+
+/// Roles other than the built-in ones are created by admins
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct Role {
+    pub id: i32,
+    pub name: String,
+    pub permissions: Vec<PermissionGrant>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewRole {
+    pub name: String,
+}
+
+/// Suspended users can't sign in and their sessions are revoked
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct UserSuspension {
+    pub id: i32,
+    pub user_id: i32,
+    pub reason: String,
+    pub suspended_until: DateTime<FixedOffset>,
+    /// The admin who suspended the user
+    pub created_by: i32,
+    pub created_at: DateTime<FixedOffset>,
+    /// Set if the suspension was lifted before it ended
+    pub lifted_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewUserSuspension {
+    pub reason: String,
+    pub suspended_until: DateTime<FixedOffset>,
+}
+
+/// A record of an admin action on a user or a role
+#[derive(Serialize, ToSchema)]
+pub struct AuditLogEntry {
+    pub id: i32,
+    pub actor_id: i32,
+    pub action: AuditAction,
+    /// `None` for actions on roles
+    pub target_user_id: Option<i32>,
+    /// Details of the action, eg. the role granted or the suspension reason
+    #[schema(value_type = Object)]
+    pub data: Value,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+pub struct NewAuditLogEntry {
+    pub actor_id: i32,
+    pub action: AuditAction,
+    pub target_user_id: Option<i32>,
+    pub data: Value,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct AuditLogFilter {
+    pub actor_id: Option<i32>,
+    pub target_user_id: Option<i32>,
+    pub action: Option<AuditAction>,
+}
@@ -0,0 +1,89 @@
+use super::model::{
+    AuditLogEntry, AuditLogFilter, NewAuditLogEntry, NewRole,
+    NewUserSuspension, Role, UserSuspension,
+};
+use crate::domain::model::auth::PermissionGrant;
+use crate::domain::repository::{Connection, Cursor, Paginated};
+
+#[trait_variant::make(Send)]
+pub trait Repo: Connection {
+    async fn find_roles(
+        &self,
+    ) -> Result<Vec<Role>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_role(
+        &self,
+        id: i32,
+    ) -> Result<Option<Role>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn role_name_exists(
+        &self,
+        name: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn create_role(
+        &self,
+        role: NewRole,
+    ) -> Result<Role, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the role already has the permission
+    async fn grant_permission(
+        &self,
+        role_id: i32,
+        grant: PermissionGrant,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the role didn't have the permission
+    async fn revoke_permission(
+        &self,
+        role_id: i32,
+        grant: PermissionGrant,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the user already has the role
+    async fn grant_role(
+        &self,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the user didn't have the role
+    async fn revoke_role(
+        &self,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The suspension in effect, if any
+    async fn find_active_suspension(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<UserSuspension>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn create_suspension(
+        &self,
+        user_id: i32,
+        created_by: i32,
+        suspension: NewUserSuspension,
+    ) -> Result<UserSuspension, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn lift_suspension(
+        &self,
+        id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn create_audit_log(
+        &self,
+        entry: NewAuditLogEntry,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Newest first
+    async fn find_audit_log(
+        &self,
+        filter: AuditLogFilter,
+        pagination: Cursor,
+    ) -> Result<
+        Paginated<AuditLogEntry>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+}
@@ -8,7 +8,9 @@ use url::Url;
 use utoipa::ToSchema;
 
 use super::Tenure;
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{
+    CorrectionEntity, Reference, ReferenceKind, References,
+};
 use crate::domain::shared::model::{
     DateWithPrecision, EntityIdent, Location, NewLocalizedName,
 };
@@ -83,6 +85,58 @@ impl CorrectionEntity for NewArtist {
     fn entity_type() -> EntityType {
         EntityType::Artist
     }
+
+    fn references(&self) -> Vec<Reference> {
+        let mut refs = References::default();
+
+        refs.many(
+            "aliases",
+            ReferenceKind::Artist,
+            self.aliases.iter().flatten().copied(),
+        )
+        .nested(
+            "localized_names",
+            self.localized_names.iter().flatten(),
+            |refs, name| {
+                refs.one(
+                    "language_id",
+                    ReferenceKind::Language,
+                    name.language_id,
+                );
+            },
+        )
+        .nested(
+            "memberships",
+            self.memberships.iter().flatten(),
+            |refs, membership| {
+                refs.one(
+                    "artist_id",
+                    ReferenceKind::Artist,
+                    membership.artist_id,
+                )
+                .many(
+                    "roles",
+                    ReferenceKind::CreditRole,
+                    membership.roles.iter().copied(),
+                );
+            },
+        );
+
+        refs.into_vec()
+    }
+
+    fn names(&self) -> Vec<String> {
+        std::iter::once(&self.name)
+            .chain(self.text_aliases.iter().flatten())
+            .map(ToString::to_string)
+            .chain(
+                self.localized_names
+                    .iter()
+                    .flatten()
+                    .map(|name| name.name.clone()),
+            )
+            .collect()
+    }
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -45,6 +45,13 @@ pub trait Repo {
         filter: FindManyFilter,
         common: CommonFilter,
     ) -> Result<Vec<Artist>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The artist as recorded in one of its histories
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Artist>, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 pub trait TxRepo: Repo + Transaction
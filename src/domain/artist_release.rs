@@ -42,6 +42,8 @@ pub struct AppearanceQuery {
 
 pub struct CreditQuery {
     pub artist_id: i32,
+    /// Only credits of this role or any of its sub roles
+    pub role_id: Option<i32>,
     pub pagination: Cursor,
 }
 
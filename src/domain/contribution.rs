@@ -0,0 +1,269 @@
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use entity::enums::{CorrectionStatus, EntityType};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::repository::Connection;
+
+/// Corrections of one entity type authored by a user
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EntityContributions {
+    pub entity_type: EntityType,
+    /// Including the pending ones
+    pub submitted: u64,
+    pub approved: u64,
+    pub rejected: u64,
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema,
+)]
+pub struct ContributorStats {
+    pub corrections: Vec<EntityContributions>,
+    /// Submitted to the image queues
+    pub images_uploaded: u64,
+    pub first_contribution_at: Option<DateTime<FixedOffset>>,
+    pub last_contribution_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Authored corrections of one entity type in one status
+#[derive(Clone, Debug)]
+pub struct CorrectionTally {
+    pub entity_type: EntityType,
+    pub status: CorrectionStatus,
+    pub count: u64,
+    pub first_at: DateTime<FixedOffset>,
+    pub last_at: DateTime<FixedOffset>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ImageTally {
+    pub count: u64,
+    pub first_at: Option<DateTime<FixedOffset>>,
+    pub last_at: Option<DateTime<FixedOffset>>,
+}
+
+impl ContributorStats {
+    pub fn tally(
+        corrections: Vec<CorrectionTally>,
+        images: ImageTally,
+    ) -> Self {
+        let mut stats = Self {
+            images_uploaded: images.count,
+            first_contribution_at: images.first_at,
+            last_contribution_at: images.last_at,
+            ..Default::default()
+        };
+
+        for tally in corrections {
+            let index = if let Some(index) = stats
+                .corrections
+                .iter()
+                .position(|entry| entry.entity_type == tally.entity_type)
+            {
+                index
+            } else {
+                stats.corrections.push(EntityContributions {
+                    entity_type: tally.entity_type,
+                    submitted: 0,
+                    approved: 0,
+                    rejected: 0,
+                });
+                stats.corrections.len() - 1
+            };
+            let entry = &mut stats.corrections[index];
+
+            entry.submitted += tally.count;
+
+            match tally.status {
+                CorrectionStatus::Approved => entry.approved += tally.count,
+                CorrectionStatus::Rejected => entry.rejected += tally.count,
+                CorrectionStatus::Pending => {}
+            }
+
+            stats.first_contribution_at = Some(
+                stats
+                    .first_contribution_at
+                    .map_or(tally.first_at, |first| first.min(tally.first_at)),
+            );
+            stats.last_contribution_at = Some(
+                stats
+                    .last_contribution_at
+                    .map_or(tally.last_at, |last| last.max(tally.last_at)),
+            );
+        }
+
+        stats
+            .corrections
+            .sort_by_key(|entry| std::cmp::Reverse(entry.submitted));
+
+        stats
+    }
+}
+
+/// Rolling windows, a week is the last 7 days
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardWindow {
+    Week,
+    Month,
+    #[default]
+    AllTime,
+}
+
+impl LeaderboardWindow {
+    pub fn since(self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Week => Some(now - TimeDelta::days(7)),
+            Self::Month => Some(now - TimeDelta::days(30)),
+            Self::AllTime => None,
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::AllTime => "all_time",
+        }
+    }
+}
+
+/// Ranked by approved corrections, ties share a rank
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LeaderboardEntry {
+    pub rank: u64,
+    pub name: String,
+    pub approved: u64,
+    pub submitted: u64,
+}
+
+pub trait Repo: Connection {
+    async fn find_correction_tallies(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<CorrectionTally>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_image_tally(
+        &self,
+        user_id: i32,
+    ) -> Result<ImageTally, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Corrections submitted since the given time
+    async fn find_leaderboard(
+        &self,
+        since: Option<DateTime<Utc>>,
+        limit: u64,
+    ) -> Result<Vec<LeaderboardEntry>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// The aggregations are too heavy to run for every profile view
+pub trait Cache {
+    async fn find_stats(
+        &self,
+        user_id: i32,
+    ) -> Result<
+        Option<ContributorStats>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    async fn save_stats(
+        &self,
+        user_id: i32,
+        stats: &ContributorStats,
+        ttl: TimeDelta,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_leaderboard(
+        &self,
+        window: LeaderboardWindow,
+    ) -> Result<
+        Option<Vec<LeaderboardEntry>>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    async fn save_leaderboard(
+        &self,
+        window: LeaderboardWindow,
+        entries: &[LeaderboardEntry],
+        ttl: TimeDelta,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn at(time: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(time).unwrap()
+    }
+
+    #[test]
+    fn tally_by_entity_type() {
+        let stats = ContributorStats::tally(
+            vec![
+                CorrectionTally {
+                    entity_type: EntityType::Artist,
+                    status: CorrectionStatus::Approved,
+                    count: 3,
+                    first_at: at("2025-02-01T00:00:00Z"),
+                    last_at: at("2025-03-01T00:00:00Z"),
+                },
+                CorrectionTally {
+                    entity_type: EntityType::Song,
+                    status: CorrectionStatus::Pending,
+                    count: 1,
+                    first_at: at("2025-04-01T00:00:00Z"),
+                    last_at: at("2025-04-01T00:00:00Z"),
+                },
+                CorrectionTally {
+                    entity_type: EntityType::Artist,
+                    status: CorrectionStatus::Rejected,
+                    count: 2,
+                    first_at: at("2025-02-15T00:00:00Z"),
+                    last_at: at("2025-02-20T00:00:00Z"),
+                },
+            ],
+            ImageTally {
+                count: 4,
+                first_at: Some(at("2025-01-01T00:00:00Z")),
+                last_at: Some(at("2025-01-02T00:00:00Z")),
+            },
+        );
+
+        assert_eq!(
+            stats.corrections,
+            [
+                EntityContributions {
+                    entity_type: EntityType::Artist,
+                    submitted: 5,
+                    approved: 3,
+                    rejected: 2,
+                },
+                EntityContributions {
+                    entity_type: EntityType::Song,
+                    submitted: 1,
+                    approved: 0,
+                    rejected: 0,
+                },
+            ]
+        );
+        assert_eq!(stats.images_uploaded, 4);
+        assert_eq!(
+            stats.first_contribution_at,
+            Some(at("2025-01-01T00:00:00Z"))
+        );
+        assert_eq!(
+            stats.last_contribution_at,
+            Some(at("2025-04-01T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn no_contributions() {
+        assert_eq!(
+            ContributorStats::tally(vec![], ImageTally::default()),
+            ContributorStats::default()
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use axum::http::StatusCode;
+use chrono::{DateTime, FixedOffset};
+use entity::enums::EntityType;
+use macros::{ApiError, IntoErrorSchema};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use super::{Correction, CorrectionSource};
+
+/// Entities created together in one transaction and reviewed as one unit
+#[derive(Deserialize, ToSchema)]
+pub struct NewChangeset {
+    pub description: String,
+    pub entities: Vec<NewChangesetEntity>,
+    /// Create the entities even if similar entities exist
+    #[serde(default)]
+    pub acknowledge_duplicates: bool,
+    /// Sources of every entity in the changeset
+    #[serde(default)]
+    pub sources: Vec<CorrectionSource>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewChangesetEntity {
+    /// Client id of the entity, eg. `$artist1`. Starts with `$` and is
+    /// unique in the changeset
+    pub temp_id: String,
+    pub entity_type: EntityType,
+    /// The new entity, in the format of its own create endpoint. Strings
+    /// equal to a temp id of the changeset are replaced with the id of that
+    /// entity
+    #[schema(value_type = Object)]
+    pub data: Value,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct Changeset {
+    pub id: i32,
+    pub created_at: DateTime<FixedOffset>,
+    /// In the order they were created and applied
+    pub entities: Vec<ChangesetEntity>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ChangesetEntity {
+    pub temp_id: String,
+    pub correction: Correction,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum ChangesetError {
+    #[snafu(display("Changeset has no entities"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    Empty,
+    #[snafu(display("Temp id {temp_id} doesn't start with `$`"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    InvalidTempId { temp_id: String },
+    #[snafu(display("Temp id {temp_id} is used by more than one entity"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    DuplicateTempId { temp_id: String },
+    #[snafu(display(
+        "Entities {} reference each other in a cycle",
+        temp_ids.join(", ")
+    ))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    Cycle { temp_ids: Vec<String> },
+    #[snafu(display("Invalid entity {temp_id}: {message}"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    InvalidEntity { temp_id: String, message: String },
+}
+
+/// Indexes of the entities in the order they have to be created, so that
+/// every entity comes after the entities it references. Otherwise keeps the
+/// order of the request
+pub fn creation_order(
+    entities: &[NewChangesetEntity],
+) -> Result<Vec<usize>, ChangesetError> {
+    if entities.is_empty() {
+        return Err(ChangesetError::Empty);
+    }
+
+    let mut temp_ids = HashSet::new();
+
+    for entity in entities {
+        if !entity.temp_id.starts_with('$') {
+            return Err(ChangesetError::InvalidTempId {
+                temp_id: entity.temp_id.clone(),
+            });
+        }
+
+        if !temp_ids.insert(entity.temp_id.as_str()) {
+            return Err(ChangesetError::DuplicateTempId {
+                temp_id: entity.temp_id.clone(),
+            });
+        }
+    }
+
+    let mut dependencies = entities
+        .iter()
+        .map(|entity| {
+            let mut refs = BTreeSet::new();
+            collect_refs(&entity.data, &temp_ids, &mut refs);
+            refs
+        })
+        .collect::<Vec<_>>();
+
+    let mut order = Vec::with_capacity(entities.len());
+
+    while order.len() < entities.len() {
+        let Some(next) = (0..entities.len())
+            .find(|idx| !order.contains(idx) && dependencies[*idx].is_empty())
+        else {
+            return Err(ChangesetError::Cycle {
+                temp_ids: (0..entities.len())
+                    .filter(|idx| !order.contains(idx))
+                    .map(|idx| entities[idx].temp_id.clone())
+                    .collect(),
+            });
+        };
+
+        for refs in &mut dependencies {
+            refs.remove(entities[next].temp_id.as_str());
+        }
+
+        order.push(next);
+    }
+
+    Ok(order)
+}
+
+fn collect_refs<'a>(
+    value: &Value,
+    temp_ids: &HashSet<&'a str>,
+    refs: &mut BTreeSet<&'a str>,
+) {
+    match value {
+        Value::String(str) => {
+            if let Some(temp_id) = temp_ids.get(str.as_str()) {
+                refs.insert(temp_id);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_refs(value, temp_ids, refs);
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values() {
+                collect_refs(value, temp_ids, refs);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Replaces temp ids in the data with the ids of the created entities
+pub fn resolve_temp_ids(value: &mut Value, ids: &HashMap<String, i32>) {
+    match value {
+        Value::String(str) => {
+            if let Some(id) = ids.get(str) {
+                *value = Value::from(*id);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                resolve_temp_ids(value, ids);
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                resolve_temp_ids(value, ids);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn entity(temp_id: &str, data: Value) -> NewChangesetEntity {
+        NewChangesetEntity {
+            temp_id: temp_id.to_owned(),
+            entity_type: EntityType::Artist,
+            data,
+        }
+    }
+
+    #[test]
+    fn references_come_first() {
+        let entities = [
+            entity("$release", json!({ "artists": ["$artist", 5] })),
+            entity("$song", json!({ "artists": ["$artist"] })),
+            entity("$artist", json!({ "name": "artist" })),
+            entity("$release2", json!({ "tracks": [{ "song_id": "$song" }] })),
+        ];
+
+        assert_eq!(creation_order(&entities).unwrap(), [2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn invalid_changesets() {
+        assert!(matches!(creation_order(&[]), Err(ChangesetError::Empty)));
+
+        assert!(matches!(
+            creation_order(&[entity("artist", json!({}))]),
+            Err(ChangesetError::InvalidTempId { .. })
+        ));
+
+        assert!(matches!(
+            creation_order(&[entity("$a", json!({})), entity("$a", json!({}))]),
+            Err(ChangesetError::DuplicateTempId { .. })
+        ));
+
+        let Err(ChangesetError::Cycle { temp_ids }) = creation_order(&[
+            entity("$a", json!({ "aliases": ["$b"] })),
+            entity("$b", json!({ "aliases": ["$a"] })),
+            entity("$c", json!({})),
+        ]) else {
+            panic!("Cycle should be detected")
+        };
+
+        assert_eq!(temp_ids, ["$a", "$b"]);
+    }
+
+    #[test]
+    fn temp_ids_are_resolved() {
+        let mut data = json!({
+            "name": "$unknown",
+            "artists": ["$artist", 3],
+            "tracks": [{ "song_id": "$song" }],
+        });
+
+        resolve_temp_ids(
+            &mut data,
+            &HashMap::from([
+                ("$artist".to_owned(), 1),
+                ("$song".to_owned(), 2),
+            ]),
+        );
+
+        assert_eq!(
+            data,
+            json!({
+                "name": "$unknown",
+                "artists": [1, 3],
+                "tracks": [{ "song_id": 2 }],
+            })
+        );
+    }
+}
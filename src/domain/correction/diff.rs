@@ -0,0 +1,116 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+#[derive(Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct FieldDiff {
+    pub field: String,
+    /// Value the correction was edited from
+    pub base: Value,
+    /// Value of the entity now
+    pub current: Value,
+    /// Value proposed by the correction
+    pub proposed: Value,
+    /// Both the entity and the correction changed the field, differently
+    pub conflict: bool,
+}
+
+/// Compares the top level fields of three snapshots of an entity, fields
+/// changed by neither side are omitted
+pub fn three_way_diff(
+    base: &Value,
+    current: &Value,
+    proposed: &Value,
+) -> Vec<FieldDiff> {
+    let fields = [base, current, proposed]
+        .into_iter()
+        .filter_map(Value::as_object)
+        .flat_map(|object| object.keys())
+        .collect::<BTreeSet<_>>();
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let get = |value: &Value| {
+                value.get(field).cloned().unwrap_or(Value::Null)
+            };
+            let (base, current, proposed) =
+                (get(base), get(current), get(proposed));
+
+            let current_changed = current != base;
+            let proposed_changed = proposed != base;
+
+            (current_changed || proposed_changed).then(|| FieldDiff {
+                field: field.clone(),
+                conflict: current_changed
+                    && proposed_changed
+                    && current != proposed,
+                base,
+                current,
+                proposed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn untouched_fields_are_omitted() {
+        let base = json!({ "name": "a", "tracks": [1, 2] });
+
+        assert_eq!(three_way_diff(&base, &base, &base), vec![]);
+    }
+
+    #[test]
+    fn conflicting_changes() {
+        let base = json!({ "name": "a", "date": null, "tracks": [1] });
+        let current = json!({ "name": "b", "date": "2020", "tracks": [1] });
+        let proposed = json!({ "name": "c", "date": "2020", "tracks": [1, 2] });
+
+        let diff = three_way_diff(&base, &current, &proposed);
+
+        assert_eq!(
+            diff,
+            vec![
+                FieldDiff {
+                    field: "date".to_owned(),
+                    base: Value::Null,
+                    current: json!("2020"),
+                    proposed: json!("2020"),
+                    conflict: false,
+                },
+                FieldDiff {
+                    field: "name".to_owned(),
+                    base: json!("a"),
+                    current: json!("b"),
+                    proposed: json!("c"),
+                    conflict: true,
+                },
+                FieldDiff {
+                    field: "tracks".to_owned(),
+                    base: json!([1]),
+                    current: json!([1]),
+                    proposed: json!([1, 2]),
+                    conflict: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_fields_are_null() {
+        let diff =
+            three_way_diff(&json!({}), &json!({ "name": "b" }), &json!({}));
+
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].base, Value::Null);
+        assert!(!diff[0].conflict);
+    }
+}
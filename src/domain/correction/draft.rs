@@ -0,0 +1,124 @@
+use chrono::{DateTime, FixedOffset, TimeDelta};
+use entity::enums::EntityType;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use super::CorrectionSource;
+use crate::domain::repository::Connection;
+
+/// A correction saved by its author without being submitted. The data is
+/// only validated on submission, so it can be incomplete
+#[derive(Serialize, ToSchema)]
+pub struct CorrectionDraft {
+    pub id: i32,
+    pub author_id: i32,
+    pub entity_type: EntityType,
+    /// The entity the draft corrects, `None` if it creates a new one
+    pub entity_id: Option<i32>,
+    /// The entity history the draft was edited from
+    pub base_history_id: Option<i32>,
+    pub description: String,
+    /// Partial data in the format of the create or update endpoint of the
+    /// entity type
+    #[schema(value_type = Object)]
+    pub data: Value,
+    pub sources: Vec<CorrectionSource>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+    /// Saving the draft again postpones it
+    pub expires_at: DateTime<FixedOffset>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewCorrectionDraft {
+    pub entity_type: EntityType,
+    /// The entity to correct, `None` to create a new one
+    pub entity_id: Option<i32>,
+    /// See `/{entity_type}/{id}/latest-history`
+    pub base_history_id: Option<i32>,
+    #[serde(default)]
+    pub description: String,
+    #[schema(value_type = Object)]
+    pub data: Value,
+    #[serde(default)]
+    pub sources: Vec<CorrectionSource>,
+}
+
+pub trait DraftRepo: Connection {
+    /// Expired drafts are not found
+    async fn find_draft(
+        &self,
+        id: i32,
+    ) -> Result<Option<CorrectionDraft>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Unexpired drafts of the author, most recently saved first
+    async fn find_drafts_by_author(
+        &self,
+        author_id: i32,
+    ) -> Result<Vec<CorrectionDraft>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn create_draft(
+        &self,
+        author_id: i32,
+        draft: NewCorrectionDraft,
+        expires_at: DateTime<FixedOffset>,
+    ) -> Result<CorrectionDraft, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn update_draft(
+        &self,
+        id: i32,
+        draft: NewCorrectionDraft,
+        expires_at: DateTime<FixedOffset>,
+    ) -> Result<CorrectionDraft, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns whether the draft existed
+    async fn delete_draft(
+        &self,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns the number of deleted drafts
+    async fn delete_expired_drafts(
+        &self,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// How long unsubmitted drafts are kept
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct DraftPolicy {
+    /// Days after the draft was last saved
+    pub lifetime_days: u32,
+}
+
+impl Default for DraftPolicy {
+    fn default() -> Self {
+        Self { lifetime_days: 30 }
+    }
+}
+
+impl DraftPolicy {
+    pub fn expires_at(
+        self,
+        saved_at: DateTime<FixedOffset>,
+    ) -> DateTime<FixedOffset> {
+        saved_at + TimeDelta::days(self.lifetime_days.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn drafts_expire_after_lifetime() {
+        let policy = DraftPolicy { lifetime_days: 30 };
+        let saved_at =
+            DateTime::parse_from_rfc3339("2025-01-15T12:00:00+08:00").unwrap();
+
+        assert_eq!(
+            policy.expires_at(saved_at),
+            DateTime::parse_from_rfc3339("2025-02-14T12:00:00+08:00").unwrap()
+        );
+    }
+}
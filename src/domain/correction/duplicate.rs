@@ -0,0 +1,33 @@
+use entity::enums::EntityType;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// An existing entity the new entity might be a duplicate of
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DuplicateCandidate {
+    pub entity_type: EntityType,
+    pub id: i32,
+    pub name: String,
+    /// Highest trigram similarity between the names of both entities
+    pub similarity: f32,
+}
+
+/// How similar the name of a new entity has to be to an existing one to be
+/// reported as a possible duplicate
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct DuplicatePolicy {
+    /// Trigram similarity between 0 and 1. Matches are first filtered by
+    /// the `%` operator of `pg_trgm`, so values below its
+    /// `pg_trgm.similarity_threshold` have no effect
+    pub similarity_threshold: f32,
+    pub max_candidates: u64,
+}
+
+impl Default for DuplicatePolicy {
+    fn default() -> Self {
+        Self {
+            similarity_threshold: 0.5,
+            max_candidates: 10,
+        }
+    }
+}
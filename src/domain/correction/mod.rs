@@ -1,18 +1,63 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, FixedOffset};
 use entity::enums::EntityType;
 
+mod changeset;
+mod diff;
+mod draft;
+mod duplicate;
 pub mod model;
+mod patch;
+mod policy;
+mod protection;
+mod reference;
+mod source;
 
+pub use changeset::{
+    Changeset, ChangesetEntity, ChangesetError, NewChangeset, creation_order,
+    resolve_temp_ids,
+};
+pub use diff::{FieldDiff, three_way_diff};
+pub use draft::{CorrectionDraft, DraftPolicy, DraftRepo, NewCorrectionDraft};
+pub use duplicate::{DuplicateCandidate, DuplicatePolicy};
 pub use entity::enums::CorrectionStatus;
 pub use model::*;
+pub use patch::CorrectionPatch;
+pub use policy::{CorrectionPolicy, ReviewPolicy};
+pub use protection::{EntityProtection, NewEntityProtection, ProtectionRepo};
+pub use reference::{
+    Reference, ReferenceKind, ReferenceProblem, References, find_problems,
+    group_ids,
+};
+pub use source::{CorrectionSource, SourceError, SourcePolicy};
 
+use super::artist::model::NewArtist;
+use super::credit_role::NewCreditRole;
+use super::event::NewEvent;
+use super::label::NewLabel;
 use super::model::auth::CorrectionApprover;
+use super::release::NewRelease;
 use super::repository::Transaction;
+use super::song::model::NewSong;
+use super::song_lyrics::NewSongLyrics;
+use super::tag::NewTag;
 use super::user::User;
+use super::{live, webhook};
 use crate::infra;
 use crate::infra::error::Error;
 
 pub trait CorrectionEntity {
     fn entity_type() -> EntityType;
+
+    /// Ids of other entities the payload references
+    fn references(&self) -> Vec<Reference>;
+
+    /// Names a new entity is checked for duplicates by, entity types without
+    /// names return none
+    fn names(&self) -> Vec<String> {
+        vec![]
+    }
 }
 
 pub struct CorrectionFilter {
@@ -45,17 +90,125 @@ impl CorrectionFilter {
     }
 }
 
-pub trait Repo: super::repository::Connection {
+pub trait Repo: super::repository::Connection + ProtectionRepo {
     async fn find_one(
         &self,
         filter: CorrectionFilter,
     ) -> Result<Option<Correction>, Box<dyn std::error::Error + Send + Sync>>;
 
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<Correction>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_users(
+        &self,
+        correction_id: i32,
+    ) -> Result<Vec<CorrectionUser>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_votes(
+        &self,
+        correction_id: i32,
+    ) -> Result<Vec<CorrectionVote>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_latest_revision(
+        &self,
+        correction_id: i32,
+    ) -> Result<
+        Option<CorrectionRevision>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    /// Oldest first
+    async fn find_revisions(
+        &self,
+        correction_id: i32,
+    ) -> Result<Vec<CorrectionRevision>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// History id of the latest approved revision of the entity, which is
+    /// what the entity currently is
+    async fn find_current_history_id(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// History id of the latest revision of the entity approved before the
+    /// moment, which is what the entity was then
+    async fn find_history_id_at(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The history record and its direct relations as a json object
+    async fn find_history_snapshot(
+        &self,
+        entity_type: EntityType,
+        history_id: i32,
+    ) -> Result<
+        Option<serde_json::Value>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    /// The history record in the input form of the entity type, the same
+    /// data the create and update endpoints take
+    async fn find_history_input(
+        &self,
+        entity_type: EntityType,
+        history_id: i32,
+    ) -> Result<
+        Option<serde_json::Value>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    /// Whether the user is the author or a co-author of the correction
     async fn is_author(
         &self,
         user: &User,
         correction: &Correction,
     ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Size of the entity used to decide whether it is high impact, see
+    /// [`ReviewPolicy`]
+    async fn entity_size(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Number of approved corrections authored by the user
+    async fn count_approved(
+        &self,
+        user_id: i32,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_changeset(
+        &self,
+        id: i32,
+    ) -> Result<Option<Changeset>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The ids of the kind that exist, out of `ids`
+    async fn find_existing_ids(
+        &self,
+        kind: ReferenceKind,
+        ids: &BTreeSet<i32>,
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Existing entities with names similar to `names`, most similar first
+    async fn find_duplicates(
+        &self,
+        entity_type: EntityType,
+        names: &[String],
+        policy: &DuplicatePolicy,
+    ) -> Result<Vec<DuplicateCandidate>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Id of the changeset the correction is part of
+    async fn find_changeset_id(
+        &self,
+        correction_id: i32,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 pub trait ApproveCorrectionContext: Send + Sync {
@@ -78,11 +231,21 @@ pub trait ApproveCorrectionContext: Send + Sync {
     fn credit_role_repo(self) -> Self::CreditRoleRepo;
 }
 
-pub trait TxRepo: Repo {
+/// The repository itself serves as the context of approvals made in the
+/// same transaction, eg. auto approvals
+pub trait TxRepo:
+    Repo
+    + ApproveCorrectionContext
+    + DraftRepo
+    + live::TxRepo
+    + webhook::Repo
+    + Clone
+{
+    /// Returns the id of the new correction
     async fn create(
         &self,
         meta: NewCorrectionMeta<impl CorrectionEntity>,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
 
     async fn update(
         &self,
@@ -90,6 +253,32 @@ pub trait TxRepo: Repo {
         meta: NewCorrectionMeta<impl CorrectionEntity>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Casts or replaces the vote of the user, who becomes a reviewer
+    async fn vote(
+        &self,
+        correction_id: i32,
+        user_id: i32,
+        vote: NewCorrectionVote,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn add_co_author(
+        &self,
+        correction_id: i32,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn reject(
+        &self,
+        correction_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Groups the corrections, given with their temp ids in the order they
+    /// are applied, into a changeset
+    async fn create_changeset(
+        &self,
+        corrections: &[(String, i32)],
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
+
     async fn approve<Ctx>(
         &self,
         correction_id: i32,
@@ -108,3 +297,14 @@ where
 
     async fn create_history(&self, data: &T) -> Result<i32, Error>;
 }
+
+/// Repository able to create every kind of entity a changeset can contain
+pub trait ChangesetRepo = TxRepo
+    + CorrectionEntityRepo<NewArtist>
+    + CorrectionEntityRepo<NewLabel>
+    + CorrectionEntityRepo<NewRelease>
+    + CorrectionEntityRepo<NewSong>
+    + CorrectionEntityRepo<NewTag>
+    + CorrectionEntityRepo<NewEvent>
+    + CorrectionEntityRepo<NewSongLyrics>
+    + CorrectionEntityRepo<NewCreditRole>;
@@ -1,9 +1,15 @@
 use chrono::{DateTime, FixedOffset};
-use entity::enums::{CorrectionStatus, CorrectionType, EntityType};
+use entity::enums::{
+    CorrectionStatus, CorrectionType, CorrectionUserType, CorrectionVoteType,
+    EntityType,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use super::CorrectionEntity;
+use super::{CorrectionEntity, CorrectionSource};
 use crate::domain::user::User;
 
+#[derive(Serialize, ToSchema)]
 pub struct Correction {
     pub id: i32,
     pub status: CorrectionStatus,
@@ -14,10 +20,54 @@ pub struct Correction {
     pub handled_at: Option<DateTime<FixedOffset>>,
 }
 
+#[derive(Serialize, ToSchema)]
+pub struct CorrectionUser {
+    pub user_id: i32,
+    pub user_type: CorrectionUserType,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CorrectionVote {
+    pub user_id: i32,
+    pub vote: CorrectionVoteType,
+    pub comment: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+/// A correction with everyone involved in it, the review votes and every
+/// revision with its sources
+#[derive(Serialize, ToSchema)]
+pub struct CorrectionDetail {
+    pub correction: Correction,
+    pub users: Vec<CorrectionUser>,
+    pub votes: Vec<CorrectionVote>,
+    /// Oldest first
+    pub revisions: Vec<CorrectionRevision>,
+}
+
+/// The entity changed since the correction was edited from it
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CorrectionConflict {
+    pub correction_id: i32,
+    pub base_history_id: i32,
+    pub current_history_id: i32,
+    pub diff: Vec<super::FieldDiff>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewCorrectionVote {
+    pub vote: CorrectionVoteType,
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
 pub struct CorrectionRevision {
     pub entity_history_id: i32,
     pub author_id: i32,
     pub description: String,
+    /// The entity history the revision was edited from
+    pub base_history_id: Option<i32>,
+    pub sources: Vec<CorrectionSource>,
 }
 
 pub struct NewCorrection<T>
@@ -28,6 +78,11 @@ where
     pub author: User,
     pub description: String,
     pub r#type: CorrectionType,
+    pub base_history_id: Option<i32>,
+    pub acknowledge_duplicates: bool,
+    pub sources: Vec<CorrectionSource>,
+    /// The draft the correction is submitted from
+    pub draft_id: Option<i32>,
 }
 
 // TODO: just use user id and role or use ref
@@ -36,8 +91,15 @@ pub struct NewCorrectionMeta<T> {
     pub r#type: CorrectionType,
     pub entity_id: i32,
     pub history_id: i32,
+    /// The entity history the correction was edited from, `None` skips
+    /// stale edit detection
+    pub base_history_id: Option<i32>,
     pub description: String,
+    pub sources: Vec<CorrectionSource>,
     pub status: CorrectionStatus,
+    /// The draft the correction is submitted from, deleted in the same
+    /// transaction
+    pub draft_id: Option<i32>,
     pub phantom: std::marker::PhantomData<T>,
 }
 
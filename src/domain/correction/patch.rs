@@ -0,0 +1,100 @@
+use serde::Deserialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// Changes to the input form of an entity, see
+/// `/{entity_type}/{id}/latest-history` for the version it applies to
+#[derive(Deserialize, ToSchema)]
+#[serde(tag = "format", content = "patch", rename_all = "snake_case")]
+pub enum CorrectionPatch {
+    /// RFC 6902 JSON Patch, the operations are applied in order and fail as
+    /// a whole
+    JsonPatch(json_patch::Patch),
+    /// RFC 7396 JSON Merge Patch, `null` removes a field
+    MergePatch(Value),
+}
+
+impl CorrectionPatch {
+    pub fn apply(
+        &self,
+        input: &mut Value,
+    ) -> Result<(), json_patch::PatchError> {
+        match self {
+            Self::JsonPatch(patch) => json_patch::patch(input, patch),
+            Self::MergePatch(patch) => {
+                json_patch::merge(input, patch);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn input() -> Value {
+        json!({
+            "title": "Title",
+            "artists": [1, 2],
+            "tracks": [{ "song_id": 1, "duration": 100 }],
+        })
+    }
+
+    #[test]
+    fn json_patch() {
+        let patch: CorrectionPatch = serde_json::from_value(json!({
+            "format": "json_patch",
+            "patch": [
+                { "op": "replace", "path": "/tracks/0/duration", "value": 90 },
+                { "op": "add", "path": "/artists/-", "value": 3 },
+            ],
+        }))
+        .unwrap();
+
+        let mut input = input();
+        patch.apply(&mut input).unwrap();
+
+        assert_eq!(
+            input,
+            json!({
+                "title": "Title",
+                "artists": [1, 2, 3],
+                "tracks": [{ "song_id": 1, "duration": 90 }],
+            })
+        );
+    }
+
+    #[test]
+    fn failed_json_patch_changes_nothing() {
+        let patch: CorrectionPatch = serde_json::from_value(json!({
+            "format": "json_patch",
+            "patch": [
+                { "op": "replace", "path": "/title", "value": "New title" },
+                { "op": "test", "path": "/artists/0", "value": 2 },
+            ],
+        }))
+        .unwrap();
+
+        let mut input = input();
+
+        assert!(patch.apply(&mut input).is_err());
+        assert_eq!(input, self::input());
+    }
+
+    #[test]
+    fn merge_patch() {
+        let patch: CorrectionPatch = serde_json::from_value(json!({
+            "format": "merge_patch",
+            "patch": { "title": "New title", "tracks": null },
+        }))
+        .unwrap();
+
+        let mut input = input();
+        patch.apply(&mut input).unwrap();
+
+        assert_eq!(input, json!({ "title": "New title", "artists": [1, 2] }));
+    }
+}
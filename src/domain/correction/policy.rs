@@ -0,0 +1,274 @@
+use entity::enums::{CorrectionStatus, CorrectionVoteType, EntityType};
+use serde::Deserialize;
+
+use super::{CorrectionVote, DraftPolicy, DuplicatePolicy, SourcePolicy};
+use crate::domain::model::auth::{CorrectionApprover, Permission};
+use crate::domain::user::User;
+
+/// The policies of the correction workflow, sections left out of the config
+/// keep their defaults
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CorrectionPolicy {
+    pub auto_approval: AutoApprovalPolicy,
+    pub review: ReviewPolicy,
+    pub duplicate: DuplicatePolicy,
+    pub draft: DraftPolicy,
+    pub source: SourcePolicy,
+}
+
+/// Decides whether a correction is approved in the same transaction it is
+/// submitted in, instead of waiting in `Pending`
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub struct AutoApprovalPolicy {
+    /// Approve corrections submitted by users who are allowed to approve
+    /// them
+    pub privileged_roles: bool,
+    /// Approve corrections of users who have at least this many approved
+    /// corrections, `None` disables it
+    pub approved_threshold: Option<u64>,
+}
+
+impl AutoApprovalPolicy {
+    pub fn approves_role(
+        &self,
+        author: &User,
+        entity_type: EntityType,
+    ) -> bool {
+        self.privileged_roles
+            && author.can_for(Permission::ApproveCorrection, entity_type)
+    }
+
+    /// Whether the approved correction count of the author is needed to
+    /// make a decision
+    pub fn needs_history(
+        &self,
+        author: &User,
+        entity_type: EntityType,
+    ) -> bool {
+        self.approved_threshold.is_some()
+            && !self.approves_role(author, entity_type)
+    }
+
+    pub fn approves_history(&self, approved_count: u64) -> bool {
+        self.approved_threshold
+            .is_some_and(|threshold| approved_count >= threshold)
+    }
+
+    /// `approved_count` is only consulted if [`Self::needs_history`]
+    pub fn approver(
+        &self,
+        author: &User,
+        entity_type: EntityType,
+        approved_count: Option<u64>,
+    ) -> Option<CorrectionApprover> {
+        (self.approves_role(author, entity_type)
+            || approved_count.is_some_and(|count| self.approves_history(count)))
+        .then(|| CorrectionApprover(author.clone()))
+    }
+}
+
+/// Votes needed to settle a correction, and which entities are considered
+/// high impact. Corrections of high impact entities can only be approved by
+/// votes, never by a single moderator or automatically
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct ReviewPolicy {
+    pub approve_threshold: usize,
+    pub reject_threshold: usize,
+    /// Releases with at least this many tracks are high impact
+    pub release_tracks: Option<u64>,
+    /// Artists with at least this many release and song credits are high
+    /// impact
+    pub artist_credits: Option<u64>,
+}
+
+impl Default for ReviewPolicy {
+    fn default() -> Self {
+        Self {
+            approve_threshold: 3,
+            reject_threshold: 3,
+            release_tracks: None,
+            artist_credits: None,
+        }
+    }
+}
+
+impl ReviewPolicy {
+    /// The size at which an entity of this type becomes high impact, `None`
+    /// if it never does
+    pub const fn impact_threshold(
+        &self,
+        entity_type: EntityType,
+    ) -> Option<u64> {
+        match entity_type {
+            EntityType::Release => self.release_tracks,
+            EntityType::Artist => self.artist_credits,
+            _ => None,
+        }
+    }
+
+    pub fn is_high_impact(&self, entity_type: EntityType, size: u64) -> bool {
+        self.impact_threshold(entity_type)
+            .is_some_and(|threshold| size >= threshold)
+    }
+
+    /// The status the correction should move to, `None` if it stays pending
+    pub fn outcome(
+        &self,
+        votes: &[CorrectionVote],
+    ) -> Option<CorrectionStatus> {
+        let count = |r#type| votes.iter().filter(|x| x.vote == r#type).count();
+
+        if count(CorrectionVoteType::Approve) >= self.approve_threshold {
+            Some(CorrectionStatus::Approved)
+        } else if count(CorrectionVoteType::Reject) >= self.reject_threshold {
+            Some(CorrectionStatus::Rejected)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::model::auth::{
+        CorrectionApprover, PermissionGrant, Permissions,
+    };
+
+    fn user(permissions: Permissions) -> User {
+        User {
+            id: 1,
+            name: "user".to_owned(),
+            password: String::new(),
+            avatar_id: None,
+            profile_banner_id: None,
+            last_login: chrono::Utc::now().into(),
+            roles: vec![],
+            permissions,
+            bio: None,
+        }
+    }
+
+    fn approver_of(entity_type: Option<EntityType>) -> User {
+        user(
+            [PermissionGrant {
+                permission: Permission::ApproveCorrection,
+                entity_type,
+            }]
+            .into_iter()
+            .collect(),
+        )
+    }
+
+    const POLICY: AutoApprovalPolicy = AutoApprovalPolicy {
+        privileged_roles: true,
+        approved_threshold: Some(10),
+    };
+
+    #[test]
+    fn privileged_roles_are_approved() {
+        for author in [approver_of(None), approver_of(Some(EntityType::Song))] {
+            assert!(!POLICY.needs_history(&author, EntityType::Song));
+            assert!(POLICY.approver(&author, EntityType::Song, None).is_some());
+        }
+
+        let lyrics_editor = approver_of(Some(EntityType::SongLyrics));
+
+        assert!(POLICY.needs_history(&lyrics_editor, EntityType::Song));
+        assert!(
+            POLICY
+                .approver(&lyrics_editor, EntityType::Song, None)
+                .is_none()
+        );
+
+        let policy = AutoApprovalPolicy {
+            privileged_roles: false,
+            ..POLICY
+        };
+        let admin = approver_of(None);
+
+        assert!(policy.needs_history(&admin, EntityType::Song));
+        assert!(policy.approver(&admin, EntityType::Song, None).is_none());
+    }
+
+    #[test]
+    fn users_above_threshold_are_approved() {
+        let author = user(Permissions::default());
+        let approver =
+            |count| POLICY.approver(&author, EntityType::Artist, count);
+
+        assert!(POLICY.needs_history(&author, EntityType::Artist));
+        assert!(approver(None).is_none());
+        assert!(approver(Some(9)).is_none());
+        assert!(approver(Some(10)).is_some());
+        assert!(approver(Some(11)).is_some());
+    }
+
+    #[test]
+    fn disabled_policy_approves_nothing() {
+        let policy = AutoApprovalPolicy::default();
+
+        for author in [approver_of(None), user(Permissions::default())] {
+            assert!(!policy.needs_history(&author, EntityType::Artist));
+            assert!(
+                policy
+                    .approver(&author, EntityType::Artist, Some(u64::MAX))
+                    .is_none()
+            );
+        }
+    }
+
+    const REVIEW: ReviewPolicy = ReviewPolicy {
+        approve_threshold: 3,
+        reject_threshold: 2,
+        release_tracks: Some(30),
+        artist_credits: None,
+    };
+
+    fn votes(approve: usize, reject: usize) -> Vec<CorrectionVote> {
+        std::iter::repeat_n(CorrectionVoteType::Approve, approve)
+            .chain(std::iter::repeat_n(CorrectionVoteType::Reject, reject))
+            .zip(1..)
+            .map(|(vote, user_id)| CorrectionVote {
+                user_id,
+                vote,
+                comment: None,
+                created_at: chrono::Utc::now().into(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn high_impact_entities() {
+        assert!(!REVIEW.is_high_impact(EntityType::Release, 29));
+        assert!(REVIEW.is_high_impact(EntityType::Release, 30));
+        assert!(!REVIEW.is_high_impact(EntityType::Artist, u64::MAX));
+        assert!(!REVIEW.is_high_impact(EntityType::Tag, u64::MAX));
+    }
+
+    #[test]
+    fn vote_outcome() {
+        assert_eq!(REVIEW.outcome(&votes(0, 0)), None);
+        assert_eq!(REVIEW.outcome(&votes(2, 1)), None);
+        assert_eq!(
+            REVIEW.outcome(&votes(3, 1)),
+            Some(CorrectionStatus::Approved)
+        );
+        assert_eq!(
+            REVIEW.outcome(&votes(1, 2)),
+            Some(CorrectionStatus::Rejected)
+        );
+    }
+
+    #[test]
+    fn only_approvers_can_vote() {
+        let unit = [EntityType::Release, EntityType::Song];
+        let voter =
+            |voter| CorrectionApprover::from_user(voter, unit.into_iter());
+
+        assert!(voter(user(Permissions::default())).is_none());
+        assert!(voter(approver_of(Some(EntityType::Song))).is_none());
+        assert!(voter(approver_of(None)).is_some());
+    }
+}
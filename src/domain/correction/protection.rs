@@ -0,0 +1,75 @@
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{EntityType, ProtectionLevel};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::model::auth::Permission;
+use crate::domain::repository::Connection;
+use crate::domain::user::User;
+
+/// Restricts the corrections of an entity, eg. after it was vandalized.
+/// Entities without protection are open to everyone
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EntityProtection {
+    pub entity_type: EntityType,
+    pub entity_id: i32,
+    pub level: ProtectionLevel,
+    pub reason: String,
+    /// `None` never expires
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    /// The moderator who protected the entity
+    pub created_by: i32,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewEntityProtection {
+    pub level: ProtectionLevel,
+    pub reason: String,
+    /// `None` never expires
+    pub expires_at: Option<DateTime<FixedOffset>>,
+}
+
+impl EntityProtection {
+    /// Whether the user can submit corrections of the entity
+    pub fn allows(&self, user: &User) -> bool {
+        match self.level {
+            ProtectionLevel::AutoApprovalDisabled => true,
+            ProtectionLevel::ModeratorOnly => {
+                user.can_for(Permission::EditProtected, self.entity_type)
+            }
+        }
+    }
+
+    /// Whether corrections of the entity can skip review
+    pub const fn allows_auto_approval(&self) -> bool {
+        !matches!(self.level, ProtectionLevel::AutoApprovalDisabled)
+    }
+}
+
+pub trait ProtectionRepo: Connection {
+    /// Expired protections are not found
+    async fn find_protection(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<
+        Option<EntityProtection>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    /// Replaces the current protection of the entity
+    async fn set_protection(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+        created_by: i32,
+        protection: NewEntityProtection,
+    ) -> Result<EntityProtection, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn delete_protection(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,197 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use derive_more::Display;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Kinds of entities a correction can reference by id
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReferenceKind {
+    Artist,
+    #[display("Credit role")]
+    CreditRole,
+    Event,
+    Label,
+    Language,
+    Song,
+    Tag,
+}
+
+/// An id in a correction payload, and where it is
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reference {
+    /// Eg. `tracks[3].song_id`
+    pub path: String,
+    pub kind: ReferenceKind,
+    pub id: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ReferenceProblem {
+    pub path: String,
+    pub problem: String,
+}
+
+/// Collects the references of a payload
+#[derive(Default)]
+pub struct References(Vec<Reference>);
+
+impl References {
+    pub fn one(
+        &mut self,
+        path: impl Into<String>,
+        kind: ReferenceKind,
+        id: i32,
+    ) -> &mut Self {
+        self.0.push(Reference {
+            path: path.into(),
+            kind,
+            id,
+        });
+        self
+    }
+
+    /// References in a list, `path` is suffixed with the index
+    pub fn many(
+        &mut self,
+        path: &str,
+        kind: ReferenceKind,
+        ids: impl IntoIterator<Item = i32>,
+    ) -> &mut Self {
+        for (idx, id) in ids.into_iter().enumerate() {
+            self.one(format!("{path}[{idx}]"), kind, id);
+        }
+        self
+    }
+
+    /// References of each item of a list, with their paths prefixed by the
+    /// path of the item
+    pub fn nested<T>(
+        &mut self,
+        path: &str,
+        items: impl IntoIterator<Item = T>,
+        mut f: impl FnMut(&mut Self, T),
+    ) -> &mut Self {
+        for (idx, item) in items.into_iter().enumerate() {
+            let mut item_refs = Self::default();
+            f(&mut item_refs, item);
+
+            self.0
+                .extend(item_refs.0.into_iter().map(|reference| Reference {
+                    path: format!("{path}[{idx}].{}", reference.path),
+                    ..reference
+                }));
+        }
+        self
+    }
+
+    pub fn into_vec(self) -> Vec<Reference> {
+        self.0
+    }
+}
+
+/// Referenced ids grouped by kind, to be looked up in one query per kind
+pub fn group_ids(
+    references: &[Reference],
+) -> BTreeMap<ReferenceKind, BTreeSet<i32>> {
+    let mut groups = BTreeMap::<_, BTreeSet<_>>::new();
+
+    for reference in references {
+        groups
+            .entry(reference.kind)
+            .or_default()
+            .insert(reference.id);
+    }
+
+    groups
+}
+
+/// References whose id is not in `existing`
+pub fn find_problems(
+    references: &[Reference],
+    existing: &HashSet<(ReferenceKind, i32)>,
+) -> Vec<ReferenceProblem> {
+    references
+        .iter()
+        .filter(|reference| !existing.contains(&(reference.kind, reference.id)))
+        .map(|reference| ReferenceProblem {
+            path: reference.path.clone(),
+            problem: format!(
+                "{} #{} doesn't exist",
+                reference.kind, reference.id
+            ),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paths() {
+        let mut refs = References::default();
+
+        refs.many("artists", ReferenceKind::Artist, [1, 2])
+            .nested("tracks", [(3, vec![4])], |refs, (song_id, artists)| {
+                refs.one("song_id", ReferenceKind::Song, song_id).many(
+                    "artists",
+                    ReferenceKind::Artist,
+                    artists,
+                );
+            })
+            .one("language_id", ReferenceKind::Language, 5);
+
+        let paths = refs
+            .into_vec()
+            .into_iter()
+            .map(|reference| reference.path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            [
+                "artists[0]",
+                "artists[1]",
+                "tracks[0].song_id",
+                "tracks[0].artists[0]",
+                "language_id",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_references() {
+        let mut refs = References::default();
+        refs.many("artists", ReferenceKind::Artist, [1, 2, 1]).one(
+            "song_id",
+            ReferenceKind::Song,
+            1,
+        );
+        let refs = refs.into_vec();
+
+        assert_eq!(
+            group_ids(&refs),
+            BTreeMap::from([
+                (ReferenceKind::Artist, BTreeSet::from([1, 2])),
+                (ReferenceKind::Song, BTreeSet::from([1])),
+            ])
+        );
+
+        let existing = HashSet::from([(ReferenceKind::Artist, 1)]);
+
+        assert_eq!(
+            find_problems(&refs, &existing),
+            [
+                ReferenceProblem {
+                    path: "artists[1]".to_owned(),
+                    problem: "Artist #2 doesn't exist".to_owned(),
+                },
+                ReferenceProblem {
+                    path: "song_id".to_owned(),
+                    problem: "Song #1 doesn't exist".to_owned(),
+                },
+            ]
+        );
+    }
+}
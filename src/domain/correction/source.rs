@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use entity::enums::{EntityType, SourceType};
+use macros::{ApiError, IntoErrorSchema};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use utoipa::ToSchema;
+
+use crate::constant::CORRECTION_SOURCE_NOTE_MAX_LENGTH;
+
+/// Where the information of a correction revision comes from
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct CorrectionSource {
+    pub url: Url,
+    pub r#type: SourceType,
+    pub note: Option<String>,
+}
+
+/// Which corrections have to cite sources
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SourcePolicy {
+    /// Corrections of these entity types need at least one source
+    pub required: Vec<EntityType>,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum SourceError {
+    #[snafu(display(
+        "Corrections of {entity_type:?} must cite at least one source"
+    ))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    Required { entity_type: EntityType },
+    #[snafu(display("Source {url} is not a http or https url"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    InvalidUrl { url: Url },
+    #[snafu(display("Source {url} is cited more than once"))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    DuplicateUrl { url: Url },
+    #[snafu(display(
+        "Note of source {url} is longer than {CORRECTION_SOURCE_NOTE_MAX_LENGTH} characters"
+    ))]
+    #[api_error(
+        status_code = StatusCode::BAD_REQUEST,
+    )]
+    NoteTooLong { url: Url },
+}
+
+impl SourcePolicy {
+    pub fn validate(
+        &self,
+        entity_type: EntityType,
+        sources: &[CorrectionSource],
+    ) -> Result<(), SourceError> {
+        if sources.is_empty() && self.required.contains(&entity_type) {
+            return Err(SourceError::Required { entity_type });
+        }
+
+        let mut urls = HashSet::new();
+
+        for source in sources {
+            let url = &source.url;
+
+            if !matches!(url.scheme(), "http" | "https") {
+                return Err(SourceError::InvalidUrl { url: url.clone() });
+            }
+
+            if !urls.insert(url) {
+                return Err(SourceError::DuplicateUrl { url: url.clone() });
+            }
+
+            if source.note.as_ref().is_some_and(|note| {
+                note.chars().count() > CORRECTION_SOURCE_NOTE_MAX_LENGTH
+            }) {
+                return Err(SourceError::NoteTooLong { url: url.clone() });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn source(url: &str) -> CorrectionSource {
+        CorrectionSource {
+            url: url.parse().unwrap(),
+            r#type: SourceType::Booklet,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn required_sources() {
+        let policy = SourcePolicy {
+            required: vec![EntityType::Release],
+        };
+
+        assert!(matches!(
+            policy.validate(EntityType::Release, &[]),
+            Err(SourceError::Required { .. })
+        ));
+        assert!(policy.validate(EntityType::Song, &[]).is_ok());
+        assert!(
+            policy
+                .validate(EntityType::Release, &[source("https://a.com")])
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn invalid_sources() {
+        let policy = SourcePolicy::default();
+
+        assert!(matches!(
+            policy.validate(EntityType::Song, &[source("ftp://a.com/booklet")]),
+            Err(SourceError::InvalidUrl { .. })
+        ));
+
+        assert!(matches!(
+            policy.validate(
+                EntityType::Song,
+                &[source("https://a.com"), source("https://a.com/")]
+            ),
+            Err(SourceError::DuplicateUrl { .. })
+        ));
+
+        let long_note = CorrectionSource {
+            note: Some("a".repeat(CORRECTION_SOURCE_NOTE_MAX_LENGTH + 1)),
+            ..source("https://a.com")
+        };
+
+        assert!(matches!(
+            policy.validate(EntityType::Song, &[long_note]),
+            Err(SourceError::NoteTooLong { .. })
+        ));
+    }
+}
@@ -1,4 +1,7 @@
 pub mod model;
-pub use model::{CreditRole, CreditRoleRef, CreditRoleSummary, NewCreditRole};
+pub use model::{
+    CreditRole, CreditRoleRef, CreditRoleSummary, CreditRoleTree,
+    CreditRoleTreeEdge, NewCreditRole,
+};
 pub mod repo;
 pub use repo::{Repo, TxRepo};
@@ -28,9 +28,26 @@ pub struct CreditRole {
     pub description: String,
 }
 
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CreditRoleTreeEdge {
+    pub role_id: i32,
+    pub super_id: i32,
+}
+
+/// All credit roles and their inheritance, a role may have multiple super roles
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CreditRoleTree {
+    pub roles: Vec<CreditRoleRef>,
+    pub edges: Vec<CreditRoleTreeEdge>,
+}
+
+use std::collections::HashSet;
+
 use entity::enums::EntityType;
 
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{
+    CorrectionEntity, Reference, ReferenceKind, References,
+};
 use crate::domain::shared::model::EntityIdent;
 
 #[derive(Deserialize, ToSchema)]
@@ -41,8 +58,73 @@ pub struct NewCreditRole {
     pub super_roles: Option<Vec<i32>>,
 }
 
+impl NewCreditRole {
+    /// Returns the first super role that would close a loop if this data is
+    /// applied to role `id`, whose current sub roles are `sub_roles`
+    pub fn find_cyclic_super_role(
+        &self,
+        id: i32,
+        sub_roles: &HashSet<i32>,
+    ) -> Option<i32> {
+        self.super_roles
+            .iter()
+            .flatten()
+            .copied()
+            .find(|super_id| *super_id == id || sub_roles.contains(super_id))
+    }
+}
+
 impl CorrectionEntity for NewCreditRole {
     fn entity_type() -> EntityType {
         EntityType::CreditRole
     }
+
+    fn references(&self) -> Vec<Reference> {
+        let mut refs = References::default();
+
+        refs.many(
+            "super_roles",
+            ReferenceKind::CreditRole,
+            self.super_roles.iter().flatten().copied(),
+        );
+
+        refs.into_vec()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn new_credit_role(super_roles: &[i32]) -> NewCreditRole {
+        serde_json::from_value(json!({
+            "name": "Arrangement",
+            "short_description": null,
+            "description": null,
+            "super_roles": super_roles,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn cyclic_super_role() {
+        let sub_roles = HashSet::from([2, 3]);
+
+        assert_eq!(
+            new_credit_role(&[4, 5]).find_cyclic_super_role(1, &sub_roles),
+            None
+        );
+        assert_eq!(
+            new_credit_role(&[4, 3]).find_cyclic_super_role(1, &sub_roles),
+            Some(3)
+        );
+        assert_eq!(
+            new_credit_role(&[1]).find_cyclic_super_role(1, &sub_roles),
+            Some(1)
+        );
+    }
 }
@@ -39,6 +39,23 @@ pub trait Repo: Connection {
         filter: FindManyFilter,
         common: CommonFilter,
     ) -> Result<Vec<K::Output>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Ids of every role inheriting from the role, transitively, excluding itself
+    async fn find_sub_role_ids(
+        &self,
+        id: i32,
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_tree(
+        &self,
+    ) -> Result<CreditRoleTree, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The credit role as recorded in one of its histories
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<CreditRole>, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 pub trait TxRepo: Repo + Transaction
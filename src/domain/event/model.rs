@@ -2,7 +2,7 @@ use entity::enums::EntityType;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{CorrectionEntity, Reference};
 use crate::domain::shared::model::{DateWithPrecision, EntityIdent};
 
 #[serde_with::apply(
@@ -47,4 +47,14 @@ impl CorrectionEntity for NewEvent {
     fn entity_type() -> EntityType {
         EntityType::Event
     }
+
+    fn references(&self) -> Vec<Reference> {
+        vec![]
+    }
+
+    fn names(&self) -> Vec<String> {
+        std::iter::once(self.name.to_string())
+            .chain(self.alternative_names.iter().flatten().cloned())
+            .collect()
+    }
 }
@@ -0,0 +1,9 @@
+pub mod model;
+pub use model::{
+    AuthorizationRequest, ExternalIdentity, OidcConfig, OidcProvider,
+    PendingAuthorization, UserIdentity,
+};
+pub mod provider;
+pub use provider::IdentityProvider;
+pub mod repo;
+pub use repo::Repo;
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use utoipa::ToSchema;
+
+/// Leaves room for a suffix when the name is taken
+const USERNAME_BASE_MAX_LENGTH: usize = 56;
+
+/// An account at an identity provider linked to a user
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct UserIdentity {
+    pub id: i32,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+/// The verified claims of an ID token
+#[derive(Clone, Debug)]
+pub struct ExternalIdentity {
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub preferred_username: Option<String>,
+}
+
+impl ExternalIdentity {
+    /// Names to try for the account created on the first sign in, in order
+    pub fn username_candidates(&self) -> impl Iterator<Item = String> {
+        let base = self
+            .preferred_username
+            .as_deref()
+            .into_iter()
+            .chain(
+                self.email
+                    .as_deref()
+                    .and_then(|email| email.split('@').next()),
+            )
+            .map(|name| {
+                name.chars()
+                    .filter(|char| char.is_alphanumeric() || *char == '_')
+                    .take(USERNAME_BASE_MAX_LENGTH)
+                    .collect::<String>()
+            })
+            .find(|name| !name.is_empty())
+            .unwrap_or_else(|| "user".to_owned());
+
+        std::iter::once(base.clone())
+            .chain((2..).map(move |suffix| format!("{base}_{suffix}")))
+    }
+}
+
+/// Kept in the session from the redirect to the provider until its callback
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingAuthorization {
+    pub provider: String,
+    /// Compared with the state of the callback against CSRF
+    pub state: String,
+    pub nonce: String,
+    pub pkce_verifier: String,
+    /// Links the identity to this user instead of signing in
+    pub link_user_id: Option<i32>,
+}
+
+pub struct AuthorizationRequest {
+    pub url: Url,
+    pub pending: PendingAuthorization,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OidcProviderConfig {
+    /// Shown to users, e.g. on the sign in button
+    pub name: String,
+    /// Where `/.well-known/openid-configuration` is discovered
+    pub issuer_url: String,
+    pub client_id: String,
+    /// `None` for public clients, which rely on PKCE alone
+    pub client_secret: Option<String>,
+}
+
+/// No providers by default, which disables the sign in
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct OidcConfig {
+    /// Providers redirect back to `{redirect_base_url}/oidc/{key}/callback`
+    pub redirect_base_url: String,
+    /// By the key used in urls and stored with linked identities
+    #[serde(default)]
+    pub providers: BTreeMap<String, OidcProviderConfig>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OidcProvider {
+    pub key: String,
+    pub name: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn identity(
+        preferred_username: Option<&str>,
+        email: Option<&str>,
+    ) -> ExternalIdentity {
+        ExternalIdentity {
+            provider: "mock".to_owned(),
+            subject: "1".to_owned(),
+            email: email.map(ToOwned::to_owned),
+            preferred_username: preferred_username.map(ToOwned::to_owned),
+        }
+    }
+
+    #[test]
+    fn username_candidates() {
+        let first_two = |identity: ExternalIdentity| {
+            identity.username_candidates().take(2).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            first_two(identity(Some("Alice"), Some("bob@example.com"))),
+            ["Alice", "Alice_2"]
+        );
+        assert_eq!(
+            first_two(identity(Some("--"), Some("bob.b@example.com"))),
+            ["bobb", "bobb_2"]
+        );
+        assert_eq!(first_two(identity(None, None)), ["user", "user_2"]);
+    }
+}
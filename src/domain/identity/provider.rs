@@ -0,0 +1,23 @@
+use super::model::{
+    AuthorizationRequest, ExternalIdentity, OidcProvider, PendingAuthorization,
+};
+
+/// OIDC providers, signed in to with the authorization code flow
+/// and PKCE
+pub trait IdentityProvider {
+    fn providers(&self) -> Vec<OidcProvider>;
+
+    /// `provider` has to be one of [`Self::providers`]
+    async fn authorize(
+        &self,
+        provider: &str,
+        link_user_id: Option<i32>,
+    ) -> Result<AuthorizationRequest, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Exchanges the code of the callback and verifies the ID token
+    async fn exchange(
+        &self,
+        pending: &PendingAuthorization,
+        code: String,
+    ) -> Result<ExternalIdentity, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,28 @@
+use super::model::{ExternalIdentity, UserIdentity};
+use crate::domain::repository::Connection;
+
+pub trait Repo: Connection {
+    async fn find_identity_owner(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_identities(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<UserIdentity>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn link_identity(
+        &self,
+        user_id: i32,
+        identity: ExternalIdentity,
+    ) -> Result<UserIdentity, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the user has no identity with the id
+    async fn unlink_identity(
+        &self,
+        user_id: i32,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -11,6 +11,13 @@ pub trait Repo: Connection {
         &self,
         filename: &str,
     ) -> Result<Option<Image>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Whether a user, artist or release still shows the image. Images are
+    /// shared by their hash
+    async fn is_in_use(
+        &self,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 pub trait TxRepo: Transaction + Repo {
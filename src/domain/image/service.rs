@@ -371,6 +371,8 @@ pub trait AsyncFileStorage: Send + Sync {
     async fn create(&self, image: NewImage) -> Result<Self::File, Self::Error>;
 
     async fn remove(&self, image: Image) -> Result<(), Self::Error>;
+
+    async fn read(&self, image: &Image) -> Result<Vec<u8>, Self::Error>;
 }
 
 #[derive(Debug, snafu::Snafu, ApiError)]
@@ -1,10 +1,14 @@
 mod model;
 
-pub use model::{ImageQueue, NewImageQueue};
+pub use model::{Error, ImageQueue, NewImageQueue};
 
 use super::repository::Connection;
 
 pub trait Repo: Connection {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<ImageQueue>, Box<dyn std::error::Error + Send + Sync>>;
     async fn create(
         &self,
         model: NewImageQueue,
@@ -1,14 +1,12 @@
 use axum::http::StatusCode;
 use chrono::Utc;
-use collection_ext::Intersection;
 pub use entity::sea_orm_active_enums::ImageQueueStatus;
-use itertools::Itertools;
 use macros::{ApiError, AutoMapper};
 use sea_orm::prelude::DateTimeWithTimeZone;
 use snafu::Snafu;
 
 use crate::domain::image::Image;
-use crate::domain::model::auth::UserRoleEnum;
+use crate::domain::model::auth::Permission;
 use crate::domain::user::User;
 
 #[derive(Debug, Clone, Copy, Snafu, ApiError)]
@@ -36,8 +34,8 @@ pub enum ImageQueueActionEnum {
 
 impl ImageQueueActionEnum {
     #[expect(clippy::unused_self, reason = "maybe needed in future")]
-    const fn required_roles(self) -> &'static [UserRoleEnum] {
-        &[UserRoleEnum::Admin, UserRoleEnum::Moderator]
+    const fn required_permission(self) -> Permission {
+        Permission::ModerateImage
     }
 }
 
@@ -104,14 +102,8 @@ impl ImageQueue {
             return Err(Error::InvalidOperation);
         }
 
-        let user_roles = user
-            .roles
-            .iter()
-            .map(|role| UserRoleEnum::try_from(role.id).unwrap())
-            .collect_vec();
-        let required_roles = action.required_roles();
         // Users also can cancel their image uploads
-        let has_permission = user_roles.intersects(&required_roles)
+        let has_permission = user.can(action.required_permission())
             || action == ImageQueueActionEnum::Cancel
                 && user.id == self.creaded_by;
 
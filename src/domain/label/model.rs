@@ -2,7 +2,9 @@ use entity::enums::EntityType;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{
+    CorrectionEntity, Reference, ReferenceKind, References,
+};
 use crate::domain::shared::model::{
     DateWithPrecision, EntityIdent, LocalizedName, NewLocalizedName,
 };
@@ -37,4 +39,38 @@ impl CorrectionEntity for NewLabel {
     fn entity_type() -> EntityType {
         EntityType::Label
     }
+
+    fn references(&self) -> Vec<Reference> {
+        let mut refs = References::default();
+
+        refs.many(
+            "founders",
+            ReferenceKind::Artist,
+            self.founders.iter().flatten().copied(),
+        )
+        .nested(
+            "localized_names",
+            self.localized_names.iter().flatten(),
+            |refs, name| {
+                refs.one(
+                    "language_id",
+                    ReferenceKind::Language,
+                    name.language_id,
+                );
+            },
+        );
+
+        refs.into_vec()
+    }
+
+    fn names(&self) -> Vec<String> {
+        std::iter::once(self.name.to_string())
+            .chain(
+                self.localized_names
+                    .iter()
+                    .flatten()
+                    .map(|name| name.name.clone()),
+            )
+            .collect()
+    }
 }
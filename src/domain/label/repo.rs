@@ -11,6 +11,13 @@ pub trait Repo: Connection {
         &self,
         keyword: &str,
     ) -> Result<Vec<Label>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The label as recorded in one of its histories
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Label>, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 pub trait TxRepo: Repo + Transaction
@@ -0,0 +1,85 @@
+use entity::enums::EntityType;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::model::auth::Permission;
+use super::notification::Notification;
+use super::repository::Transaction;
+use super::user::User;
+
+/// Pushed to the connected clients of every server instance
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveEvent {
+    /// A correction was submitted or amended and waits for review
+    CorrectionPending {
+        correction_id: i32,
+        entity_type: EntityType,
+        entity_id: i32,
+    },
+    /// An image was submitted to the image queue
+    ImageSubmitted {
+        queue_id: i32,
+    },
+    Notification(Notification),
+}
+
+impl LiveEvent {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::CorrectionPending { .. } => "correction_pending",
+            Self::ImageSubmitted { .. } => "image_submitted",
+            Self::Notification(_) => "notification",
+        }
+    }
+
+    /// Review queues go to the moderators of them, notifications to their
+    /// recipient
+    pub fn is_for(&self, user: &User) -> bool {
+        match self {
+            Self::CorrectionPending { entity_type, .. } => {
+                user.can_for(Permission::ApproveCorrection, *entity_type)
+            }
+            Self::ImageSubmitted { .. } => user.can(Permission::ModerateImage),
+            Self::Notification(notification) => notification.user_id == user.id,
+        }
+    }
+}
+
+pub trait TxRepo: Transaction {
+    /// The event is published once the transaction commits, and dropped if
+    /// it doesn't
+    fn publish(&self, event: LiveEvent);
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::notification::NotificationEvent;
+
+    #[test]
+    fn notification_round_trips() {
+        let event = LiveEvent::Notification(Notification {
+            id: 1,
+            user_id: 2,
+            event: NotificationEvent::ImageApproved { queue_id: 3 },
+            created_at: Utc::now().into(),
+            read_at: None,
+        });
+
+        let json = serde_json::to_string(&event).unwrap();
+        let LiveEvent::Notification(notification) =
+            serde_json::from_str(&json).unwrap()
+        else {
+            panic!("Expected a notification: {json}");
+        };
+
+        assert_eq!(notification.user_id, 2);
+        assert_eq!(
+            notification.event,
+            NotificationEvent::ImageApproved { queue_id: 3 }
+        );
+    }
+}
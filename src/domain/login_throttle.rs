@@ -0,0 +1,458 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// How failed sign ins of one username or ip are slowed down
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct LoginThrottlePolicy {
+    /// Failures before any delay
+    pub free_attempts: u32,
+    /// Doubles with each failure after the free ones
+    pub base_delay_seconds: u32,
+    pub max_delay_seconds: u32,
+    /// Failures until sign ins are locked out
+    pub lockout_threshold: u32,
+    /// Also how long failures are remembered after the last one
+    pub lockout_minutes: u32,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct LoginThrottleConfig {
+    pub username: LoginThrottlePolicy,
+    /// Should be more lenient, many users can share an ip
+    pub ip: LoginThrottlePolicy,
+}
+
+impl Default for LoginThrottleConfig {
+    fn default() -> Self {
+        Self {
+            username: LoginThrottlePolicy {
+                free_attempts: 3,
+                base_delay_seconds: 1,
+                max_delay_seconds: 300,
+                lockout_threshold: 10,
+                lockout_minutes: 15,
+            },
+            ip: LoginThrottlePolicy {
+                free_attempts: 10,
+                base_delay_seconds: 1,
+                max_delay_seconds: 60,
+                lockout_threshold: 50,
+                lockout_minutes: 15,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedLogins {
+    pub count: u32,
+    pub last_failed_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ThrottleKey {
+    Username(String),
+    Ip(String),
+}
+
+impl Display for ThrottleKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Username(name) => write!(f, "user:{name}"),
+            Self::Ip(ip) => write!(f, "ip:{ip}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Throttled {
+    Backoff { retry_at: DateTime<Utc> },
+    LockedOut { until: DateTime<Utc> },
+}
+
+impl Throttled {
+    /// Lockouts take precedence, otherwise the later retry
+    fn max(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::LockedOut { until: a }, Self::LockedOut { until: b }) => {
+                Self::LockedOut { until: a.max(b) }
+            }
+            (locked @ Self::LockedOut { .. }, _)
+            | (_, locked @ Self::LockedOut { .. }) => locked,
+            (Self::Backoff { retry_at: a }, Self::Backoff { retry_at: b }) => {
+                Self::Backoff { retry_at: a.max(b) }
+            }
+        }
+    }
+}
+
+impl LoginThrottlePolicy {
+    fn remember_for(&self) -> TimeDelta {
+        TimeDelta::minutes(self.lockout_minutes.into())
+    }
+
+    fn delay(&self, count: u32) -> TimeDelta {
+        let Some(exponent) = count.checked_sub(self.free_attempts) else {
+            return TimeDelta::zero();
+        };
+
+        let delay = 2u64
+            .checked_pow(exponent)
+            .and_then(|factor| {
+                factor.checked_mul(self.base_delay_seconds.into())
+            })
+            .map_or_else(
+                || self.max_delay_seconds.into(),
+                |delay| delay.min(self.max_delay_seconds.into()),
+            );
+
+        TimeDelta::seconds(delay.try_into().unwrap_or(i64::MAX))
+    }
+
+    pub fn check(
+        &self,
+        failures: FailedLogins,
+        now: DateTime<Utc>,
+    ) -> Option<Throttled> {
+        if failures.count >= self.lockout_threshold {
+            let until = failures.last_failed_at + self.remember_for();
+
+            return (now < until).then_some(Throttled::LockedOut { until });
+        }
+
+        let retry_at = failures.last_failed_at + self.delay(failures.count);
+
+        (now < retry_at).then_some(Throttled::Backoff { retry_at })
+    }
+
+    /// Failures older than the lockout are forgotten
+    pub fn record_failure(
+        &self,
+        failures: Option<FailedLogins>,
+        now: DateTime<Utc>,
+    ) -> FailedLogins {
+        let count = failures
+            .filter(|failures| {
+                now - failures.last_failed_at < self.remember_for()
+            })
+            .map_or(0, |failures| failures.count);
+
+        FailedLogins {
+            count: count.saturating_add(1),
+            last_failed_at: now,
+        }
+    }
+}
+
+#[trait_variant::make(Send)]
+pub trait Repo {
+    async fn find_failures(
+        &self,
+        key: &ThrottleKey,
+    ) -> Result<Option<FailedLogins>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Forgets the failures after `ttl`
+    async fn save_failures(
+        &self,
+        key: &ThrottleKey,
+        failures: FailedLogins,
+        ttl: TimeDelta,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn clear_failures(
+        &self,
+        key: &ThrottleKey,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Counts failed sign ins by username and by ip
+#[derive(Clone)]
+pub struct LoginThrottle<R, C = SystemClock> {
+    repo: R,
+    clock: C,
+    config: LoginThrottleConfig,
+}
+
+impl<R, C> LoginThrottle<R, C>
+where
+    R: Repo + Sync,
+    C: Clock + Sync,
+{
+    pub const fn new(repo: R, clock: C, config: LoginThrottleConfig) -> Self {
+        Self {
+            repo,
+            clock,
+            config,
+        }
+    }
+
+    fn keys(
+        &self,
+        username: &str,
+        ip: Option<&str>,
+    ) -> Vec<(ThrottleKey, LoginThrottlePolicy)> {
+        let mut keys = vec![(
+            ThrottleKey::Username(username.to_owned()),
+            self.config.username,
+        )];
+
+        if let Some(ip) = ip {
+            keys.push((ThrottleKey::Ip(ip.to_owned()), self.config.ip));
+        }
+
+        keys
+    }
+
+    /// Checked before the password, throttled attempts don't count as
+    /// failures
+    pub async fn check(
+        &self,
+        username: &str,
+        ip: Option<&str>,
+    ) -> Result<Option<Throttled>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let now = self.clock.now();
+        let mut throttled: Option<Throttled> = None;
+
+        for (key, policy) in self.keys(username, ip) {
+            if let Some(failures) = self.repo.find_failures(&key).await?
+                && let Some(key_throttled) = policy.check(failures, now)
+            {
+                throttled =
+                    Some(throttled.map_or(key_throttled, |throttled| {
+                        throttled.max(key_throttled)
+                    }));
+            }
+        }
+
+        Ok(throttled)
+    }
+
+    /// Returns the keys that are locked out by this failure
+    pub async fn record_failure(
+        &self,
+        username: &str,
+        ip: Option<&str>,
+    ) -> Result<Vec<ThrottleKey>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let now = self.clock.now();
+        let mut locked_out = vec![];
+
+        for (key, policy) in self.keys(username, ip) {
+            let failures = policy
+                .record_failure(self.repo.find_failures(&key).await?, now);
+
+            self.repo
+                .save_failures(&key, failures, policy.remember_for())
+                .await?;
+
+            if failures.count == policy.lockout_threshold {
+                locked_out.push(key);
+            }
+        }
+
+        Ok(locked_out)
+    }
+
+    /// Failures of the ip are kept, an account of their own mustn't let
+    /// attackers reset it
+    pub async fn record_success(
+        &self,
+        username: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.repo
+            .clear_failures(&ThrottleKey::Username(username.to_owned()))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    struct FakeClock(Mutex<DateTime<Utc>>);
+
+    impl FakeClock {
+        fn advance(&self, delta: TimeDelta) {
+            *self.0.lock().unwrap() += delta;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryRepo(Mutex<HashMap<String, FailedLogins>>);
+
+    impl Repo for MemoryRepo {
+        fn find_failures(
+            &self,
+            key: &ThrottleKey,
+        ) -> impl Future<
+            Output = Result<
+                Option<FailedLogins>,
+                Box<dyn std::error::Error + Send + Sync>,
+            >,
+        > {
+            std::future::ready(Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .get(&key.to_string())
+                .copied()))
+        }
+
+        fn save_failures(
+            &self,
+            key: &ThrottleKey,
+            failures: FailedLogins,
+            _: TimeDelta,
+        ) -> impl Future<Output = Result<(), Box<dyn std::error::Error + Send + Sync>>>
+        {
+            self.0.lock().unwrap().insert(key.to_string(), failures);
+
+            std::future::ready(Ok(()))
+        }
+
+        fn clear_failures(
+            &self,
+            key: &ThrottleKey,
+        ) -> impl Future<Output = Result<(), Box<dyn std::error::Error + Send + Sync>>>
+        {
+            self.0.lock().unwrap().remove(&key.to_string());
+
+            std::future::ready(Ok(()))
+        }
+    }
+
+    const POLICY: LoginThrottlePolicy = LoginThrottlePolicy {
+        free_attempts: 2,
+        base_delay_seconds: 1,
+        max_delay_seconds: 4,
+        lockout_threshold: 6,
+        lockout_minutes: 15,
+    };
+
+    fn throttle() -> LoginThrottle<MemoryRepo, FakeClock> {
+        LoginThrottle::new(
+            MemoryRepo::default(),
+            FakeClock(Mutex::new(
+                DateTime::parse_from_rfc3339("2025-01-15T12:00:00Z")
+                    .unwrap()
+                    .into(),
+            )),
+            LoginThrottleConfig {
+                username: POLICY,
+                ip: LoginThrottlePolicy {
+                    lockout_threshold: 100,
+                    ..POLICY
+                },
+            },
+        )
+    }
+
+    fn seconds_until(
+        throttle: &LoginThrottle<MemoryRepo, FakeClock>,
+        throttled: Option<Throttled>,
+    ) -> Option<i64> {
+        match throttled? {
+            Throttled::Backoff { retry_at } => {
+                Some((retry_at - throttle.clock.now()).num_seconds())
+            }
+            Throttled::LockedOut { .. } => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn backoff_doubles_up_to_the_max() {
+        let throttle = throttle();
+        let mut delays = vec![];
+
+        for _ in 0..5 {
+            throttle.record_failure("alice", None).await.unwrap();
+
+            let throttled = throttle.check("alice", None).await.unwrap();
+
+            delays.push(seconds_until(&throttle, throttled));
+            throttle.clock.advance(TimeDelta::seconds(10));
+        }
+
+        assert_eq!(delays, [None, Some(1), Some(2), Some(4), Some(4)]);
+
+        throttle.record_success("alice").await.unwrap();
+
+        assert_eq!(throttle.check("alice", None).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn lockout_expires() {
+        let throttle = throttle();
+
+        for count in 1..=POLICY.lockout_threshold {
+            let locked_out =
+                throttle.record_failure("alice", Some("::1")).await.unwrap();
+
+            assert_eq!(
+                locked_out.is_empty(),
+                count != POLICY.lockout_threshold
+            );
+        }
+
+        let until = throttle.clock.now() + TimeDelta::minutes(15);
+
+        assert_eq!(
+            throttle.check("alice", None).await.unwrap(),
+            Some(Throttled::LockedOut { until })
+        );
+
+        throttle.clock.advance(TimeDelta::minutes(15));
+
+        assert_eq!(throttle.check("alice", None).await.unwrap(), None);
+
+        // Forgotten by now, so this starts a new count
+        throttle.record_failure("alice", None).await.unwrap();
+
+        assert_eq!(throttle.check("alice", None).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn success_keeps_ip_failures() {
+        let throttle = throttle();
+
+        for name in ["alice", "bob", "carol"] {
+            throttle.record_failure(name, Some("::1")).await.unwrap();
+        }
+
+        throttle.record_success("carol").await.unwrap();
+
+        assert_eq!(
+            seconds_until(
+                &throttle,
+                throttle.check("dave", Some("::1")).await.unwrap()
+            ),
+            Some(2)
+        );
+        assert_eq!(throttle.check("dave", Some("::2")).await.unwrap(), None);
+    }
+}
@@ -1,17 +1,27 @@
+pub mod account;
+pub mod admin;
 pub mod artist;
 pub mod artist_image_queue;
+pub mod contribution;
 pub mod correction;
 pub mod event;
+pub mod identity;
 pub mod image;
 pub mod image_queue;
 pub mod label;
+pub mod live;
+pub mod login_throttle;
 pub mod model;
+pub mod notification;
 pub mod release;
+pub mod report;
 pub mod shared;
 pub mod song;
 pub mod song_lyrics;
 pub mod tag;
+pub mod two_factor;
 pub mod user;
+pub mod webhook;
 pub use shared::*;
 pub mod artist_release;
 pub mod credit_role;
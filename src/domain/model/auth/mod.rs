@@ -1,18 +1,30 @@
 pub use auth_creds::*;
 mod auth_creds;
+pub use permission::*;
+mod permission;
 pub use user_role::*;
 mod user_role;
+use entity::enums::EntityType;
 #[expect(unused_imports)]
 pub use verfication_code::*;
 
 use crate::domain::user::User;
 mod verfication_code;
 
+#[derive(Clone)]
 pub struct CorrectionApprover(pub User);
 
 impl CorrectionApprover {
-    pub fn from_user(user: User) -> Option<Self> {
-        user.has_roles(&[UserRoleEnum::Admin, UserRoleEnum::Moderator])
+    /// The user has to be allowed to approve corrections of every entity
+    /// type
+    pub fn from_user(
+        user: User,
+        mut entity_types: impl Iterator<Item = EntityType>,
+    ) -> Option<Self> {
+        entity_types
+            .all(|entity_type| {
+                user.can_for(Permission::ApproveCorrection, entity_type)
+            })
             .then_some(Self(user))
     }
 }
@@ -0,0 +1,99 @@
+use entity::enums::EntityType;
+pub use entity::enums::Permission;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// A permission granted to a role
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    IntoParams,
+)]
+pub struct PermissionGrant {
+    pub permission: Permission,
+    /// Limits the permission to one entity type, `None` grants it for all
+    /// of them
+    pub entity_type: Option<EntityType>,
+}
+
+/// The permissions a user is granted through their roles
+#[derive(Clone, Debug, Default)]
+pub struct Permissions(Vec<PermissionGrant>);
+
+impl Permissions {
+    /// `entity_type` is `None` for permissions that aren't about an entity,
+    /// which only unrestricted grants allow
+    pub fn allows(
+        &self,
+        permission: Permission,
+        entity_type: Option<EntityType>,
+    ) -> bool {
+        self.0.iter().any(|grant| {
+            grant.permission == permission
+                && grant
+                    .entity_type
+                    .is_none_or(|granted| Some(granted) == entity_type)
+        })
+    }
+
+    /// Drops the grants of the given permissions
+    #[must_use]
+    pub fn without(mut self, permissions: &[Permission]) -> Self {
+        self.0
+            .retain(|grant| !permissions.contains(&grant.permission));
+
+        self
+    }
+}
+
+impl FromIterator<PermissionGrant> for Permissions {
+    fn from_iter<T: IntoIterator<Item = PermissionGrant>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grants_are_scoped_by_entity_type() {
+        let permissions: Permissions = [
+            PermissionGrant {
+                permission: Permission::ApproveCorrection,
+                entity_type: Some(EntityType::SongLyrics),
+            },
+            PermissionGrant {
+                permission: Permission::ModerateImage,
+                entity_type: None,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(permissions.allows(
+            Permission::ApproveCorrection,
+            Some(EntityType::SongLyrics)
+        ));
+        assert!(
+            !permissions.allows(
+                Permission::ApproveCorrection,
+                Some(EntityType::Artist)
+            )
+        );
+        assert!(!permissions.allows(Permission::ApproveCorrection, None));
+
+        assert!(permissions.allows(Permission::ModerateImage, None));
+        assert!(
+            permissions
+                .allows(Permission::ModerateImage, Some(EntityType::Release))
+        );
+        assert!(!permissions.allows(Permission::ManageUser, None));
+    }
+}
@@ -1,5 +1,4 @@
-use sea_orm::DbErr;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use strum::{EnumCount, EnumIter, EnumString};
 use utoipa::ToSchema;
 
@@ -14,6 +13,7 @@ use utoipa::ToSchema;
     EnumString,
     strum::Display,
     Serialize,
+    Deserialize,
     ToSchema,
 )]
 pub enum UserRoleEnum {
@@ -45,31 +45,19 @@ impl TryFrom<i32> for UserRoleEnum {
     }
 }
 
-impl From<UserRole> for UserRoleEnum {
-    fn from(val: UserRole) -> Self {
-        (&val).into()
-    }
-}
-
-impl From<&UserRole> for UserRoleEnum {
-    fn from(val: &UserRole) -> Self {
-        Self::try_from(val.id).expect("Invalid user role id from database")
-    }
-}
-
 #[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct UserRole {
     pub id: i32,
-    #[schema(value_type = UserRoleEnum)]
+    /// The built-in roles are named after [`UserRoleEnum`]
     pub name: String,
 }
 
-impl TryFrom<entity::user_role::Model> for UserRole {
-    type Error = DbErr;
-    fn try_from(value: entity::user_role::Model) -> Result<Self, Self::Error> {
-        Ok(UserRoleEnum::try_from(value.role_id)
-            .map_err(|str| DbErr::Custom(str.to_owned()))?
-            .into())
+impl From<entity::role::Model> for UserRole {
+    fn from(value: entity::role::Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+        }
     }
 }
 
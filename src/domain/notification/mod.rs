@@ -0,0 +1,7 @@
+pub mod model;
+pub use model::{
+    NewNotification, Notification, NotificationEvent, NotificationFilter,
+    NotificationPreference,
+};
+pub mod repo;
+pub use repo::Repo;
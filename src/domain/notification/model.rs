@@ -0,0 +1,142 @@
+use chrono::{DateTime, FixedOffset};
+use entity::enums::EntityType;
+pub use entity::enums::NotificationKind;
+use enumset::EnumSet;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::correction::Correction;
+
+/// What happened, stored as the kind and the data of a notification
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema,
+)]
+#[serde(tag = "kind", content = "data")]
+pub enum NotificationEvent {
+    CorrectionApproved {
+        correction_id: i32,
+        entity_type: EntityType,
+        entity_id: i32,
+    },
+    CorrectionRejected {
+        correction_id: i32,
+        entity_type: EntityType,
+        entity_id: i32,
+    },
+    ImageApproved {
+        queue_id: i32,
+    },
+    ImageRejected {
+        queue_id: i32,
+    },
+    ImageReverted {
+        queue_id: i32,
+    },
+}
+
+impl NotificationEvent {
+    pub const fn correction_approved(correction: &Correction) -> Self {
+        Self::CorrectionApproved {
+            correction_id: correction.id,
+            entity_type: correction.entity_type,
+            entity_id: correction.entity_id,
+        }
+    }
+
+    pub const fn correction_rejected(correction: &Correction) -> Self {
+        Self::CorrectionRejected {
+            correction_id: correction.id,
+            entity_type: correction.entity_type,
+            entity_id: correction.entity_id,
+        }
+    }
+
+    pub const fn kind(&self) -> NotificationKind {
+        match self {
+            Self::CorrectionApproved { .. } => {
+                NotificationKind::CorrectionApproved
+            }
+            Self::CorrectionRejected { .. } => {
+                NotificationKind::CorrectionRejected
+            }
+            Self::ImageApproved { .. } => NotificationKind::ImageApproved,
+            Self::ImageRejected { .. } => NotificationKind::ImageRejected,
+            Self::ImageReverted { .. } => NotificationKind::ImageReverted,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct Notification {
+    pub id: i32,
+    pub user_id: i32,
+    #[serde(flatten)]
+    pub event: NotificationEvent,
+    pub created_at: DateTime<FixedOffset>,
+    pub read_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct NewNotification {
+    pub user_id: i32,
+    pub event: NotificationEvent,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, IntoParams)]
+pub struct NotificationFilter {
+    /// Only the notifications which are not read yet
+    #[serde(default)]
+    pub unread: bool,
+}
+
+/// Kinds are enabled unless the user turned them off
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema,
+)]
+pub struct NotificationPreference {
+    pub kind: NotificationKind,
+    pub enabled: bool,
+}
+
+impl NotificationPreference {
+    /// The preference of every kind, filling in the ones the user never set
+    pub fn complete(saved: &[Self]) -> Vec<Self> {
+        EnumSet::<NotificationKind>::all()
+            .iter()
+            .map(|kind| Self {
+                kind,
+                enabled: saved
+                    .iter()
+                    .find(|preference| preference.kind == kind)
+                    .is_none_or(|preference| preference.enabled),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn event_kind_matches_tag() {
+        let event = NotificationEvent::ImageRejected { queue_id: 1 };
+        let value = serde_json::to_value(event).unwrap();
+
+        assert_eq!(value["kind"], serde_json::to_value(event.kind()).unwrap());
+        assert_eq!(value["data"]["queue_id"], 1);
+    }
+
+    #[test]
+    fn unset_preferences_are_enabled() {
+        let preferences =
+            NotificationPreference::complete(&[NotificationPreference {
+                kind: NotificationKind::ImageApproved,
+                enabled: false,
+            }]);
+
+        assert_eq!(preferences.len(), EnumSet::<NotificationKind>::all().len());
+        assert!(preferences.iter().all(|preference| preference.enabled
+            != (preference.kind == NotificationKind::ImageApproved)));
+    }
+}
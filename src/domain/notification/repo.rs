@@ -0,0 +1,52 @@
+use super::model::{
+    NewNotification, Notification, NotificationFilter, NotificationPreference,
+};
+use crate::domain::repository::{Connection, Cursor, Paginated};
+
+pub trait Repo: Connection {
+    /// Skips the notifications of kinds the recipient turned off, returns the
+    /// created ones
+    async fn create_notifications(
+        &self,
+        notifications: Vec<NewNotification>,
+    ) -> Result<Vec<Notification>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_notifications(
+        &self,
+        user_id: i32,
+        filter: NotificationFilter,
+        pagination: Cursor,
+    ) -> Result<Paginated<Notification>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn count_unread(
+        &self,
+        user_id: i32,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the user has no such notification
+    async fn mark_read(
+        &self,
+        user_id: i32,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn mark_all_read(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Only the preferences the user has set
+    async fn find_preferences(
+        &self,
+        user_id: i32,
+    ) -> Result<
+        Vec<NotificationPreference>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    async fn save_preferences(
+        &self,
+        user_id: i32,
+        preferences: Vec<NotificationPreference>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -3,7 +3,9 @@ use garde::Validate;
 use serde::Deserialize;
 use utoipa::ToSchema;
 
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{
+    CorrectionEntity, Reference, ReferenceKind, References,
+};
 use crate::domain::shared::model::{DateWithPrecision, NewLocalizedTitle};
 
 #[derive(Clone, Validate, Deserialize, ToSchema)]
@@ -56,6 +58,49 @@ impl CorrectionEntity for NewRelease {
     fn entity_type() -> entity::enums::EntityType {
         entity::enums::EntityType::Release
     }
+
+    fn references(&self) -> Vec<Reference> {
+        let mut refs = References::default();
+
+        refs.many(
+            "artists",
+            ReferenceKind::Artist,
+            self.artists.iter().copied(),
+        )
+        .nested("catalog_nums", &self.catalog_nums, |refs, catalog_num| {
+            if let Some(label_id) = catalog_num.label_id {
+                refs.one("label_id", ReferenceKind::Label, label_id);
+            }
+        })
+        .nested("credits", &self.credits, |refs, credit| {
+            refs.one("artist_id", ReferenceKind::Artist, credit.artist_id)
+                .one("role_id", ReferenceKind::CreditRole, credit.role_id);
+        })
+        .many("events", ReferenceKind::Event, self.events.iter().copied())
+        .nested("localized_titles", &self.localized_titles, |refs, title| {
+            refs.one("language_id", ReferenceKind::Language, title.language_id);
+        })
+        .nested("tracks", &self.tracks, |refs, track| {
+            refs.one("song_id", ReferenceKind::Song, track.song_id)
+                .many(
+                    "artists",
+                    ReferenceKind::Artist,
+                    track.artists.iter().copied(),
+                );
+        });
+
+        refs.into_vec()
+    }
+
+    fn names(&self) -> Vec<String> {
+        std::iter::once(self.title.clone())
+            .chain(
+                self.localized_titles
+                    .iter()
+                    .map(|title| title.title.clone()),
+            )
+            .collect()
+    }
 }
 
 #[derive(Clone, ToSchema, Deserialize)]
@@ -1,4 +1,4 @@
-use crate::domain::repository::{Connection, Transaction};
+use crate::domain::repository::{Connection, Cursor, Paginated, Transaction};
 
 pub enum Filter {
     Id(i32),
@@ -20,10 +20,31 @@ pub trait Repo: Connection {
         Vec<super::model::Release>,
         Box<dyn std::error::Error + Send + Sync>,
     >;
+    /// Releases crediting the role or any of its sub roles, optionally only
+    /// for the given artist
+    async fn find_by_credit(
+        &self,
+        role_id: i32,
+        artist_id: Option<i32>,
+        pagination: Cursor,
+    ) -> Result<
+        Paginated<super::model::Release>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
     async fn exist(
         &self,
         id: i32,
     ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The release as recorded in one of its histories
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<
+        Option<super::model::Release>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
 }
 
 pub trait TxRepo: Transaction + Repo
@@ -0,0 +1,6 @@
+pub mod model;
+pub use model::{
+    NewReport, Report, ReportFilter, ReportPolicy, ReportResolution,
+};
+pub mod repo;
+pub use repo::Repo;
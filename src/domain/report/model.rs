@@ -0,0 +1,108 @@
+use chrono::{DateTime, FixedOffset, TimeDelta};
+use entity::enums::{ReportAction, ReportReason, ReportState, ReportTarget};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// A flag raised by a user on bad data or abuse, handled by moderators
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct Report {
+    pub id: i32,
+    pub reporter_id: i32,
+    pub target: ReportTarget,
+    pub target_id: i32,
+    pub reason: ReportReason,
+    pub description: String,
+    pub state: ReportState,
+    /// The moderator who claimed or handled the report
+    pub assignee_id: Option<i32>,
+    /// What was done about the report, set once it is resolved
+    pub action: Option<ReportAction>,
+    /// The correction, comment or user the action refers to
+    pub action_id: Option<i32>,
+    pub note: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub handled_at: Option<DateTime<FixedOffset>>,
+}
+
+impl Report {
+    pub const fn is_open(&self) -> bool {
+        matches!(self.state, ReportState::Open | ReportState::Claimed)
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewReport {
+    pub target: ReportTarget,
+    pub target_id: i32,
+    pub reason: ReportReason,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// How a report was closed
+pub struct ReportResolution {
+    /// `Resolved` or `Dismissed`
+    pub state: ReportState,
+    pub action: Option<ReportAction>,
+    pub action_id: Option<i32>,
+    pub note: Option<String>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ReportFilter {
+    /// Open reports if not set
+    pub state: Option<ReportState>,
+    pub target: Option<ReportTarget>,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+pub struct ReportPolicy {
+    /// Reports a user can submit within the window
+    pub max_reports: u32,
+    pub window_minutes: u32,
+}
+
+impl Default for ReportPolicy {
+    fn default() -> Self {
+        Self {
+            max_reports: 10,
+            window_minutes: 60,
+        }
+    }
+}
+
+impl ReportPolicy {
+    /// Reports submitted after this count towards the limit
+    pub fn window_start(
+        self,
+        now: DateTime<FixedOffset>,
+    ) -> DateTime<FixedOffset> {
+        now - TimeDelta::minutes(self.window_minutes.into())
+    }
+
+    pub fn is_exceeded(self, submitted: u64) -> bool {
+        submitted >= u64::from(self.max_reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_policy_window() {
+        let policy = ReportPolicy {
+            max_reports: 3,
+            window_minutes: 90,
+        };
+        let now =
+            DateTime::parse_from_rfc3339("2025-01-15T12:00:00+08:00").unwrap();
+
+        assert_eq!(
+            policy.window_start(now),
+            DateTime::parse_from_rfc3339("2025-01-15T10:30:00+08:00").unwrap()
+        );
+        assert!(!policy.is_exceeded(2));
+        assert!(policy.is_exceeded(3));
+    }
+}
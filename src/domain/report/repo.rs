@@ -0,0 +1,58 @@
+use chrono::{DateTime, FixedOffset};
+use entity::enums::ReportTarget;
+
+use super::model::{NewReport, Report, ReportFilter, ReportResolution};
+use crate::domain::repository::{Connection, Cursor, Paginated};
+
+pub trait Repo: Connection {
+    async fn create(
+        &self,
+        reporter_id: i32,
+        report: NewReport,
+    ) -> Result<Report, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<Report>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Oldest first
+    async fn find_many(
+        &self,
+        filter: ReportFilter,
+        pagination: Cursor,
+    ) -> Result<Paginated<Report>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn count_by_reporter_since(
+        &self,
+        reporter_id: i32,
+        since: DateTime<FixedOffset>,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Holds back other submissions of the reporter until the transaction
+    /// ends
+    async fn lock_reporter(
+        &self,
+        reporter_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn target_exists(
+        &self,
+        target: ReportTarget,
+        target_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the report is no longer open
+    async fn claim(
+        &self,
+        id: i32,
+        assignee_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn close(
+        &self,
+        id: i32,
+        assignee_id: i32,
+        resolution: ReportResolution,
+    ) -> Result<Report, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::domain::artist::model::SimpleArtist;
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{
+    CorrectionEntity, Reference, ReferenceKind, References,
+};
 use crate::domain::credit_role::CreditRoleRef;
 use crate::domain::release::model::SimpleRelease;
 use crate::domain::shared::model::{EntityIdent, Language, NewLocalizedName};
@@ -64,4 +66,50 @@ impl CorrectionEntity for NewSong {
     fn entity_type() -> EntityType {
         EntityType::Song
     }
+
+    fn references(&self) -> Vec<Reference> {
+        let mut refs = References::default();
+
+        refs.many(
+            "artists",
+            ReferenceKind::Artist,
+            self.artists.iter().flatten().copied(),
+        )
+        .nested("credits", self.credits.iter().flatten(), |refs, credit| {
+            refs.one("artist_id", ReferenceKind::Artist, credit.artist_id);
+
+            if let Some(role_id) = credit.role_id {
+                refs.one("role_id", ReferenceKind::CreditRole, role_id);
+            }
+        })
+        .many(
+            "languages",
+            ReferenceKind::Language,
+            self.languages.iter().flatten().copied(),
+        )
+        .nested(
+            "localized_titles",
+            self.localized_titles.iter().flatten(),
+            |refs, title| {
+                refs.one(
+                    "language_id",
+                    ReferenceKind::Language,
+                    title.language_id,
+                );
+            },
+        );
+
+        refs.into_vec()
+    }
+
+    fn names(&self) -> Vec<String> {
+        std::iter::once(self.title.to_string())
+            .chain(
+                self.localized_titles
+                    .iter()
+                    .flatten()
+                    .map(|title| title.name.clone()),
+            )
+            .collect()
+    }
 }
@@ -11,6 +11,13 @@ pub trait Repo: Connection {
         &self,
         keyword: &str,
     ) -> Result<Vec<Song>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The song as recorded in one of its histories
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Song>, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 pub trait TxRepo: Repo + Transaction
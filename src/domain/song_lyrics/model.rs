@@ -7,7 +7,9 @@ use macros::ApiError;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{
+    CorrectionEntity, Reference, ReferenceKind, References,
+};
 use crate::domain::shared::model::Language;
 
 #[derive(Clone, Debug, Serialize, ToSchema)]
@@ -80,4 +82,16 @@ impl CorrectionEntity for NewSongLyrics {
     fn entity_type() -> EntityType {
         EntityType::SongLyrics
     }
+
+    fn references(&self) -> Vec<Reference> {
+        let mut refs = References::default();
+
+        refs.one("song_id", ReferenceKind::Song, self.song_id).one(
+            "language_id",
+            ReferenceKind::Language,
+            self.language_id,
+        );
+
+        refs.into_vec()
+    }
 }
@@ -1,9 +1,13 @@
+use std::collections::HashSet;
+
 use entity::enums::EntityType;
 use entity::sea_orm_active_enums::{TagRelationType, TagType};
 use serde::Deserialize;
 use utoipa::ToSchema;
 
-use crate::domain::correction::CorrectionEntity;
+use crate::domain::correction::{
+    CorrectionEntity, Reference, ReferenceKind, References,
+};
 use crate::domain::shared::model::EntityIdent;
 
 #[derive(Deserialize, ToSchema)]
@@ -22,8 +26,83 @@ pub struct NewTagRelation {
     pub r#type: TagRelationType,
 }
 
+impl NewTag {
+    /// Returns the first related tag that would close a loop if this data
+    /// is applied to tag `id`, whose current descendants are `descendants`
+    pub fn find_cyclic_relation(
+        &self,
+        id: i32,
+        descendants: &HashSet<i32>,
+    ) -> Option<i32> {
+        self.relations
+            .iter()
+            .flatten()
+            .map(|relation| relation.related_tag_id)
+            .find(|related| *related == id || descendants.contains(related))
+    }
+}
+
 impl CorrectionEntity for NewTag {
     fn entity_type() -> EntityType {
         EntityType::Tag
     }
+
+    fn references(&self) -> Vec<Reference> {
+        let mut refs = References::default();
+
+        refs.nested(
+            "relations",
+            self.relations.iter().flatten(),
+            |refs, relation| {
+                refs.one(
+                    "related_tag_id",
+                    ReferenceKind::Tag,
+                    relation.related_tag_id,
+                );
+            },
+        );
+
+        refs.into_vec()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn new_tag(related: &[i32]) -> NewTag {
+        let relations = related
+            .iter()
+            .map(|id| json!({ "related_tag_id": id, "type": "Inherit" }))
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(json!({
+            "name": "Tag",
+            "type": "Genre",
+            "relations": relations,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn cyclic_relation() {
+        let descendants = HashSet::from([2, 3]);
+
+        assert_eq!(
+            new_tag(&[4, 5]).find_cyclic_relation(1, &descendants),
+            None
+        );
+        assert_eq!(
+            new_tag(&[4, 3]).find_cyclic_relation(1, &descendants),
+            Some(3)
+        );
+        assert_eq!(
+            new_tag(&[1]).find_cyclic_relation(1, &descendants),
+            Some(1)
+        );
+    }
 }
@@ -34,3 +34,17 @@ pub struct TagRelation {
     pub tag: TagRef,
     pub r#type: TagRelationType,
 }
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TagTreeEdge {
+    pub tag_id: i32,
+    pub parent_id: i32,
+    pub r#type: TagRelationType,
+}
+
+/// A flat view of (part of) the tag graph, tags may have multiple parents
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TagTree {
+    pub tags: Vec<TagRef>,
+    pub edges: Vec<TagTreeEdge>,
+}
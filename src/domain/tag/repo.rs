@@ -1,4 +1,6 @@
-use super::model::{NewTag, Tag};
+use entity::sea_orm_active_enums::TagType;
+
+use super::model::{NewTag, Tag, TagTree};
 use crate::domain::repository::{Connection, Transaction};
 
 pub trait Repo: Connection {
@@ -11,6 +13,31 @@ pub trait Repo: Connection {
         &self,
         keyword: &str,
     ) -> Result<Vec<Tag>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The tag as recorded in one of its histories
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Tag>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The tag itself and every tag it inherits or derives from, transitively
+    async fn find_ancestors(
+        &self,
+        id: i32,
+    ) -> Result<Option<TagTree>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// The tag itself and every tag inheriting or deriving from it, transitively
+    async fn find_descendants(
+        &self,
+        id: i32,
+    ) -> Result<Option<TagTree>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// All tags of the type and the relations between them
+    async fn find_tree(
+        &self,
+        r#type: TagType,
+    ) -> Result<TagTree, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 pub trait TxRepo: Repo + Transaction
@@ -0,0 +1,9 @@
+pub mod model;
+pub use model::{
+    PendingSignIn, RecoveryCodes, TotpEnrollment, TwoFactorCode,
+    TwoFactorPolicy, UserTotp, hash_recovery_code,
+};
+pub mod repo;
+pub use repo::Repo;
+mod totp;
+pub use totp::TotpSecret;
@@ -0,0 +1,223 @@
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+
+use super::TotpSecret;
+use crate::domain::model::auth::{Permission, Permissions};
+
+/// Recovery codes issued at a time, each can be used once instead of a code
+/// from the authenticator app
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Without characters that are easy to mistake for each other
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// Time to enter the second factor after the password
+const PENDING_SIGN_IN_EXPIRY: TimeDelta = TimeDelta::minutes(5);
+
+const MAX_CODE_ATTEMPTS: u8 = 5;
+
+#[derive(Clone, Debug)]
+pub struct UserTotp {
+    pub user_id: i32,
+    pub secret: TotpSecret,
+    /// `None` until the user confirms the enrollment with a code
+    pub enabled_at: Option<DateTime<FixedOffset>>,
+    /// Codes of this and earlier time steps can't be used again
+    pub last_used_step: Option<u64>,
+}
+
+impl UserTotp {
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+}
+
+/// Shown once when a user starts enrolling
+#[derive(Serialize, ToSchema)]
+pub struct TotpEnrollment {
+    /// Base32, for apps that can't scan the QR code
+    pub secret: String,
+    /// Encode as a QR code for authenticator apps
+    pub provisioning_uri: String,
+}
+
+/// A code from the authenticator app or a recovery code
+#[derive(Deserialize, ToSchema)]
+pub struct TwoFactorCode {
+    pub code: String,
+}
+
+impl TwoFactorCode {
+    pub fn is_recovery_code(&self) -> bool {
+        !self.code.trim().chars().all(|char| char.is_ascii_digit())
+    }
+}
+
+/// A sign in waiting for the second factor, kept in the session
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingSignIn {
+    pub user_id: i32,
+    /// Wrong codes are throttled like wrong passwords
+    pub username: String,
+    pub ip: Option<String>,
+    expires_at: DateTime<Utc>,
+    attempts: u8,
+}
+
+impl PendingSignIn {
+    pub fn new(
+        user_id: i32,
+        username: String,
+        ip: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            username,
+            ip,
+            expires_at: now + PENDING_SIGN_IN_EXPIRY,
+            attempts: 0,
+        }
+    }
+
+    /// Counts an attempt, returns `false` once the sign in expired or ran
+    /// out of attempts
+    pub fn attempt(&mut self, now: DateTime<Utc>) -> bool {
+        if now >= self.expires_at || self.attempts >= MAX_CODE_ATTEMPTS {
+            return false;
+        }
+
+        self.attempts += 1;
+
+        true
+    }
+}
+
+/// Shown once, only their hashes are stored
+#[derive(Serialize, ToSchema)]
+pub struct RecoveryCodes {
+    pub codes: Vec<String>,
+}
+
+impl RecoveryCodes {
+    pub fn generate() -> Self {
+        let mut rng = rand::rng();
+
+        let mut part = || {
+            (0..5)
+                .map(|_| {
+                    char::from(
+                        RECOVERY_CODE_ALPHABET
+                            [rng.random_range(0..RECOVERY_CODE_ALPHABET.len())],
+                    )
+                })
+                .collect::<String>()
+        };
+
+        Self {
+            codes: (0..RECOVERY_CODE_COUNT)
+                .map(|_| format!("{}-{}", part(), part()))
+                .collect(),
+        }
+    }
+
+    pub fn hashes(&self) -> Vec<String> {
+        self.codes
+            .iter()
+            .map(|code| hash_recovery_code(code))
+            .collect()
+    }
+}
+
+/// Codes are compared case-insensitively and without separators
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized = code
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|char| char.to_ascii_lowercase())
+        .collect::<String>();
+
+    format!("{:x}", Sha256::digest(normalized))
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TwoFactorPolicy {
+    /// Shown in authenticator apps next to the user name
+    pub issuer: String,
+    /// Users have to enable two-factor authentication before their roles
+    /// grant them these permissions, e.g. `ApproveCorrection` and
+    /// `ModerateImage` to require it of moderators and admins
+    pub required_for: Vec<Permission>,
+}
+
+impl Default for TwoFactorPolicy {
+    fn default() -> Self {
+        Self {
+            issuer: "Touhou Cloud DB".to_owned(),
+            required_for: vec![],
+        }
+    }
+}
+
+impl TwoFactorPolicy {
+    pub fn restrict(
+        &self,
+        permissions: Permissions,
+        two_factor_enabled: bool,
+    ) -> Permissions {
+        if two_factor_enabled {
+            permissions
+        } else {
+            permissions.without(&self.required_for)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recovery_codes_are_normalized_before_hashing() {
+        let codes = RecoveryCodes::generate();
+
+        assert_eq!(codes.codes.len(), RECOVERY_CODE_COUNT);
+        assert_eq!(
+            hash_recovery_code(&codes.codes[0]),
+            hash_recovery_code(&format!(
+                " {} ",
+                codes.codes[0].to_uppercase().replace('-', "")
+            ))
+        );
+        assert!(
+            TwoFactorCode {
+                code: codes.codes[0].clone()
+            }
+            .is_recovery_code()
+        );
+        assert!(
+            !TwoFactorCode {
+                code: "123456".to_string()
+            }
+            .is_recovery_code()
+        );
+    }
+
+    #[test]
+    fn pending_sign_in_limits() {
+        let now = Utc::now();
+        let mut pending = PendingSignIn::new(1, "alice".to_owned(), None, now);
+
+        for _ in 0..MAX_CODE_ATTEMPTS {
+            assert!(pending.attempt(now));
+        }
+        assert!(!pending.attempt(now));
+
+        let mut pending = PendingSignIn::new(1, "alice".to_owned(), None, now);
+
+        assert!(!pending.attempt(now + PENDING_SIGN_IN_EXPIRY));
+    }
+}
@@ -0,0 +1,50 @@
+use super::TotpSecret;
+use super::model::UserTotp;
+use crate::domain::repository::Connection;
+
+#[trait_variant::make(Send)]
+pub trait Repo: Connection {
+    async fn find_totp(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<UserTotp>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Replaces an enrollment that wasn't confirmed yet
+    async fn save_pending_totp(
+        &self,
+        user_id: i32,
+        secret: &TotpSecret,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn enable_totp(
+        &self,
+        user_id: i32,
+        step: u64,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if a code of this or a later step was already used
+    async fn use_totp_step(
+        &self,
+        user_id: i32,
+        step: u64,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Deletes the recovery codes too
+    async fn delete_totp(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: i32,
+        code_hashes: Vec<String>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the user has no unused code with the hash
+    async fn use_recovery_code(
+        &self,
+        user_id: i32,
+        code_hash: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -1,7 +1,10 @@
+use chrono::{DateTime, Utc};
+use entity::enums::EntityType;
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use super::model::auth::{AuthCredential, UserRole, UserRoleEnum};
+use super::contribution::ContributorStats;
+use super::model::auth::{AuthCredential, Permission, Permissions, UserRole};
 use super::model::markdown::Markdown;
 use super::repository::{Connection, Transaction};
 use crate::infra::error::Error;
@@ -12,6 +15,8 @@ use crate::infra::error::Error;
 )]
 #[derive(Clone, ToSchema, Serialize)]
 pub struct UserProfile {
+    #[serde(skip)]
+    pub id: i32,
     pub name: String,
 
     /// Avatar url with sub directory, eg. ab/cd/abcd..xyz.jpg
@@ -26,26 +31,40 @@ pub struct UserProfile {
     pub is_following: Option<bool>,
 
     pub bio: Option<String>,
+
+    pub contributions: ContributorStats,
 }
 
 #[derive(Clone, Debug)]
 pub struct User {
     pub id: i32,
     pub name: String,
+    /// Empty for users who signed up through an identity provider
     pub password: String,
     pub avatar_id: Option<i32>,
     pub profile_banner_id: Option<i32>,
     pub last_login: chrono::DateTime<chrono::FixedOffset>,
     pub roles: Vec<UserRole>,
+    /// Granted through the roles
+    pub permissions: Permissions,
     pub bio: Option<Markdown>,
 }
 
 impl User {
-    pub fn has_roles(&self, expected: &[UserRoleEnum]) -> bool {
-        self.roles.iter().any(|r| {
-            UserRoleEnum::try_from(r.id)
-                .is_ok_and(|role| expected.contains(&role))
-        })
+    pub fn password_hash(&self) -> Option<&str> {
+        Some(self.password.as_str()).filter(|password| !password.is_empty())
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.permissions.allows(permission, None)
+    }
+
+    pub fn can_for(
+        &self,
+        permission: Permission,
+        entity_type: EntityType,
+    ) -> bool {
+        self.permissions.allows(permission, Some(entity_type))
     }
 }
 
@@ -55,6 +74,16 @@ pub struct NewUser {
     pub password: String,
 }
 
+impl NewUser {
+    /// Signs in through an identity provider only
+    pub const fn without_password(name: String) -> Self {
+        Self {
+            name,
+            password: String::new(),
+        }
+    }
+}
+
 impl TryFrom<AuthCredential> for NewUser {
     type Error = Error;
 
@@ -103,3 +132,73 @@ pub trait ProfileRepository: Connection {
         current_user: &User,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 }
+
+/// A signed in session. The session id is the secret of the session cookie,
+/// so sessions are referred to by a separate id
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct UserSession {
+    pub id: String,
+    pub created_at: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    /// Whether this is the session of the request
+    pub current: bool,
+}
+
+/// Where a session was signed in from
+#[derive(Clone, Debug, Default)]
+pub struct SessionClient {
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+}
+
+/// The sessions of each user, which the session store only knows by id
+#[trait_variant::make(Send)]
+pub trait SessionRepo: Sync {
+    async fn track_session(
+        &self,
+        user_id: i32,
+        session_id: String,
+        client: SessionClient,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Revoked and expired sessions aren't signed in
+    async fn is_signed_in(
+        &self,
+        session_id: String,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Records that the session is in use
+    async fn touch_session(
+        &self,
+        session_id: String,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Most recently seen first
+    async fn find_sessions(
+        &self,
+        user_id: i32,
+        current_session_id: String,
+    ) -> Result<Vec<UserSession>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Returns `false` if the user has no session with the id
+    async fn revoke_session(
+        &self,
+        user_id: i32,
+        id: String,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Signs the user out of every session
+    async fn revoke_sessions(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Signs the user out of every session but the given one
+    async fn revoke_other_sessions(
+        &self,
+        user_id: i32,
+        session_id: String,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,9 @@
+pub mod model;
+pub use model::{
+    CreatedWebhook, NewWebhook, Webhook, WebhookDelivery, WebhookEvent,
+    WebhookPolicy,
+};
+pub mod repo;
+pub use repo::Repo;
+pub mod service;
+pub use service::{Client, Dispatcher};
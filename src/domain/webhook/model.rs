@@ -0,0 +1,304 @@
+use std::fmt::Write;
+
+use chrono::{DateTime, FixedOffset, TimeDelta};
+use entity::enums::{CorrectionType, EntityType};
+pub use entity::enums::{WebhookDeliveryStatus, WebhookEventKind};
+use enumset::EnumSet;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+use utoipa::ToSchema;
+
+use crate::domain::correction::Correction;
+
+/// What happened, sent as the body of the deliveries
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema,
+)]
+#[serde(tag = "event", content = "data")]
+pub enum WebhookEvent {
+    /// Sent on request to test the receiver
+    Ping {
+        webhook_id: i32,
+    },
+    CorrectionApproved {
+        correction_id: i32,
+        correction_type: CorrectionType,
+        entity_type: EntityType,
+        entity_id: i32,
+    },
+    ReleaseCreated {
+        release_id: i32,
+        correction_id: i32,
+    },
+    ImageApproved {
+        queue_id: i32,
+        image_id: Option<i32>,
+    },
+}
+
+impl WebhookEvent {
+    pub const fn kind(&self) -> WebhookEventKind {
+        match self {
+            Self::Ping { .. } => WebhookEventKind::Ping,
+            Self::CorrectionApproved { .. } => {
+                WebhookEventKind::CorrectionApproved
+            }
+            Self::ReleaseCreated { .. } => WebhookEventKind::ReleaseCreated,
+            Self::ImageApproved { .. } => WebhookEventKind::ImageApproved,
+        }
+    }
+
+    /// The events of approving the correction, new releases also have their
+    /// own
+    pub fn approved(correction: &Correction) -> Vec<Self> {
+        let mut events = vec![Self::CorrectionApproved {
+            correction_id: correction.id,
+            correction_type: correction.r#type,
+            entity_type: correction.entity_type,
+            entity_id: correction.entity_id,
+        }];
+
+        if correction.r#type == CorrectionType::Create
+            && correction.entity_type == EntityType::Release
+        {
+            events.push(Self::ReleaseCreated {
+                release_id: correction.entity_id,
+                correction_id: correction.id,
+            });
+        }
+
+        events
+    }
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct Webhook {
+    pub id: i32,
+    pub owner_id: i32,
+    pub url: String,
+    #[serde(skip)]
+    pub secret: String,
+    #[schema(value_type = HashSet<WebhookEventKind>)]
+    pub events: EnumSet<WebhookEventKind>,
+    /// Correction events of other entity types aren't sent, empty for every
+    /// type
+    #[schema(value_type = HashSet<EntityType>)]
+    pub entity_types: EnumSet<EntityType>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl Webhook {
+    /// Pings are sent to the webhook they are requested for only
+    pub fn accepts(&self, event: &WebhookEvent) -> bool {
+        if !self.events.contains(event.kind()) {
+            return false;
+        }
+
+        match event {
+            WebhookEvent::Ping { webhook_id } => *webhook_id == self.id,
+            WebhookEvent::CorrectionApproved { entity_type, .. } => {
+                self.entity_types.is_empty()
+                    || self.entity_types.contains(*entity_type)
+            }
+            WebhookEvent::ReleaseCreated { .. }
+            | WebhookEvent::ImageApproved { .. } => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+pub struct NewWebhook {
+    /// An http or https url
+    pub url: String,
+    #[schema(value_type = HashSet<WebhookEventKind>)]
+    pub events: EnumSet<WebhookEventKind>,
+    #[schema(value_type = HashSet<EntityType>)]
+    #[serde(default)]
+    pub entity_types: EnumSet<EntityType>,
+}
+
+/// The secret is shown only once, when the webhook is created
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CreatedWebhook {
+    #[serde(flatten)]
+    pub webhook: Webhook,
+    pub secret: String,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookDelivery {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: WebhookEventKind,
+    #[schema(value_type = Object)]
+    pub payload: Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<FixedOffset>,
+    /// The status code of the last response
+    pub response_status: Option<i32>,
+    /// Why the last attempt failed
+    pub error: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+}
+
+/// A delivery claimed for sending, with the webhook it goes to
+#[derive(Clone, Debug)]
+pub struct PendingDelivery {
+    pub id: i32,
+    pub event: WebhookEventKind,
+    pub payload: Value,
+    pub attempts: i32,
+    pub url: String,
+    pub secret: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeliveryAttempt {
+    pub status: WebhookDeliveryStatus,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    /// Only for deliveries which are retried
+    pub next_attempt_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+pub struct WebhookPolicy {
+    /// Attempts before a delivery is given up
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each one after
+    pub retry_base_seconds: u32,
+    /// Lets webhooks target loopback and private addresses, eg. a local
+    /// receiver for testing
+    pub allow_private_hosts: bool,
+    /// Webhooks a user can have at once
+    pub max_webhooks_per_owner: u32,
+}
+
+impl Default for WebhookPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 8,
+            retry_base_seconds: 30,
+            allow_private_hosts: false,
+            max_webhooks_per_owner: 10,
+        }
+    }
+}
+
+impl WebhookPolicy {
+    /// `None` once the delivery made every attempt
+    pub fn retry_delay(self, attempts: u32) -> Option<TimeDelta> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+
+        let factor = 2_i64.saturating_pow(attempts.saturating_sub(1));
+
+        Some(TimeDelta::seconds(
+            i64::from(self.retry_base_seconds).saturating_mul(factor),
+        ))
+    }
+}
+
+/// The key of the signatures of a new webhook
+pub fn generate_secret() -> String {
+    rand::rng()
+        .sample_iter(Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+/// The value of the `Webhook-Signature` header, which is the hex encoded
+/// HMAC-SHA256 of `{timestamp}.{body}` keyed with the secret of the webhook
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC can take key of any size");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+
+    let digest = mac.finalize().into_bytes().iter().fold(
+        String::with_capacity(64),
+        |mut buf, byte| {
+            write!(buf, "{byte:02x}").unwrap();
+            buf
+        },
+    );
+
+    format!("t={timestamp},v1={digest}")
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn webhook(
+        events: EnumSet<WebhookEventKind>,
+        entity_types: EnumSet<EntityType>,
+    ) -> Webhook {
+        Webhook {
+            id: 1,
+            owner_id: 1,
+            url: "http://localhost".to_owned(),
+            secret: String::new(),
+            events,
+            entity_types,
+            created_at: Utc::now().into(),
+        }
+    }
+
+    #[test]
+    fn accepts_subscribed_entity_types() {
+        let approved = |entity_type| WebhookEvent::CorrectionApproved {
+            correction_id: 1,
+            correction_type: CorrectionType::Update,
+            entity_type,
+            entity_id: 1,
+        };
+
+        let all = webhook(
+            WebhookEventKind::CorrectionApproved.into(),
+            EnumSet::new(),
+        );
+        let songs = webhook(
+            WebhookEventKind::CorrectionApproved.into(),
+            EntityType::Song.into(),
+        );
+
+        assert!(all.accepts(&approved(EntityType::Artist)));
+        assert!(songs.accepts(&approved(EntityType::Song)));
+        assert!(!songs.accepts(&approved(EntityType::Artist)));
+        assert!(!songs.accepts(&WebhookEvent::ReleaseCreated {
+            release_id: 1,
+            correction_id: 1
+        }));
+    }
+
+    #[test]
+    fn retries_back_off_until_max_attempts() {
+        let policy = WebhookPolicy {
+            max_attempts: 3,
+            retry_base_seconds: 30,
+            ..WebhookPolicy::default()
+        };
+
+        assert_eq!(policy.retry_delay(1), Some(TimeDelta::seconds(30)));
+        assert_eq!(policy.retry_delay(2), Some(TimeDelta::seconds(60)));
+        assert_eq!(policy.retry_delay(3), None);
+    }
+
+    #[test]
+    fn signature_covers_timestamp_and_body() {
+        assert_eq!(
+            sign("secret", 1_700_000_000, r#"{"event":"Ping"}"#),
+            "t=1700000000,v1=3013f3e1e947c2b9235d2d278f9dc1ab15368e4627723dcd2ac3c7685a7f085f"
+        );
+    }
+}
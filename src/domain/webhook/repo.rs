@@ -0,0 +1,67 @@
+use chrono::TimeDelta;
+
+use super::model::{
+    DeliveryAttempt, NewWebhook, PendingDelivery, Webhook, WebhookDelivery,
+    WebhookEvent,
+};
+use crate::domain::repository::{Connection, Cursor, Paginated};
+
+pub trait Repo: Connection {
+    async fn create_webhook(
+        &self,
+        owner_id: i32,
+        secret: String,
+        webhook: NewWebhook,
+    ) -> Result<Webhook, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_webhook(
+        &self,
+        id: i32,
+    ) -> Result<Option<Webhook>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_webhooks(
+        &self,
+        owner_id: i32,
+    ) -> Result<Vec<Webhook>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn delete_webhook(
+        &self,
+        id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Queues a delivery of each event to every webhook accepting it
+    async fn enqueue(
+        &self,
+        events: Vec<WebhookEvent>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Queues a delivery of the event to the webhook only
+    async fn enqueue_for(
+        &self,
+        webhook_id: i32,
+        event: WebhookEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_deliveries(
+        &self,
+        webhook_id: i32,
+        pagination: Cursor,
+    ) -> Result<
+        Paginated<WebhookDelivery>,
+        Box<dyn std::error::Error + Send + Sync>,
+    >;
+
+    /// Takes the pending deliveries which are due, they aren't claimed again
+    /// until the lease runs out, so a crashed worker can't lose them
+    async fn claim_due(
+        &self,
+        limit: u64,
+        lease: TimeDelta,
+    ) -> Result<Vec<PendingDelivery>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn record_attempt(
+        &self,
+        delivery_id: i32,
+        attempt: DeliveryAttempt,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,107 @@
+use chrono::{TimeDelta, Utc};
+use futures_util::future::join_all;
+
+use super::model::{
+    DeliveryAttempt, PendingDelivery, WebhookDeliveryStatus, WebhookPolicy,
+    sign,
+};
+use super::repo::Repo;
+
+/// Deliveries claimed in one go
+const BATCH_SIZE: u64 = 32;
+/// Longer than the timeout of the client, so a delivery in flight isn't
+/// claimed twice
+const LEASE: TimeDelta = TimeDelta::minutes(2);
+
+pub trait Client: Send + Sync {
+    /// Returns the status code of the response
+    async fn post(
+        &self,
+        url: &str,
+        headers: Vec<(&'static str, String)>,
+        body: String,
+    ) -> Result<u16, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+pub struct Dispatcher<R, C> {
+    repo: R,
+    client: C,
+    policy: WebhookPolicy,
+}
+
+impl<R, C> Dispatcher<R, C>
+where
+    R: Repo,
+    C: Client,
+{
+    pub const fn new(repo: R, client: C, policy: WebhookPolicy) -> Self {
+        Self {
+            repo,
+            client,
+            policy,
+        }
+    }
+
+    /// Makes an attempt of each due delivery, returns how many were made
+    pub async fn deliver_due(
+        &self,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let deliveries = self.repo.claim_due(BATCH_SIZE, LEASE).await?;
+
+        let attempts =
+            join_all(deliveries.iter().map(|delivery| self.attempt(delivery)))
+                .await;
+
+        for (delivery, attempt) in deliveries.iter().zip(attempts) {
+            self.repo.record_attempt(delivery.id, attempt).await?;
+        }
+
+        Ok(deliveries.len())
+    }
+
+    async fn attempt(&self, delivery: &PendingDelivery) -> DeliveryAttempt {
+        let body = delivery.payload.to_string();
+        let headers = vec![
+            (
+                "Webhook-Signature",
+                sign(&delivery.secret, Utc::now().timestamp(), &body),
+            ),
+            ("Webhook-Id", delivery.id.to_string()),
+            ("Webhook-Event", format!("{:?}", delivery.event)),
+        ];
+
+        let (response_status, error) =
+            match self.client.post(&delivery.url, headers, body).await {
+                Ok(status) if (200..300).contains(&status) => {
+                    return DeliveryAttempt {
+                        status: WebhookDeliveryStatus::Succeeded,
+                        response_status: Some(status.into()),
+                        error: None,
+                        next_attempt_at: None,
+                    };
+                }
+                Ok(status) => (
+                    Some(status.into()),
+                    format!("Unexpected response status: {status}"),
+                ),
+                Err(err) => (None, err.to_string()),
+            };
+
+        let attempts = u32::try_from(delivery.attempts).unwrap_or(0) + 1;
+        let next_attempt_at = self
+            .policy
+            .retry_delay(attempts)
+            .map(|delay| (Utc::now() + delay).into());
+
+        DeliveryAttempt {
+            status: if next_attempt_at.is_some() {
+                WebhookDeliveryStatus::Pending
+            } else {
+                WebhookDeliveryStatus::Failed
+            },
+            response_status,
+            error: Some(error),
+            next_attempt_at,
+        }
+    }
+}
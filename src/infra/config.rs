@@ -2,6 +2,13 @@ use flow::Pipe;
 use nestify::nest;
 use serde::Deserialize;
 
+use crate::domain::correction::CorrectionPolicy;
+use crate::domain::identity::OidcConfig;
+use crate::domain::login_throttle::LoginThrottleConfig;
+use crate::domain::report::ReportPolicy;
+use crate::domain::two_factor::TwoFactorPolicy;
+use crate::domain::webhook::WebhookPolicy;
+
 nest! {
     #[derive(Clone, Deserialize)]*
     pub struct Config {
@@ -22,7 +29,20 @@ nest! {
                 pub req_per_sec: u64,
                 pub burst_size: u32,
             }
-        }
+        },
+        // Sections of the policies can be left out of existing configs
+        #[serde(default)]
+        pub correction: CorrectionPolicy,
+        #[serde(default)]
+        pub login_throttle: LoginThrottleConfig,
+        #[serde(default)]
+        pub oidc: OidcConfig,
+        #[serde(default)]
+        pub report: ReportPolicy,
+        #[serde(default)]
+        pub two_factor: TwoFactorPolicy,
+        #[serde(default)]
+        pub webhook: WebhookPolicy,
     }
 }
 
@@ -0,0 +1,96 @@
+use chrono::TimeDelta;
+use fred::prelude::{Expiration, KeysInterface};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::domain::contribution::{
+    Cache, ContributorStats, LeaderboardEntry, LeaderboardWindow,
+};
+
+#[derive(Clone)]
+pub struct RedisContributionCache {
+    pool: fred::prelude::Pool,
+}
+
+impl RedisContributionCache {
+    pub const fn new(pool: fred::prelude::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        key: String,
+    ) -> Result<Option<T>, Box<dyn std::error::Error + Send + Sync>> {
+        let json: Option<String> = self.pool.get(key).await?;
+
+        Ok(json.map(|json| serde_json::from_str(&json)).transpose()?)
+    }
+
+    async fn set<T: Serialize + Sync + ?Sized>(
+        &self,
+        key: String,
+        value: &T,
+        ttl: TimeDelta,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let () = self
+            .pool
+            .set(
+                key,
+                serde_json::to_string(value)?,
+                Some(Expiration::EX(ttl.num_seconds().max(1))),
+                None,
+                false,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn stats_key(user_id: i32) -> String {
+    format!("contributor_stats:{user_id}")
+}
+
+fn leaderboard_key(window: LeaderboardWindow) -> String {
+    format!("leaderboard:{}", window.as_str())
+}
+
+impl Cache for RedisContributionCache {
+    async fn find_stats(
+        &self,
+        user_id: i32,
+    ) -> Result<
+        Option<ContributorStats>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        self.get(stats_key(user_id)).await
+    }
+
+    async fn save_stats(
+        &self,
+        user_id: i32,
+        stats: &ContributorStats,
+        ttl: TimeDelta,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.set(stats_key(user_id), stats, ttl).await
+    }
+
+    async fn find_leaderboard(
+        &self,
+        window: LeaderboardWindow,
+    ) -> Result<
+        Option<Vec<LeaderboardEntry>>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        self.get(leaderboard_key(window)).await
+    }
+
+    async fn save_leaderboard(
+        &self,
+        window: LeaderboardWindow,
+        entries: &[LeaderboardEntry],
+        ttl: TimeDelta,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.set(leaderboard_key(window), entries, ttl).await
+    }
+}
@@ -0,0 +1,234 @@
+use entity::enums::CorrectionUserType;
+use entity::{
+    comment, correction, correction_draft, correction_revision,
+    correction_user, notification, notification_preference, user,
+    user_following, user_identity, user_list, user_list_item,
+    user_recovery_code, user_role, user_totp, webhook,
+};
+use itertools::Itertools;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use super::SeaOrmTxRepo;
+use crate::domain::account::{
+    ExportedComment, ExportedCorrection, ExportedList, ExportedListItem,
+    ExportedRevision, Repo, TxRepo, anonymized_name,
+};
+use crate::domain::repository::Connection;
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn export_corrections(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExportedCorrection>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let corrections = correction::Entity::find()
+            .inner_join(correction_user::Entity)
+            .filter(correction_user::Column::UserId.eq(user_id))
+            .filter(
+                correction_user::Column::UserType
+                    .eq(CorrectionUserType::Author),
+            )
+            .order_by_asc(correction::Column::Id)
+            .all(self.conn())
+            .await?;
+
+        let mut revisions = correction_revision::Entity::find()
+            .filter(correction_revision::Column::AuthorId.eq(user_id))
+            .filter(
+                correction_revision::Column::CorrectionId
+                    .is_in(corrections.iter().map(|correction| correction.id)),
+            )
+            .order_by_asc(correction_revision::Column::EntityHistoryId)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .into_group_map_by(|revision| revision.correction_id);
+
+        Ok(corrections
+            .into_iter()
+            .map(|correction| ExportedCorrection {
+                id: correction.id,
+                status: correction.status,
+                r#type: correction.r#type,
+                entity_type: correction.entity_type,
+                entity_id: correction.entity_id,
+                created_at: correction.created_at,
+                handled_at: correction.handled_at,
+                revisions: revisions
+                    .remove(&correction.id)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|revision| ExportedRevision {
+                        entity_history_id: revision.entity_history_id,
+                        description: revision.description,
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
+    async fn export_comments(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExportedComment>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        Ok(comment::Entity::find()
+            .filter(comment::Column::AuthorId.eq(user_id))
+            .order_by_asc(comment::Column::Id)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(|comment| ExportedComment {
+                id: comment.id,
+                content: comment.content,
+                state: comment.state,
+                target: comment.target,
+                target_id: comment.target_id,
+                parent_id: comment.parent_id,
+                created_at: comment.created_at,
+                updated_at: comment.updated_at,
+            })
+            .collect())
+    }
+
+    async fn export_lists(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExportedList>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let lists = user_list::Entity::find()
+            .filter(user_list::Column::UserId.eq(user_id))
+            .order_by_asc(user_list::Column::Id)
+            .all(self.conn())
+            .await?;
+
+        let mut items = user_list_item::Entity::find()
+            .filter(
+                user_list_item::Column::UserListId
+                    .is_in(lists.iter().map(|list| list.id)),
+            )
+            .order_by_asc(user_list_item::Column::Id)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .into_group_map_by(|item| item.user_list_id);
+
+        Ok(lists
+            .into_iter()
+            .map(|list| ExportedList {
+                id: list.id,
+                items: items
+                    .remove(&list.id)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|item| ExportedListItem {
+                        entity_type: item.entity_type,
+                        entity_id: item.entity_id,
+                        description: item.description,
+                    })
+                    .collect(),
+                name: list.name,
+                description: list.description,
+                is_public: list.is_public,
+            })
+            .collect())
+    }
+}
+
+impl TxRepo for SeaOrmTxRepo {
+    async fn anonymize_user(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let conn = self.conn();
+
+        let list_ids = user_list::Entity::find()
+            .filter(user_list::Column::UserId.eq(user_id))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|list| list.id)
+            .collect_vec();
+
+        user_list_item::Entity::delete_many()
+            .filter(user_list_item::Column::UserListId.is_in(list_ids))
+            .exec(conn)
+            .await?;
+
+        user_list::Entity::delete_many()
+            .filter(user_list::Column::UserId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        user_following::Entity::delete_many()
+            .filter(
+                user_following::Column::UserId
+                    .eq(user_id)
+                    .or(user_following::Column::FollowingId.eq(user_id)),
+            )
+            .exec(conn)
+            .await?;
+
+        user_role::Entity::delete_many()
+            .filter(user_role::Column::UserId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        correction_draft::Entity::delete_many()
+            .filter(correction_draft::Column::AuthorId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        notification::Entity::delete_many()
+            .filter(notification::Column::UserId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        notification_preference::Entity::delete_many()
+            .filter(notification_preference::Column::UserId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        user_identity::Entity::delete_many()
+            .filter(user_identity::Column::UserId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        webhook::Entity::delete_many()
+            .filter(webhook::Column::OwnerId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        user_recovery_code::Entity::delete_many()
+            .filter(user_recovery_code::Column::UserId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        user_totp::Entity::delete_many()
+            .filter(user_totp::Column::UserId.eq(user_id))
+            .exec(conn)
+            .await?;
+
+        // An empty password can't be signed in with
+        user::Entity::update(user::ActiveModel {
+            id: Set(user_id),
+            name: Set(anonymized_name(user_id)),
+            password: Set(String::new()),
+            avatar_id: Set(None),
+            profile_banner_id: Set(None),
+            bio: Set(None),
+            ..Default::default()
+        })
+        .exec(conn)
+        .await?;
+
+        Ok(())
+    }
+}
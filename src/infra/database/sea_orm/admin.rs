@@ -0,0 +1,316 @@
+use entity::{audit_log, role, role_permission, user_role, user_suspension};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait,
+};
+
+use crate::domain::admin::{
+    AuditLogEntry, AuditLogFilter, NewAuditLogEntry, NewRole,
+    NewUserSuspension, Repo, Role, UserSuspension,
+};
+use crate::domain::model::auth::PermissionGrant;
+use crate::domain::repository::{Connection, Cursor, Paginated};
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn find_roles(
+        &self,
+    ) -> Result<Vec<Role>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(role::Entity::find()
+            .find_with_related(role_permission::Entity)
+            .order_by_asc(role::Column::Id)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn find_role(
+        &self,
+        id: i32,
+    ) -> Result<Option<Role>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(role::Entity::find_by_id(id)
+            .find_with_related(role_permission::Entity)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .next()
+            .map(Into::into))
+    }
+
+    async fn role_name_exists(
+        &self,
+        name: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(role::Entity::find()
+            .filter(role::Column::Name.eq(name))
+            .count(self.conn())
+            .await?
+            > 0)
+    }
+
+    async fn create_role(
+        &self,
+        new_role: NewRole,
+    ) -> Result<Role, Box<dyn std::error::Error + Send + Sync>> {
+        let model = role::Entity::insert(role::ActiveModel {
+            id: NotSet,
+            name: Set(new_role.name),
+        })
+        .exec_with_returning(self.conn())
+        .await?;
+
+        Ok((model, vec![]).into())
+    }
+
+    async fn grant_permission(
+        &self,
+        role_id: i32,
+        grant: PermissionGrant,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let res =
+            role_permission::Entity::insert(role_permission::ActiveModel {
+                id: NotSet,
+                role_id: Set(role_id),
+                permission: Set(grant.permission),
+                entity_type: Set(grant.entity_type),
+            })
+            .on_conflict(
+                OnConflict::columns([
+                    role_permission::Column::RoleId,
+                    role_permission::Column::Permission,
+                    role_permission::Column::EntityType,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(self.conn())
+            .await?;
+
+        Ok(res > 0)
+    }
+
+    async fn revoke_permission(
+        &self,
+        role_id: i32,
+        grant: PermissionGrant,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let entity_type = grant.entity_type.map_or_else(
+            || role_permission::Column::EntityType.is_null(),
+            |entity_type| role_permission::Column::EntityType.eq(entity_type),
+        );
+
+        let res = role_permission::Entity::delete_many()
+            .filter(role_permission::Column::RoleId.eq(role_id))
+            .filter(role_permission::Column::Permission.eq(grant.permission))
+            .filter(entity_type)
+            .exec(self.conn())
+            .await?;
+
+        Ok(res.rows_affected > 0)
+    }
+
+    async fn grant_role(
+        &self,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let res = user_role::Entity::insert(user_role::ActiveModel {
+            user_id: Set(user_id),
+            role_id: Set(role_id),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                user_role::Column::UserId,
+                user_role::Column::RoleId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(self.conn())
+        .await?;
+
+        Ok(res > 0)
+    }
+
+    async fn revoke_role(
+        &self,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let res = user_role::Entity::delete_many()
+            .filter(user_role::Column::UserId.eq(user_id))
+            .filter(user_role::Column::RoleId.eq(role_id))
+            .exec(self.conn())
+            .await?;
+
+        Ok(res.rows_affected > 0)
+    }
+
+    async fn find_active_suspension(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<UserSuspension>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        Ok(user_suspension::Entity::find()
+            .filter(user_suspension::Column::UserId.eq(user_id))
+            .filter(user_suspension::Column::LiftedAt.is_null())
+            .filter(
+                Expr::col(user_suspension::Column::SuspendedUntil)
+                    .gt(Expr::current_timestamp()),
+            )
+            .order_by_desc(user_suspension::Column::SuspendedUntil)
+            .one(self.conn())
+            .await?
+            .map(Into::into))
+    }
+
+    async fn create_suspension(
+        &self,
+        user_id: i32,
+        created_by: i32,
+        suspension: NewUserSuspension,
+    ) -> Result<UserSuspension, Box<dyn std::error::Error + Send + Sync>> {
+        let model =
+            user_suspension::Entity::insert(user_suspension::ActiveModel {
+                id: NotSet,
+                user_id: Set(user_id),
+                reason: Set(suspension.reason),
+                suspended_until: Set(suspension.suspended_until),
+                created_by: Set(created_by),
+                created_at: NotSet,
+                lifted_at: Set(None),
+            })
+            .exec_with_returning(self.conn())
+            .await?;
+
+        Ok(model.into())
+    }
+
+    async fn lift_suspension(
+        &self,
+        id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        user_suspension::Entity::update_many()
+            .col_expr(
+                user_suspension::Column::LiftedAt,
+                Expr::current_timestamp().into(),
+            )
+            .filter(user_suspension::Column::Id.eq(id))
+            .exec(self.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_audit_log(
+        &self,
+        entry: NewAuditLogEntry,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        audit_log::Entity::insert(audit_log::ActiveModel {
+            id: NotSet,
+            actor_id: Set(entry.actor_id),
+            action: Set(entry.action),
+            target_user_id: Set(entry.target_user_id),
+            data: Set(entry.data),
+            created_at: NotSet,
+        })
+        .exec_without_returning(self.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_audit_log(
+        &self,
+        filter: AuditLogFilter,
+        pagination: Cursor,
+    ) -> Result<
+        Paginated<AuditLogEntry>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        // Get one more to check if there are more
+        let mut entries = audit_log::Entity::find()
+            .apply_if(filter.actor_id, |select, id| {
+                select.filter(audit_log::Column::ActorId.eq(id))
+            })
+            .apply_if(filter.target_user_id, |select, id| {
+                select.filter(audit_log::Column::TargetUserId.eq(id))
+            })
+            .apply_if(filter.action, |select, action| {
+                select.filter(audit_log::Column::Action.eq(action))
+            })
+            .apply_if((pagination.at > 0).then_some(pagination.at), |s, at| {
+                s.filter(audit_log::Column::Id.lt(at))
+            })
+            .order_by_desc(audit_log::Column::Id)
+            .limit(u64::from(pagination.limit) + 1)
+            .all(self.conn())
+            .await?;
+
+        let has_more = entries.len() > pagination.limit.into();
+
+        if has_more {
+            entries.pop();
+        }
+
+        let next_cursor =
+            entries.last().map(|entry| entry.id).filter(|_| has_more);
+
+        Ok(Paginated {
+            items: entries.into_iter().map(Into::into).collect(),
+            next_cursor,
+        })
+    }
+}
+
+impl From<(role::Model, Vec<role_permission::Model>)> for Role {
+    fn from(
+        (role, grants): (role::Model, Vec<role_permission::Model>),
+    ) -> Self {
+        Self {
+            id: role.id,
+            name: role.name,
+            permissions: grants
+                .into_iter()
+                .map(|grant| PermissionGrant {
+                    permission: grant.permission,
+                    entity_type: grant.entity_type,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<user_suspension::Model> for UserSuspension {
+    fn from(model: user_suspension::Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            reason: model.reason,
+            suspended_until: model.suspended_until,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            lifted_at: model.lifted_at,
+        }
+    }
+}
+
+impl From<audit_log::Model> for AuditLogEntry {
+    fn from(model: audit_log::Model) -> Self {
+        Self {
+            id: model.id,
+            actor_id: model.actor_id,
+            action: model.action,
+            target_user_id: model.target_user_id,
+            data: model.data,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -3,9 +3,12 @@ use std::path::PathBuf;
 
 use entity::sea_orm_active_enums::ArtistImageType;
 use entity::{
-    artist, artist_alias, artist_image, artist_link, artist_localized_name,
-    artist_membership, artist_membership_role, artist_membership_tenure,
-    credit_role, image, language,
+    artist, artist_alias, artist_alias_history, artist_history, artist_image,
+    artist_link, artist_link_history, artist_localized_name,
+    artist_localized_name_history, artist_membership,
+    artist_membership_history, artist_membership_role,
+    artist_membership_role_history, artist_membership_tenure,
+    artist_membership_tenure_history, credit_role, image, language,
 };
 use itertools::{Itertools, izip};
 use sea_orm::{
@@ -67,6 +70,14 @@ where
 
         find_many_impl(select, self.conn()).await.boxed()
     }
+
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Artist>, Box<dyn std::error::Error + Send + Sync>> {
+        find_history_impl(id, history_id, self.conn()).await.boxed()
+    }
 }
 
 #[derive(FromQueryResult)]
@@ -77,7 +88,6 @@ struct ArtistImage {
     r#type: ArtistImageType,
 }
 
-#[expect(clippy::too_many_lines, reason = "TODO")]
 async fn find_many_impl(
     select: Select<artist::Entity>,
     db: &impl ConnectionTrait,
@@ -99,32 +109,6 @@ async fn find_many_impl(
         .all(db)
         .await?;
 
-    let artist_images = artist_image::Entity::find()
-        .filter(artist_image::Column::ArtistId.is_in(ids.iter().copied()))
-        .left_join(image::Entity)
-        .into_model::<ArtistImage>()
-        .all(db)
-        .await?;
-
-    let mut images_map: HashMap<i32, Vec<_>> = artist_images.into_iter().fold(
-        HashMap::new(),
-        |mut acc, artist_image| {
-            acc.entry(artist_image.artist_id)
-                .or_default()
-                .push(artist_image);
-            acc
-        },
-    );
-
-    let (artists, images): (Vec<_>, Vec<_>) = artists
-        .into_iter()
-        .map(|artist| {
-            let artist_images =
-                images_map.remove(&artist.id).unwrap_or_default();
-            (artist, artist_images)
-        })
-        .unzip();
-
     let links = artists.load_many(artist_link::Entity, db).await?;
 
     let localized_names =
@@ -154,8 +138,195 @@ async fn find_many_impl(
         .load_many(artist_membership_tenure::Entity, db)
         .await?;
 
-    let group_association =
-        izip!(artist_memberships, roles, join_leaves).collect_vec();
+    conv_to_domain_models(
+        artists,
+        aliases,
+        links,
+        localized_names,
+        izip!(artist_memberships, roles, join_leaves).collect_vec(),
+        db,
+    )
+    .await
+}
+
+/// The artist as recorded in the history, with the id of the artist. Images
+/// aren't part of the history, so the current ones are used
+async fn find_history_impl(
+    id: i32,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<Artist>, DbErr> {
+    let Some(history) = artist_history::Entity::find_by_id(history_id)
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let (aliases, links, localized_names, memberships) = tokio::try_join!(
+        artist_alias_history::Entity::find()
+            .filter(artist_alias_history::Column::HistoryId.eq(history_id))
+            .all(db),
+        artist_link_history::Entity::find()
+            .filter(artist_link_history::Column::HistoryId.eq(history_id))
+            .order_by_asc(artist_link_history::Column::Id)
+            .all(db),
+        artist_localized_name_history::Entity::find()
+            .filter(
+                artist_localized_name_history::Column::HistoryId.eq(history_id)
+            )
+            .all(db),
+        find_membership_history(
+            id,
+            history.artist_type.is_solo(),
+            history_id,
+            db
+        ),
+    )?;
+
+    let artist = artist::Model {
+        id,
+        name: history.name,
+        artist_type: history.artist_type,
+        text_alias: history.text_alias,
+        start_date: history.start_date,
+        start_date_precision: history.start_date_precision,
+        end_date: history.end_date,
+        end_date_precision: history.end_date_precision,
+        current_location_country: history.current_location_country,
+        current_location_province: history.current_location_province,
+        current_location_city: history.current_location_city,
+        start_location_country: history.start_location_country,
+        start_location_province: history.start_location_province,
+        start_location_city: history.start_location_city,
+    };
+    let aliases = aliases
+        .into_iter()
+        .map(|alias| artist_alias::Model {
+            first_id: alias.alias_id.min(id),
+            second_id: alias.alias_id.max(id),
+        })
+        .collect();
+    let links = links
+        .into_iter()
+        .map(|link| artist_link::Model {
+            id: link.id,
+            artist_id: id,
+            url: link.url,
+        })
+        .collect();
+    let localized_names = localized_names
+        .into_iter()
+        .map(|name| artist_localized_name::Model {
+            id: name.id,
+            artist_id: id,
+            language_id: name.language_id,
+            name: name.name,
+        })
+        .collect();
+    Ok(conv_to_domain_models(
+        vec![artist],
+        aliases,
+        vec![links],
+        vec![localized_names],
+        memberships,
+        db,
+    )
+    .await?
+    .pop())
+}
+
+/// The memberships in the artist history as the models of the current
+/// memberships, which keep the ids of their history rows
+async fn find_membership_history(
+    id: i32,
+    is_solo: bool,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<MembershipModels>, DbErr> {
+    let memberships = artist_membership_history::Entity::find()
+        .filter(artist_membership_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(artist_membership_history::Column::Id)
+        .all(db)
+        .await?;
+
+    let (roles, tenures) = tokio::try_join!(
+        memberships.load_many_to_many(
+            credit_role::Entity,
+            artist_membership_role_history::Entity,
+            db,
+        ),
+        memberships.load_many(artist_membership_tenure_history::Entity, db),
+    )?;
+
+    Ok(izip!(memberships, roles, tenures)
+        .map(|(membership, roles, tenures)| {
+            let (member_id, group_id) = if is_solo {
+                (id, membership.artist_id)
+            } else {
+                (membership.artist_id, id)
+            };
+            let tenures = tenures
+                .into_iter()
+                .map(|tenure| artist_membership_tenure::Model {
+                    id: tenure.id,
+                    membership_id: membership.id,
+                    join_year: tenure.join_year,
+                    leave_year: tenure.leave_year,
+                })
+                .collect();
+
+            (
+                artist_membership::Model {
+                    id: membership.id,
+                    member_id,
+                    group_id,
+                },
+                roles,
+                tenures,
+            )
+        })
+        .collect())
+}
+
+type MembershipModels = (
+    artist_membership::Model,
+    Vec<credit_role::Model>,
+    Vec<artist_membership_tenure::Model>,
+);
+
+#[expect(clippy::too_many_lines, reason = "TODO")]
+async fn conv_to_domain_models(
+    artists: Vec<artist::Model>,
+    aliases: Vec<artist_alias::Model>,
+    links: Vec<Vec<artist_link::Model>>,
+    localized_names: Vec<Vec<artist_localized_name::Model>>,
+    group_association: Vec<MembershipModels>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<Artist>, DbErr> {
+    let artist_images = artist_image::Entity::find()
+        .filter(
+            artist_image::Column::ArtistId.is_in(artists.iter().map(|x| x.id)),
+        )
+        .left_join(image::Entity)
+        .into_model::<ArtistImage>()
+        .all(db)
+        .await?;
+
+    let mut images_map: HashMap<i32, Vec<_>> = artist_images.into_iter().fold(
+        HashMap::new(),
+        |mut acc, artist_image| {
+            acc.entry(artist_image.artist_id)
+                .or_default()
+                .push(artist_image);
+            acc
+        },
+    );
+
+    let images = artists
+        .iter()
+        .map(|artist| images_map.remove(&artist.id).unwrap_or_default())
+        .collect_vec();
 
     let langs = language::Entity::find()
         .filter(
@@ -9,9 +9,10 @@ use libfp::FunctorExt;
 use sea_orm::JoinType::*;
 use sea_orm::prelude::*;
 use sea_orm::{QuerySelect, QueryTrait};
-use sea_query::{Cond, ExprTrait, IntoCondition, SimpleExpr};
+use sea_query::{Cond, ExprTrait, SimpleExpr};
 
 use super::SeaOrmRepository;
+use super::credit_role::role_or_sub_role;
 use crate::domain::artist_release::*;
 use crate::domain::credit_role::CreditRoleRef;
 use crate::domain::image::Image;
@@ -52,7 +53,7 @@ impl Repo for SeaOrmRepository {
     ) -> Result<Paginated<Credit>, Box<dyn std::error::Error + Send + Sync>>
     {
         let releases_and_artists = find_artist_releases(
-            credit_select(query.artist_id),
+            credit_select(query.artist_id, query.role_id),
             query.pagination,
             self.conn(),
         )
@@ -74,9 +75,8 @@ impl Repo for SeaOrmRepository {
 
         let release_credits = releases
             .load_many(
-                release_credit::Entity::find().filter(
-                    release_credit::Column::ArtistId.eq(query.artist_id),
-                ),
+                release_credit::Entity::find()
+                    .filter(credit_condition(query.artist_id, query.role_id)),
                 self.conn(),
             )
             .await?;
@@ -286,21 +286,33 @@ fn appearance_select(artist_id: i32) -> Select<release::Entity> {
     )
 }
 
-fn credit_select(artist_id: i32) -> Select<release::Entity> {
+fn credit_select(
+    artist_id: i32,
+    role_id: Option<i32>,
+) -> Select<release::Entity> {
     release::Entity::find()
+        .distinct()
         .join(
             InnerJoin,
-            release_credit::Relation::Release.def().rev().on_condition(
-                move |_, _| {
-                    release_credit::Column::ArtistId
-                        .eq(artist_id)
-                        .into_condition()
-                },
-            ),
+            release_credit::Relation::Release
+                .def()
+                .rev()
+                .on_condition(move |_, _| credit_condition(artist_id, role_id)),
         )
         .filter(not_release_artist(artist_id))
 }
 
+fn credit_condition(artist_id: i32, role_id: Option<i32>) -> Cond {
+    Cond::all()
+        .add(release_credit::Column::ArtistId.eq(artist_id))
+        .add_option(role_id.map(|role_id| {
+            role_or_sub_role(
+                (release_credit::Entity, release_credit::Column::RoleId),
+                role_id,
+            )
+        }))
+}
+
 fn not_release_artist(artist_id: i32) -> SimpleExpr {
     let subquery = release_artist::Entity::find()
         .select_only()
@@ -0,0 +1,149 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use entity::enums::{CorrectionStatus, EntityType};
+use sea_orm::{ConnectionTrait, DbBackend, FromQueryResult, Statement};
+
+use crate::domain::contribution::{
+    CorrectionTally, ImageTally, LeaderboardEntry, Repo,
+};
+use crate::domain::repository::Connection;
+
+// Enums are cast to text, they are decoded from strings
+const CORRECTION_TALLIES: &str = r#"
+SELECT c."entity_type"::text AS "entity_type",
+       c."status"::text AS "status",
+       COUNT(*) AS "count",
+       MIN(c."created_at") AS "first_at",
+       MAX(c."created_at") AS "last_at"
+FROM "correction" c
+INNER JOIN "correction_user" cu ON cu."correction_id" = c."id"
+WHERE cu."user_id" = $1 AND cu."user_type" = 'Author'
+GROUP BY c."entity_type", c."status"
+"#;
+
+const IMAGE_TALLY: &str = r#"
+SELECT COUNT(*) AS "count",
+       MIN("created_at") AS "first_at",
+       MAX("created_at") AS "last_at"
+FROM "image_queue"
+WHERE "creaded_by" = $1
+"#;
+
+const LEADERBOARD: &str = r#"
+SELECT RANK() OVER (
+           ORDER BY COUNT(*) FILTER (WHERE c."status" = 'Approved') DESC
+       ) AS "rank",
+       u."name",
+       COUNT(*) FILTER (WHERE c."status" = 'Approved') AS "approved",
+       COUNT(*) AS "submitted"
+FROM "correction" c
+INNER JOIN "correction_user" cu
+    ON cu."correction_id" = c."id" AND cu."user_type" = 'Author'
+INNER JOIN "user" u ON u."id" = cu."user_id"
+WHERE $1::timestamptz IS NULL OR c."created_at" >= $1
+GROUP BY u."id", u."name"
+ORDER BY "rank", "submitted" DESC, u."name"
+LIMIT $2
+"#;
+
+#[derive(FromQueryResult)]
+struct CorrectionTallyRow {
+    entity_type: EntityType,
+    status: CorrectionStatus,
+    count: i64,
+    first_at: DateTime<FixedOffset>,
+    last_at: DateTime<FixedOffset>,
+}
+
+#[derive(FromQueryResult)]
+struct ImageTallyRow {
+    count: i64,
+    first_at: Option<DateTime<FixedOffset>>,
+    last_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(FromQueryResult)]
+struct LeaderboardRow {
+    rank: i64,
+    name: String,
+    approved: i64,
+    submitted: i64,
+}
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn find_correction_tallies(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<CorrectionTally>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let rows = CorrectionTallyRow::find_by_statement(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                CORRECTION_TALLIES,
+                [user_id.into()],
+            ),
+        )
+        .all(self.conn())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| CorrectionTally {
+                entity_type: row.entity_type,
+                status: row.status,
+                count: row.count.unsigned_abs(),
+                first_at: row.first_at,
+                last_at: row.last_at,
+            })
+            .collect())
+    }
+
+    async fn find_image_tally(
+        &self,
+        user_id: i32,
+    ) -> Result<ImageTally, Box<dyn std::error::Error + Send + Sync>> {
+        let row =
+            ImageTallyRow::find_by_statement(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                IMAGE_TALLY,
+                [user_id.into()],
+            ))
+            .one(self.conn())
+            .await?;
+
+        Ok(row.map_or_else(ImageTally::default, |row| ImageTally {
+            count: row.count.unsigned_abs(),
+            first_at: row.first_at,
+            last_at: row.last_at,
+        }))
+    }
+
+    async fn find_leaderboard(
+        &self,
+        since: Option<DateTime<Utc>>,
+        limit: u64,
+    ) -> Result<Vec<LeaderboardEntry>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let rows =
+            LeaderboardRow::find_by_statement(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                LEADERBOARD,
+                [since.into(), i64::try_from(limit)?.into()],
+            ))
+            .all(self.conn())
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| LeaderboardEntry {
+                rank: row.rank.unsigned_abs(),
+                name: row.name,
+                approved: row.approved.unsigned_abs(),
+                submitted: row.submitted.unsigned_abs(),
+            })
+            .collect())
+    }
+}
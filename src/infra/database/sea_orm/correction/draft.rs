@@ -0,0 +1,138 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use entity::correction_draft::{ActiveModel, Column, Entity, Model};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use crate::domain::correction::{
+    CorrectionDraft, DraftRepo, NewCorrectionDraft,
+};
+use crate::domain::repository::Connection;
+
+impl<T> DraftRepo for T
+where
+    T: Connection,
+    T::Conn: sea_orm::ConnectionTrait,
+{
+    async fn find_draft(
+        &self,
+        id: i32,
+    ) -> Result<Option<CorrectionDraft>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        Ok(Entity::find_by_id(id)
+            .filter(Expr::col(Column::ExpiresAt).gt(Expr::current_timestamp()))
+            .one(self.conn())
+            .await?
+            .map(TryInto::try_into)
+            .transpose()?)
+    }
+
+    async fn find_drafts_by_author(
+        &self,
+        author_id: i32,
+    ) -> Result<Vec<CorrectionDraft>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        Ok(Entity::find()
+            .filter(Column::AuthorId.eq(author_id))
+            .filter(Expr::col(Column::ExpiresAt).gt(Expr::current_timestamp()))
+            .order_by_desc(Column::UpdatedAt)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn create_draft(
+        &self,
+        author_id: i32,
+        draft: NewCorrectionDraft,
+        expires_at: DateTime<FixedOffset>,
+    ) -> Result<CorrectionDraft, Box<dyn std::error::Error + Send + Sync>> {
+        let now = Utc::now();
+
+        let model = ActiveModel {
+            id: NotSet,
+            author_id: Set(author_id),
+            entity_type: Set(draft.entity_type),
+            entity_id: Set(draft.entity_id),
+            base_history_id: Set(draft.base_history_id),
+            description: Set(draft.description),
+            data: Set(draft.data),
+            sources: Set(serde_json::to_value(&draft.sources)?),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
+            expires_at: Set(expires_at),
+        }
+        .insert(self.conn())
+        .await?;
+
+        Ok(model.try_into()?)
+    }
+
+    async fn update_draft(
+        &self,
+        id: i32,
+        draft: NewCorrectionDraft,
+        expires_at: DateTime<FixedOffset>,
+    ) -> Result<CorrectionDraft, Box<dyn std::error::Error + Send + Sync>> {
+        let model = ActiveModel {
+            id: Set(id),
+            author_id: NotSet,
+            entity_type: Set(draft.entity_type),
+            entity_id: Set(draft.entity_id),
+            base_history_id: Set(draft.base_history_id),
+            description: Set(draft.description),
+            data: Set(draft.data),
+            sources: Set(serde_json::to_value(&draft.sources)?),
+            created_at: NotSet,
+            updated_at: Set(Utc::now().into()),
+            expires_at: Set(expires_at),
+        }
+        .update(self.conn())
+        .await?;
+
+        Ok(model.try_into()?)
+    }
+
+    async fn delete_draft(
+        &self,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let result = Entity::delete_by_id(id).exec(self.conn()).await?;
+
+        Ok(result.rows_affected != 0)
+    }
+
+    async fn delete_expired_drafts(
+        &self,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Entity::delete_many()
+            .filter(Expr::col(Column::ExpiresAt).lte(Expr::current_timestamp()))
+            .exec(self.conn())
+            .await?
+            .rows_affected)
+    }
+}
+
+impl TryFrom<Model> for CorrectionDraft {
+    type Error = serde_json::Error;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: model.id,
+            author_id: model.author_id,
+            entity_type: model.entity_type,
+            entity_id: model.entity_id,
+            base_history_id: model.base_history_id,
+            description: model.description,
+            data: model.data,
+            sources: serde_json::from_value(model.sources)?,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+            expires_at: model.expires_at,
+        })
+    }
+}
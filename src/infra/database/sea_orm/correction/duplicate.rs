@@ -0,0 +1,151 @@
+use entity::enums::EntityType;
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, FromQueryResult, Statement};
+
+use crate::domain::correction::{DuplicateCandidate, DuplicatePolicy};
+
+/// Where the names of an entity type are stored. Each source is a `FROM`
+/// clause exposing the entity as `e`, and the expression of the name in it
+struct NameSources {
+    /// Column of the display name of the entity
+    column: &'static str,
+    sources: &'static [(&'static str, &'static str)],
+}
+
+const fn name_sources(entity_type: EntityType) -> Option<NameSources> {
+    Some(match entity_type {
+        EntityType::Artist => NameSources {
+            column: "name",
+            sources: &[
+                (r#""artist" e"#, r#"e."name""#),
+                (
+                    r#""artist_localized_name" o JOIN "artist" e ON e."id" = o."artist_id""#,
+                    r#"o."name""#,
+                ),
+                (
+                    r#""artist" e CROSS JOIN LATERAL unnest(e."text_alias") o("name")"#,
+                    r#"o."name""#,
+                ),
+            ],
+        },
+        EntityType::Label => NameSources {
+            column: "name",
+            sources: &[
+                (r#""label" e"#, r#"e."name""#),
+                (
+                    r#""label_localized_name" o JOIN "label" e ON e."id" = o."label_id""#,
+                    r#"o."name""#,
+                ),
+            ],
+        },
+        EntityType::Release => NameSources {
+            column: "title",
+            sources: &[
+                (r#""release" e"#, r#"e."title""#),
+                (
+                    r#""release_localized_title" o JOIN "release" e ON e."id" = o."release_id""#,
+                    r#"o."title""#,
+                ),
+            ],
+        },
+        EntityType::Song => NameSources {
+            column: "title",
+            sources: &[
+                (r#""song" e"#, r#"e."title""#),
+                (
+                    r#""song_localized_title" o JOIN "song" e ON e."id" = o."song_id""#,
+                    r#"o."title""#,
+                ),
+            ],
+        },
+        EntityType::Event => NameSources {
+            column: "name",
+            sources: &[
+                (r#""event" e"#, r#"e."name""#),
+                (
+                    r#""event_alternative_name" o JOIN "event" e ON e."id" = o."event_id""#,
+                    r#"o."name""#,
+                ),
+            ],
+        },
+        EntityType::Tag | EntityType::SongLyrics | EntityType::CreditRole => {
+            return None;
+        }
+    })
+}
+
+// `%` is used in addition to similarity() so the gist trigram indexes on the
+// name columns apply. Artist aliases are an array and are scanned
+fn duplicate_sql(names: &NameSources) -> String {
+    let column = names.column;
+
+    let matches = names
+        .sources
+        .iter()
+        .map(|(from, name)| {
+            format!(
+                r#"  SELECT e."id", e."{column}" AS "name", similarity({name}, q."name") AS "similarity"
+  FROM {from} CROSS JOIN "q"
+  WHERE {name} % q."name""#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n  UNION ALL\n");
+
+    format!(
+        r#"
+WITH "q" AS (SELECT jsonb_array_elements_text($1) AS "name")
+SELECT "id", "name", max("similarity") AS "similarity"
+FROM (
+{matches}
+) "m"
+WHERE "similarity" >= $2
+GROUP BY "id", "name"
+ORDER BY "similarity" DESC, "id"
+LIMIT $3
+"#
+    )
+}
+
+#[derive(FromQueryResult)]
+struct CandidateRow {
+    id: i32,
+    name: String,
+    similarity: f32,
+}
+
+pub(super) async fn find_duplicates(
+    entity_type: EntityType,
+    names: &[String],
+    policy: &DuplicatePolicy,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<DuplicateCandidate>, DbErr> {
+    let Some(sources) = name_sources(entity_type) else {
+        return Ok(vec![]);
+    };
+
+    if names.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let rows = CandidateRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        duplicate_sql(&sources),
+        [
+            serde_json::json!(names).into(),
+            policy.similarity_threshold.into(),
+            policy.max_candidates.into(),
+        ],
+    ))
+    .all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| DuplicateCandidate {
+            entity_type,
+            id: row.id,
+            name: row.name,
+            similarity: row.similarity,
+        })
+        .collect())
+}
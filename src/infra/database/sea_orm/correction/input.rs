@@ -0,0 +1,583 @@
+use entity::enums::EntityType;
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, FromQueryResult, Statement};
+
+/// How a value of the input is built from the history record, aliased as
+/// `h`
+#[derive(Clone, Copy)]
+enum Field {
+    /// A sql expression
+    Sql(&'static str),
+    /// `{ value, precision }` of a date column, as in `DateWithPrecision`
+    Date(&'static str),
+    /// `{ country, province, city }` of the columns with the prefix, as in
+    /// `Location`, without the null ones
+    Location(&'static str),
+    Object(Fields),
+    /// An object without the null fields
+    SparseObject(Fields),
+    List(List),
+}
+
+/// Json array of `value` over the rows of a child table
+#[derive(Clone, Copy)]
+struct List {
+    table: &'static str,
+    alias: &'static str,
+    /// The column referencing the id of the parent row
+    parent: &'static str,
+    value: &'static Field,
+    order: &'static str,
+}
+
+type Fields = &'static [(&'static str, Field)];
+
+/// Rows of a child table of the history record, aliased as `c`
+const fn rows(
+    table: &'static str,
+    value: &'static Field,
+    order: &'static str,
+) -> Field {
+    Field::List(List {
+        table,
+        alias: "c",
+        parent: "history_id",
+        value,
+        order,
+    })
+}
+
+const LOCALIZED_NAME: Field = Field::Object(&[
+    ("language_id", Field::Sql(r#"c."language_id""#)),
+    ("name", Field::Sql(r#"c."name""#)),
+]);
+
+// Rows without an id are ordered by their content, rows with one by the
+// order they were inserted in
+const ARTIST: Fields = &[
+    ("name", Field::Sql(r#"h."name""#)),
+    ("artist_type", Field::Sql(r#"h."artist_type""#)),
+    (
+        "aliases",
+        rows(
+            "artist_alias_history",
+            &Field::Sql(r#"c."alias_id""#),
+            r#"c."alias_id""#,
+        ),
+    ),
+    ("text_aliases", Field::Sql(r#"to_jsonb(h."text_alias")"#)),
+    ("start_date", Field::Date("start_date")),
+    ("end_date", Field::Date("end_date")),
+    (
+        "links",
+        rows(
+            "artist_link_history",
+            &Field::Sql(r#"c."url""#),
+            r#"c."id""#,
+        ),
+    ),
+    (
+        "localized_names",
+        rows(
+            "artist_localized_name_history",
+            &LOCALIZED_NAME,
+            r#"c."id""#,
+        ),
+    ),
+    ("start_location", Field::Location("start_location")),
+    ("current_location", Field::Location("current_location")),
+    (
+        "memberships",
+        rows(
+            "artist_membership_history",
+            &Field::Object(&[
+                ("artist_id", Field::Sql(r#"c."artist_id""#)),
+                (
+                    "roles",
+                    Field::List(List {
+                        table: "artist_membership_role_history",
+                        alias: "r",
+                        parent: "membership_history_id",
+                        value: &Field::Sql(r#"r."role_id""#),
+                        order: r#"r."role_id""#,
+                    }),
+                ),
+                (
+                    "tenure",
+                    Field::List(List {
+                        table: "artist_membership_tenure_history",
+                        alias: "t",
+                        parent: "membership_history_id",
+                        value: &Field::SparseObject(&[
+                            ("join_year", Field::Sql(r#"t."join_year""#)),
+                            ("leave_year", Field::Sql(r#"t."leave_year""#)),
+                        ]),
+                        order: r#"t."id""#,
+                    }),
+                ),
+            ]),
+            r#"c."id""#,
+        ),
+    ),
+];
+
+const LABEL: Fields = &[
+    ("name", Field::Sql(r#"h."name""#)),
+    ("founded_date", Field::Date("founded_date")),
+    ("dissolved_date", Field::Date("dissolved_date")),
+    (
+        "founders",
+        rows(
+            "label_founder_history",
+            &Field::Sql(r#"c."artist_id""#),
+            r#"c."artist_id""#,
+        ),
+    ),
+    (
+        "localized_names",
+        rows(
+            "label_localized_name_history",
+            &LOCALIZED_NAME,
+            r#"c."language_id", c."name""#,
+        ),
+    ),
+];
+
+const RELEASE: Fields = &[
+    ("title", Field::Sql(r#"h."title""#)),
+    // The only variant whose database value differs from its name
+    (
+        "release_type",
+        Field::Sql(
+            r#"CASE h."release_type" WHEN 'EP' THEN 'Ep' ELSE h."release_type"::text END"#,
+        ),
+    ),
+    ("release_date", Field::Date("release_date")),
+    ("recording_date_start", Field::Date("recording_date_start")),
+    ("recording_date_end", Field::Date("recording_date_end")),
+    (
+        "artists",
+        rows(
+            "release_artist_history",
+            &Field::Sql(r#"c."artist_id""#),
+            r#"c."artist_id""#,
+        ),
+    ),
+    (
+        "catalog_nums",
+        rows(
+            "release_catalog_number_history",
+            &Field::Object(&[
+                ("catalog_number", Field::Sql(r#"c."catalog_number""#)),
+                ("label_id", Field::Sql(r#"c."label_id""#)),
+            ]),
+            r#"c."id""#,
+        ),
+    ),
+    (
+        "credits",
+        rows(
+            "release_credit_history",
+            &Field::Object(&[
+                ("artist_id", Field::Sql(r#"c."artist_id""#)),
+                ("role_id", Field::Sql(r#"c."role_id""#)),
+                ("on", Field::Sql(r#"c."on""#)),
+            ]),
+            r#"c."id""#,
+        ),
+    ),
+    (
+        "discs",
+        rows(
+            "release_disc_history",
+            &Field::Object(&[("name", Field::Sql(r#"c."name""#))]),
+            r#"c."id""#,
+        ),
+    ),
+    (
+        "events",
+        rows(
+            "release_event_history",
+            &Field::Sql(r#"c."event_id""#),
+            r#"c."event_id""#,
+        ),
+    ),
+    (
+        "localized_titles",
+        rows(
+            "release_localized_title_history",
+            &Field::Object(&[
+                ("language_id", Field::Sql(r#"c."language_id""#)),
+                ("title", Field::Sql(r#"c."title""#)),
+            ]),
+            r#"c."language_id", c."title""#,
+        ),
+    ),
+    (
+        "tracks",
+        rows(
+            "release_track_history",
+            &Field::Object(&[
+                ("song_id", Field::Sql(r#"c."song_id""#)),
+                ("track_number", Field::Sql(r#"c."track_number""#)),
+                ("display_title", Field::Sql(r#"c."display_title""#)),
+                ("duration", Field::Sql(r#"c."duration""#)),
+                (
+                    "disc_index",
+                    Field::Sql(
+                        r#"(SELECT count(*) FROM "release_disc_history" d WHERE d."history_id" = h."id" AND d."id" < c."disc_history_id")"#,
+                    ),
+                ),
+                (
+                    "artists",
+                    Field::List(List {
+                        table: "release_track_artist_history",
+                        alias: "a",
+                        parent: "track_history_id",
+                        value: &Field::Sql(r#"a."artist_id""#),
+                        order: r#"a."artist_id""#,
+                    }),
+                ),
+            ]),
+            r#"c."id""#,
+        ),
+    ),
+];
+
+const SONG: Fields = &[
+    ("title", Field::Sql(r#"h."title""#)),
+    (
+        "artists",
+        rows(
+            "song_artist_history",
+            &Field::Sql(r#"c."artist_id""#),
+            r#"c."artist_id""#,
+        ),
+    ),
+    (
+        "credits",
+        rows(
+            "song_credit_history",
+            &Field::Object(&[
+                ("artist_id", Field::Sql(r#"c."artist_id""#)),
+                ("role_id", Field::Sql(r#"c."role_id""#)),
+            ]),
+            r#"c."id""#,
+        ),
+    ),
+    (
+        "languages",
+        rows(
+            "song_language_history",
+            &Field::Sql(r#"c."language_id""#),
+            r#"c."language_id""#,
+        ),
+    ),
+    (
+        "localized_titles",
+        rows(
+            "song_localized_title_history",
+            &Field::Object(&[
+                ("language_id", Field::Sql(r#"c."language_id""#)),
+                ("name", Field::Sql(r#"c."title""#)),
+            ]),
+            r#"c."id""#,
+        ),
+    ),
+];
+
+const TAG: Fields = &[
+    ("name", Field::Sql(r#"h."name""#)),
+    ("type", Field::Sql(r#"h."type""#)),
+    ("short_description", Field::Sql(r#"h."short_description""#)),
+    ("description", Field::Sql(r#"h."description""#)),
+    (
+        "alt_names",
+        rows(
+            "tag_alternative_name_history",
+            &Field::Sql(r#"c."name""#),
+            r#"c."id""#,
+        ),
+    ),
+    (
+        "relations",
+        rows(
+            "tag_relation_history",
+            &Field::Object(&[
+                ("related_tag_id", Field::Sql(r#"c."related_tag_id""#)),
+                ("type", Field::Sql(r#"c."type""#)),
+            ]),
+            r#"c."related_tag_id""#,
+        ),
+    ),
+];
+
+const EVENT: Fields = &[
+    ("name", Field::Sql(r#"h."name""#)),
+    ("short_description", Field::Sql(r#"h."short_description""#)),
+    ("description", Field::Sql(r#"h."description""#)),
+    ("start_date", Field::Date("start_date")),
+    ("end_date", Field::Date("end_date")),
+    (
+        "alternative_names",
+        rows(
+            "event_alternative_name_history",
+            &Field::Sql(r#"c."name""#),
+            r#"c."id""#,
+        ),
+    ),
+];
+
+const SONG_LYRICS: Fields = &[
+    ("song_id", Field::Sql(r#"h."song_id""#)),
+    ("language_id", Field::Sql(r#"h."language_id""#)),
+    ("content", Field::Sql(r#"h."content""#)),
+    ("is_main", Field::Sql(r#"h."is_main""#)),
+];
+
+const CREDIT_ROLE: Fields = &[
+    ("name", Field::Sql(r#"h."name""#)),
+    ("short_description", Field::Sql(r#"h."short_description""#)),
+    ("description", Field::Sql(r#"h."description""#)),
+    (
+        "super_roles",
+        rows(
+            "credit_role_inheritance_history",
+            &Field::Sql(r#"c."super_id""#),
+            r#"c."super_id""#,
+        ),
+    ),
+];
+
+const fn input_fields(entity_type: EntityType) -> Fields {
+    match entity_type {
+        EntityType::Artist => ARTIST,
+        EntityType::Label => LABEL,
+        EntityType::Release => RELEASE,
+        EntityType::Song => SONG,
+        EntityType::Tag => TAG,
+        EntityType::Event => EVENT,
+        EntityType::SongLyrics => SONG_LYRICS,
+        EntityType::CreditRole => CREDIT_ROLE,
+    }
+}
+
+/// `parent` is the alias of the row the field is read from
+fn field_sql(field: &Field, parent: &str) -> String {
+    match field {
+        Field::Sql(sql) => (*sql).to_owned(),
+        Field::Date(column) => format!(
+            r#"CASE WHEN h."{column}" IS NULL THEN NULL ELSE jsonb_build_object('value', h."{column}", 'precision', h."{column}_precision") END"#
+        ),
+        Field::Location(prefix) => format!(
+            r#"jsonb_strip_nulls(jsonb_build_object('country', h."{prefix}_country", 'province', h."{prefix}_province", 'city', h."{prefix}_city"))"#
+        ),
+        Field::Object(fields) => object_sql(fields, parent),
+        Field::SparseObject(fields) => {
+            format!("jsonb_strip_nulls({})", object_sql(fields, parent))
+        }
+        Field::List(List {
+            table,
+            alias,
+            parent: column,
+            value,
+            order,
+        }) => format!(
+            r#"(SELECT COALESCE(jsonb_agg({value} ORDER BY {order}), '[]'::jsonb) FROM "{table}" {alias} WHERE {alias}."{column}" = {parent}."id")"#,
+            value = field_sql(value, alias)
+        ),
+    }
+}
+
+fn object_sql(fields: Fields, parent: &str) -> String {
+    let fields = fields
+        .iter()
+        .map(|(key, field)| format!("'{key}', {}", field_sql(field, parent)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("jsonb_build_object({fields})")
+}
+
+const fn history_table(entity_type: EntityType) -> &'static str {
+    match entity_type {
+        EntityType::Artist => "artist_history",
+        EntityType::Label => "label_history",
+        EntityType::Release => "release_history",
+        EntityType::Song => "song_history",
+        EntityType::Tag => "tag_history",
+        EntityType::Event => "event_history",
+        EntityType::SongLyrics => "song_lyrics_history",
+        EntityType::CreditRole => "credit_role_history",
+    }
+}
+
+fn input_sql(entity_type: EntityType) -> String {
+    format!(
+        r#"SELECT {input} AS "input" FROM "{table}" h WHERE h."id" = $1"#,
+        input = object_sql(input_fields(entity_type), "h"),
+        table = history_table(entity_type)
+    )
+}
+
+#[derive(FromQueryResult)]
+struct InputRow {
+    input: serde_json::Value,
+}
+
+/// The history record in the input form of its entity type, ie. the data
+/// the record would be created from
+pub(super) async fn find_history_input(
+    entity_type: EntityType,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<serde_json::Value>, DbErr> {
+    let row = InputRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        input_sql(entity_type),
+        [history_id.into()],
+    ))
+    .one(db)
+    .await?;
+
+    Ok(row.map(|row| row.input))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+    use serde_json::Value;
+    use utoipa::ToSchema;
+
+    use super::*;
+    use crate::domain::artist::model::NewArtist;
+    use crate::domain::credit_role::NewCreditRole;
+    use crate::domain::event::NewEvent;
+    use crate::domain::label::NewLabel;
+    use crate::domain::release::NewRelease;
+    use crate::domain::song::model::NewSong;
+    use crate::domain::song_lyrics::NewSongLyrics;
+    use crate::domain::tag::NewTag;
+
+    /// The keys of each object by its path, eg. `tracks[].artists`
+    type Shape = BTreeMap<String, BTreeSet<String>>;
+
+    fn schema_shape<T: ToSchema>() -> Shape {
+        let mut components = vec![];
+        T::schemas(&mut components);
+
+        let components = components
+            .into_iter()
+            .map(|(name, schema)| (name, serde_json::to_value(schema).unwrap()))
+            .collect::<HashMap<_, _>>();
+
+        let mut shape = Shape::new();
+        collect_schema(
+            &serde_json::to_value(T::schema()).unwrap(),
+            "",
+            &components,
+            &mut shape,
+        );
+        shape
+    }
+
+    fn collect_schema(
+        schema: &Value,
+        path: &str,
+        components: &HashMap<String, Value>,
+        shape: &mut Shape,
+    ) {
+        let Value::Object(map) = schema else {
+            return;
+        };
+
+        if let Some(Value::String(reference)) = map.get("$ref") {
+            let name = reference.rsplit('/').next().unwrap();
+            collect_schema(&components[name], path, components, shape);
+        }
+        for key in ["oneOf", "anyOf", "allOf"] {
+            if let Some(Value::Array(schemas)) = map.get(key) {
+                for schema in schemas {
+                    collect_schema(schema, path, components, shape);
+                }
+            }
+        }
+        if let Some(items) = map.get("items") {
+            collect_schema(items, &format!("{path}[]"), components, shape);
+        }
+        if let Some(Value::Object(properties)) = map.get("properties") {
+            shape
+                .entry(path.to_owned())
+                .or_default()
+                .extend(properties.keys().cloned());
+
+            for (key, property) in properties {
+                collect_schema(property, &join(path, key), components, shape);
+            }
+        }
+    }
+
+    fn input_shape(fields: Fields) -> Shape {
+        let mut shape = Shape::new();
+        collect_fields(fields, "", &mut shape);
+        shape
+    }
+
+    fn collect_fields(fields: Fields, path: &str, shape: &mut Shape) {
+        shape
+            .entry(path.to_owned())
+            .or_default()
+            .extend(fields.iter().map(|(key, _)| (*key).to_owned()));
+
+        for (key, field) in fields {
+            collect_field(field, &join(path, key), shape);
+        }
+    }
+
+    fn collect_field(field: &Field, path: &str, shape: &mut Shape) {
+        let keys: &[&str] = match field {
+            Field::Sql(_) => return,
+            Field::Date(_) => &["value", "precision"],
+            Field::Location(_) => &["country", "province", "city"],
+            Field::Object(fields) | Field::SparseObject(fields) => {
+                return collect_fields(fields, path, shape);
+            }
+            Field::List(list) => {
+                return collect_field(list.value, &format!("{path}[]"), shape);
+            }
+        };
+
+        shape
+            .entry(path.to_owned())
+            .or_default()
+            .extend(keys.iter().map(|key| (*key).to_owned()));
+    }
+
+    fn join(path: &str, key: &str) -> String {
+        if path.is_empty() {
+            key.to_owned()
+        } else {
+            format!("{path}.{key}")
+        }
+    }
+
+    fn assert_same_shape<T: ToSchema>(entity_type: EntityType) {
+        assert_eq!(
+            input_shape(input_fields(entity_type)),
+            schema_shape::<T>(),
+            "{entity_type:?}"
+        );
+    }
+
+    #[test]
+    fn input_matches_new_entities() {
+        assert_same_shape::<NewArtist>(EntityType::Artist);
+        assert_same_shape::<NewLabel>(EntityType::Label);
+        assert_same_shape::<NewRelease>(EntityType::Release);
+        assert_same_shape::<NewSong>(EntityType::Song);
+        assert_same_shape::<NewTag>(EntityType::Tag);
+        assert_same_shape::<NewEvent>(EntityType::Event);
+        assert_same_shape::<NewSongLyrics>(EntityType::SongLyrics);
+        assert_same_shape::<NewCreditRole>(EntityType::CreditRole);
+    }
+}
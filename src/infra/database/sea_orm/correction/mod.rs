@@ -0,0 +1,790 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use entity::correction::{Column, Entity};
+use entity::enums::{CorrectionStatus, CorrectionUserType, EntityType};
+use entity::{
+    correction_changeset, correction_changeset_item, correction_revision,
+    correction_revision_source, correction_user, correction_vote,
+    release_credit, release_track, song_credit,
+};
+use itertools::Itertools;
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::{Alias, Expr, OnConflict, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, DbErr,
+    EntityTrait, IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder,
+    QueryTrait,
+};
+
+use super::SeaOrmTxRepo;
+use crate::domain::artist::TxRepo as _;
+use crate::domain::artist::model::NewArtist;
+use crate::domain::correction::{
+    ApproveCorrectionContext, Changeset, ChangesetEntity, Correction,
+    CorrectionEntity, CorrectionEntityRepo, CorrectionFilter,
+    CorrectionFilterStatus, CorrectionRevision, CorrectionSource,
+    CorrectionUser, CorrectionVote, DuplicateCandidate, DuplicatePolicy,
+    NewCorrectionMeta, NewCorrectionVote, ReferenceKind, Repo, TxRepo,
+};
+use crate::domain::credit_role::{NewCreditRole, TxRepo as _};
+use crate::domain::event::{NewEvent, TxRepo as _};
+use crate::domain::label::{NewLabel, TxRepo as _};
+use crate::domain::model::auth::CorrectionApprover;
+use crate::domain::release::{NewRelease, TxRepo as _};
+use crate::domain::repository::Connection;
+use crate::domain::song::TxRepo as _;
+use crate::domain::song::model::NewSong;
+use crate::domain::song_lyrics::{NewSongLyrics, TxRepo as _};
+use crate::domain::tag::{NewTag, TxRepo as _};
+use crate::infra;
+
+mod draft;
+mod duplicate;
+mod input;
+mod protection;
+mod snapshot;
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: sea_orm::ConnectionTrait,
+{
+    async fn find_one(
+        &self,
+        filter: CorrectionFilter,
+    ) -> Result<Option<Correction>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let ret = Entity::find()
+            .filter(Column::EntityId.eq(filter.entity_id))
+            .filter(Column::EntityType.eq(filter.entity_type))
+            .apply_if(filter.status, |query, status| match status {
+                CorrectionFilterStatus::Many(many) => {
+                    query.filter(Column::Status.is_in(many))
+                }
+                CorrectionFilterStatus::One(one) => {
+                    query.filter(Column::Status.eq(one))
+                }
+            })
+            .order_by_desc(Column::CreatedAt)
+            .one(self.conn())
+            .await?
+            .map(Into::into);
+        Ok(ret)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<Correction>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        Ok(Entity::find_by_id(id)
+            .one(self.conn())
+            .await?
+            .map(Into::into))
+    }
+
+    async fn find_users(
+        &self,
+        correction_id: i32,
+    ) -> Result<Vec<CorrectionUser>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let users = correction_user::Entity::find()
+            .filter(correction_user::Column::CorrectionId.eq(correction_id))
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(|model| CorrectionUser {
+                user_id: model.user_id,
+                user_type: model.user_type,
+            })
+            .collect();
+        Ok(users)
+    }
+
+    async fn find_votes(
+        &self,
+        correction_id: i32,
+    ) -> Result<Vec<CorrectionVote>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let votes = correction_vote::Entity::find()
+            .filter(correction_vote::Column::CorrectionId.eq(correction_id))
+            .order_by_asc(correction_vote::Column::CreatedAt)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(|model| CorrectionVote {
+                user_id: model.user_id,
+                vote: model.vote,
+                comment: model.comment,
+                created_at: model.created_at,
+            })
+            .collect();
+        Ok(votes)
+    }
+
+    async fn find_latest_revision(
+        &self,
+        correction_id: i32,
+    ) -> Result<
+        Option<CorrectionRevision>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        let revision = correction_revision::Entity::find()
+            .filter(correction_revision::Column::CorrectionId.eq(correction_id))
+            .order_by_desc(correction_revision::Column::EntityHistoryId)
+            .one(self.conn())
+            .await?;
+
+        Ok(with_sources(
+            correction_id,
+            revision.into_iter().collect(),
+            self.conn(),
+        )
+        .await?
+        .pop())
+    }
+
+    async fn find_revisions(
+        &self,
+        correction_id: i32,
+    ) -> Result<Vec<CorrectionRevision>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let revisions = correction_revision::Entity::find()
+            .filter(correction_revision::Column::CorrectionId.eq(correction_id))
+            .order_by_asc(correction_revision::Column::EntityHistoryId)
+            .all(self.conn())
+            .await?;
+
+        with_sources(correction_id, revisions, self.conn()).await
+    }
+
+    async fn find_current_history_id(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        let revision = correction_revision::Entity::find()
+            .inner_join(Entity)
+            .filter(Column::EntityType.eq(entity_type))
+            .filter(Column::EntityId.eq(entity_id))
+            .filter(Column::Status.eq(CorrectionStatus::Approved))
+            .order_by_desc(Column::HandledAt)
+            .order_by_desc(correction_revision::Column::EntityHistoryId)
+            .one(self.conn())
+            .await?;
+        Ok(revision.map(|model| model.entity_history_id))
+    }
+
+    async fn find_history_id_at(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+        at: DateTime<FixedOffset>,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        let revision = correction_revision::Entity::find()
+            .inner_join(Entity)
+            .filter(Column::EntityType.eq(entity_type))
+            .filter(Column::EntityId.eq(entity_id))
+            .filter(Column::Status.eq(CorrectionStatus::Approved))
+            .filter(Column::HandledAt.lt(at))
+            .order_by_desc(Column::HandledAt)
+            .order_by_desc(correction_revision::Column::EntityHistoryId)
+            .one(self.conn())
+            .await?;
+        Ok(revision.map(|model| model.entity_history_id))
+    }
+
+    async fn find_history_snapshot(
+        &self,
+        entity_type: EntityType,
+        history_id: i32,
+    ) -> Result<
+        Option<serde_json::Value>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        Ok(snapshot::find_history_snapshot(
+            entity_type,
+            history_id,
+            self.conn(),
+        )
+        .await?)
+    }
+
+    async fn find_history_input(
+        &self,
+        entity_type: EntityType,
+        history_id: i32,
+    ) -> Result<
+        Option<serde_json::Value>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        Ok(
+            input::find_history_input(entity_type, history_id, self.conn())
+                .await?,
+        )
+    }
+
+    async fn is_author(
+        &self,
+        user: &crate::domain::user::User,
+        correction: &Correction,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let correction_id = correction.id;
+        let count = correction_user::Entity::find()
+            .filter(correction_user::Column::CorrectionId.eq(correction_id))
+            .filter(correction_user::Column::UserId.eq(user.id))
+            .filter(correction_user::Column::UserType.is_in([
+                CorrectionUserType::Author,
+                CorrectionUserType::CoAuthor,
+            ]))
+            .count(self.conn())
+            .await?;
+        Ok(count != 0)
+    }
+
+    async fn entity_size(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let size = match entity_type {
+            EntityType::Release => {
+                release_track::Entity::find()
+                    .filter(release_track::Column::ReleaseId.eq(entity_id))
+                    .count(self.conn())
+                    .await?
+            }
+            EntityType::Artist => {
+                release_credit::Entity::find()
+                    .filter(release_credit::Column::ArtistId.eq(entity_id))
+                    .count(self.conn())
+                    .await?
+                    + song_credit::Entity::find()
+                        .filter(song_credit::Column::ArtistId.eq(entity_id))
+                        .count(self.conn())
+                        .await?
+            }
+            _ => 0,
+        };
+        Ok(size)
+    }
+
+    async fn count_approved(
+        &self,
+        user_id: i32,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let count = Entity::find()
+            .inner_join(correction_user::Entity)
+            .filter(Column::Status.eq(CorrectionStatus::Approved))
+            .filter(correction_user::Column::UserId.eq(user_id))
+            .filter(
+                correction_user::Column::UserType
+                    .eq(CorrectionUserType::Author),
+            )
+            .count(self.conn())
+            .await?;
+        Ok(count)
+    }
+
+    async fn find_changeset(
+        &self,
+        id: i32,
+    ) -> Result<Option<Changeset>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let Some(changeset) = correction_changeset::Entity::find_by_id(id)
+            .one(self.conn())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let entities = correction_changeset_item::Entity::find()
+            .filter(correction_changeset_item::Column::ChangesetId.eq(id))
+            .order_by_asc(correction_changeset_item::Column::Position)
+            .find_also_related(Entity)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .filter_map(|(item, correction)| {
+                Some(ChangesetEntity {
+                    temp_id: item.temp_id,
+                    correction: correction?.into(),
+                })
+            })
+            .collect();
+
+        Ok(Some(Changeset {
+            id: changeset.id,
+            created_at: changeset.created_at,
+            entities,
+        }))
+    }
+
+    async fn find_existing_ids(
+        &self,
+        kind: ReferenceKind,
+        ids: &BTreeSet<i32>,
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        let table = match kind {
+            ReferenceKind::Artist => "artist",
+            ReferenceKind::CreditRole => "credit_role",
+            ReferenceKind::Event => "event",
+            ReferenceKind::Label => "label",
+            ReferenceKind::Language => "language",
+            ReferenceKind::Song => "song",
+            ReferenceKind::Tag => "tag",
+        };
+
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new(table))
+            .and_where(Expr::col(Alias::new("id")).is_in(ids.iter().copied()))
+            .to_owned();
+
+        let rows = self
+            .conn()
+            .query_all(DbBackend::Postgres.build(&query))
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| row.try_get::<i32>("", "id"))
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn find_duplicates(
+        &self,
+        entity_type: EntityType,
+        names: &[String],
+        policy: &DuplicatePolicy,
+    ) -> Result<Vec<DuplicateCandidate>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        Ok(
+            duplicate::find_duplicates(entity_type, names, policy, self.conn())
+                .await?,
+        )
+    }
+
+    async fn find_changeset_id(
+        &self,
+        correction_id: i32,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(correction_changeset_item::Entity::find_by_id(correction_id)
+            .one(self.conn())
+            .await?
+            .map(|item| item.changeset_id))
+    }
+}
+
+impl TxRepo for SeaOrmTxRepo {
+    async fn create(
+        &self,
+        meta: NewCorrectionMeta<impl CorrectionEntity>,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        let new_correction = entity::correction::ActiveModel {
+            id: NotSet,
+            status: Set(CorrectionStatus::Pending),
+            r#type: Set(meta.r#type),
+            entity_type: Set(meta.entity_type()),
+            entity_id: Set(meta.entity_id),
+            created_at: NotSet,
+            handled_at: NotSet,
+        }
+        .insert(self.conn())
+        .await?;
+
+        let correction_id = new_correction.id;
+
+        // TODO: remove dupelicate correction user table
+        entity::correction_user::Model {
+            correction_id,
+            user_id: meta.author.id,
+            user_type: CorrectionUserType::Author,
+        }
+        .into_active_model()
+        .insert(self.conn())
+        .await?;
+
+        correction_revision::Model {
+            correction_id,
+            entity_history_id: meta.history_id,
+            description: meta.description,
+            author_id: meta.author.id,
+            base_history_id: meta.base_history_id,
+        }
+        .into_active_model()
+        .insert(self.conn())
+        .await?;
+
+        insert_sources(
+            correction_id,
+            meta.history_id,
+            meta.sources,
+            self.conn(),
+        )
+        .await?;
+
+        Ok(correction_id)
+    }
+
+    async fn update(
+        &self,
+        id: i32,
+        meta: NewCorrectionMeta<impl CorrectionEntity>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        correction_revision::Model {
+            correction_id: id,
+            entity_history_id: meta.history_id,
+            description: meta.description,
+            author_id: meta.author.id,
+            base_history_id: meta.base_history_id,
+        }
+        .into_active_model()
+        .insert(self.conn())
+        .await?;
+
+        insert_sources(id, meta.history_id, meta.sources, self.conn()).await?;
+
+        Ok(())
+    }
+
+    async fn vote(
+        &self,
+        correction_id: i32,
+        user_id: i32,
+        vote: NewCorrectionVote,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        correction_vote::Entity::insert(correction_vote::ActiveModel {
+            correction_id: Set(correction_id),
+            user_id: Set(user_id),
+            vote: Set(vote.vote),
+            comment: Set(vote.comment),
+            created_at: Set(Utc::now().into()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                correction_vote::Column::CorrectionId,
+                correction_vote::Column::UserId,
+            ])
+            .update_columns([
+                correction_vote::Column::Vote,
+                correction_vote::Column::Comment,
+                correction_vote::Column::CreatedAt,
+            ])
+            .to_owned(),
+        )
+        .exec_without_returning(self.conn())
+        .await?;
+
+        insert_correction_user(
+            correction_id,
+            user_id,
+            CorrectionUserType::Reviewer,
+            self.conn(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn add_co_author(
+        &self,
+        correction_id: i32,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        insert_correction_user(
+            correction_id,
+            user_id,
+            CorrectionUserType::CoAuthor,
+            self.conn(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reject(
+        &self,
+        correction_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        entity::correction::ActiveModel {
+            id: Set(correction_id),
+            status: Set(CorrectionStatus::Rejected),
+            handled_at: Set(Some(Utc::now().into())),
+            ..Default::default()
+        }
+        .update(self.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn create_changeset(
+        &self,
+        corrections: &[(String, i32)],
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        let changeset = correction_changeset::ActiveModel {
+            id: NotSet,
+            created_at: NotSet,
+        }
+        .insert(self.conn())
+        .await?;
+
+        let items = corrections.iter().zip(0..).map(
+            |((temp_id, correction_id), position)| {
+                correction_changeset_item::ActiveModel {
+                    correction_id: Set(*correction_id),
+                    changeset_id: Set(changeset.id),
+                    temp_id: Set(temp_id.clone()),
+                    position: Set(position),
+                }
+            },
+        );
+
+        correction_changeset_item::Entity::insert_many(items)
+            .exec_without_returning(self.conn())
+            .await?;
+
+        Ok(changeset.id)
+    }
+
+    // TODO: Move to service
+    async fn approve<Ctx>(
+        &self,
+        correction_id: i32,
+        CorrectionApprover(approver): CorrectionApprover,
+        context: Ctx,
+    ) -> Result<(), infra::Error>
+    where
+        Ctx: ApproveCorrectionContext,
+    {
+        let correction = entity::correction::Entity::find_by_id(correction_id)
+            .one(self.conn())
+            .await?
+            .ok_or(DbErr::Custom(
+                "Correction not found, but it should not happen".to_owned(),
+            ))?;
+
+        entity::correction_user::Entity::insert(
+            entity::correction_user::ActiveModel {
+                user_id: Set(approver.id),
+                correction_id: Set(correction_id),
+                user_type: Set(CorrectionUserType::Approver),
+            },
+        )
+        .exec(self.conn())
+        .await?;
+
+        let mut correction_active_model = correction.into_active_model();
+        correction_active_model.status = Set(CorrectionStatus::Approved);
+        correction_active_model.handled_at = Set(Some(Utc::now().into()));
+
+        let correction = correction_active_model.update(self.conn()).await?;
+
+        match correction.entity_type {
+            EntityType::Artist => {
+                context.artist_repo().apply_update(correction).await?;
+            }
+            EntityType::Label => {
+                context.label_repo().apply_update(correction).await?;
+            }
+            EntityType::Release => {
+                context.release_repo().apply_update(correction).await?;
+            }
+            EntityType::Song => {
+                context.song_repo().apply_update(correction).await?;
+            }
+            EntityType::Tag => {
+                context.tag_repo().apply_update(correction).await?;
+            }
+            EntityType::Event => {
+                context.event_repo().apply_update(correction).await?;
+            }
+            EntityType::SongLyrics => {
+                context.song_lyrics_repo().apply_update(correction).await?;
+            }
+            EntityType::CreditRole => {
+                context.credit_role_repo().apply_update(correction).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn insert_correction_user(
+    correction_id: i32,
+    user_id: i32,
+    user_type: CorrectionUserType,
+    db: &impl sea_orm::ConnectionTrait,
+) -> Result<(), DbErr> {
+    correction_user::Entity::insert(correction_user::ActiveModel {
+        correction_id: Set(correction_id),
+        user_id: Set(user_id),
+        user_type: Set(user_type),
+    })
+    .on_conflict_do_nothing()
+    .exec_without_returning(db)
+    .await?;
+
+    Ok(())
+}
+
+async fn insert_sources(
+    correction_id: i32,
+    history_id: i32,
+    sources: Vec<CorrectionSource>,
+    db: &impl sea_orm::ConnectionTrait,
+) -> Result<(), DbErr> {
+    if sources.is_empty() {
+        return Ok(());
+    }
+
+    correction_revision_source::Entity::insert_many(sources.into_iter().map(
+        |source| correction_revision_source::ActiveModel {
+            id: NotSet,
+            correction_id: Set(correction_id),
+            entity_history_id: Set(history_id),
+            url: Set(source.url.into()),
+            r#type: Set(source.r#type),
+            note: Set(source.note),
+        },
+    ))
+    .exec_without_returning(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Loads the sources of the revisions of a correction
+async fn with_sources(
+    correction_id: i32,
+    revisions: Vec<correction_revision::Model>,
+    db: &impl sea_orm::ConnectionTrait,
+) -> Result<Vec<CorrectionRevision>, Box<dyn std::error::Error + Send + Sync>> {
+    let mut sources = correction_revision_source::Entity::find()
+        .filter(
+            correction_revision_source::Column::CorrectionId.eq(correction_id),
+        )
+        .filter(
+            correction_revision_source::Column::EntityHistoryId.is_in(
+                revisions.iter().map(|revision| revision.entity_history_id),
+            ),
+        )
+        .order_by_asc(correction_revision_source::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            Ok((
+                model.entity_history_id,
+                CorrectionSource {
+                    url: model.url.parse()?,
+                    r#type: model.r#type,
+                    note: model.note,
+                },
+            ))
+        })
+        .collect::<Result<Vec<_>, url::ParseError>>()?
+        .into_iter()
+        .into_group_map();
+
+    Ok(revisions
+        .into_iter()
+        .map(|model| CorrectionRevision {
+            sources: sources
+                .remove(&model.entity_history_id)
+                .unwrap_or_default(),
+            entity_history_id: model.entity_history_id,
+            author_id: model.author_id,
+            description: model.description,
+            base_history_id: model.base_history_id,
+        })
+        .collect())
+}
+
+impl From<entity::correction::Model> for Correction {
+    fn from(model: entity::correction::Model) -> Self {
+        Self {
+            id: model.id,
+            status: model.status,
+            r#type: model.r#type,
+            entity_id: model.entity_id,
+            entity_type: model.entity_type,
+            created_at: model.created_at,
+            handled_at: model.handled_at,
+        }
+    }
+}
+
+impl ApproveCorrectionContext for SeaOrmTxRepo {
+    type ArtistRepo = Self;
+    type ReleaseRepo = Self;
+    type SongRepo = Self;
+    type LabelRepo = Self;
+    type EventRepo = Self;
+    type TagRepo = Self;
+    type SongLyricsRepo = Self;
+    type CreditRoleRepo = Self;
+
+    fn artist_repo(self) -> Self::ArtistRepo {
+        self
+    }
+
+    fn release_repo(self) -> Self::ReleaseRepo {
+        self
+    }
+
+    fn song_repo(self) -> Self::SongRepo {
+        self
+    }
+
+    fn label_repo(self) -> Self::LabelRepo {
+        self
+    }
+
+    fn event_repo(self) -> Self::EventRepo {
+        self
+    }
+
+    fn tag_repo(self) -> Self::TagRepo {
+        self
+    }
+
+    fn song_lyrics_repo(self) -> Self::SongLyricsRepo {
+        self
+    }
+
+    fn credit_role_repo(self) -> Self::CreditRoleRepo {
+        self
+    }
+}
+
+macro_rules! impl_correction_entity_repo {
+    ($($model:ty => $repo:path),* $(,)?) => {$(
+        impl CorrectionEntityRepo<$model> for SeaOrmTxRepo {
+            async fn create(&self, data: &$model) -> Result<i32, infra::Error> {
+                Ok(<Self as $repo>::create(self, data).await?)
+            }
+
+            async fn create_history(
+                &self,
+                data: &$model,
+            ) -> Result<i32, infra::Error> {
+                Ok(<Self as $repo>::create_history(self, data).await?)
+            }
+        }
+    )*};
+}
+
+impl_correction_entity_repo! {
+    NewArtist => crate::domain::artist::TxRepo,
+    NewLabel => crate::domain::label::TxRepo,
+    NewRelease => crate::domain::release::TxRepo,
+    NewSong => crate::domain::song::TxRepo,
+    NewTag => crate::domain::tag::TxRepo,
+    NewEvent => crate::domain::event::TxRepo,
+    NewSongLyrics => crate::domain::song_lyrics::TxRepo,
+    NewCreditRole => crate::domain::credit_role::TxRepo,
+}
@@ -0,0 +1,93 @@
+use entity::entity_protection::{ActiveModel, Column, Entity, Model};
+use entity::enums::EntityType;
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter};
+
+use crate::domain::correction::{
+    EntityProtection, NewEntityProtection, ProtectionRepo,
+};
+use crate::domain::repository::Connection;
+
+impl<T> ProtectionRepo for T
+where
+    T: Connection,
+    T::Conn: sea_orm::ConnectionTrait,
+{
+    async fn find_protection(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<
+        Option<EntityProtection>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        Ok(Entity::find_by_id((entity_type, entity_id))
+            .filter(Condition::any().add(Column::ExpiresAt.is_null()).add(
+                Expr::col(Column::ExpiresAt).gt(Expr::current_timestamp()),
+            ))
+            .one(self.conn())
+            .await?
+            .map(Into::into))
+    }
+
+    async fn set_protection(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+        created_by: i32,
+        protection: NewEntityProtection,
+    ) -> Result<EntityProtection, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let model = Entity::insert(ActiveModel {
+            entity_type: Set(entity_type),
+            entity_id: Set(entity_id),
+            level: Set(protection.level),
+            reason: Set(protection.reason),
+            expires_at: Set(protection.expires_at),
+            created_by: Set(created_by),
+            created_at: NotSet,
+        })
+        .on_conflict(
+            OnConflict::columns([Column::EntityType, Column::EntityId])
+                .update_columns([
+                    Column::Level,
+                    Column::Reason,
+                    Column::ExpiresAt,
+                    Column::CreatedBy,
+                ])
+                .value(Column::CreatedAt, Expr::current_timestamp())
+                .to_owned(),
+        )
+        .exec_with_returning(self.conn())
+        .await?;
+
+        Ok(model.into())
+    }
+
+    async fn delete_protection(
+        &self,
+        entity_type: EntityType,
+        entity_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Entity::delete_by_id((entity_type, entity_id))
+            .exec(self.conn())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl From<Model> for EntityProtection {
+    fn from(model: Model) -> Self {
+        Self {
+            entity_type: model.entity_type,
+            entity_id: model.entity_id,
+            level: model.level,
+            reason: model.reason,
+            expires_at: model.expires_at,
+            created_by: model.created_by,
+            created_at: model.created_at,
+        }
+    }
+}
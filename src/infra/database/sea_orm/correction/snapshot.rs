@@ -0,0 +1,130 @@
+use entity::enums::EntityType;
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, FromQueryResult, Statement};
+
+/// History table of an entity type, and its child tables referencing it by
+/// `history_id`, keyed by the field name in the snapshot
+struct HistoryTables {
+    table: &'static str,
+    children: &'static [(&'static str, &'static str)],
+}
+
+const fn history_tables(entity_type: EntityType) -> HistoryTables {
+    match entity_type {
+        EntityType::Artist => HistoryTables {
+            table: "artist_history",
+            children: &[
+                ("aliases", "artist_alias_history"),
+                ("links", "artist_link_history"),
+                ("localized_names", "artist_localized_name_history"),
+                ("memberships", "artist_membership_history"),
+            ],
+        },
+        EntityType::Label => HistoryTables {
+            table: "label_history",
+            children: &[
+                ("founders", "label_founder_history"),
+                ("localized_names", "label_localized_name_history"),
+            ],
+        },
+        EntityType::Release => HistoryTables {
+            table: "release_history",
+            children: &[
+                ("artists", "release_artist_history"),
+                ("catalog_numbers", "release_catalog_number_history"),
+                ("credits", "release_credit_history"),
+                ("discs", "release_disc_history"),
+                ("events", "release_event_history"),
+                ("localized_titles", "release_localized_title_history"),
+                ("tracks", "release_track_history"),
+            ],
+        },
+        EntityType::Song => HistoryTables {
+            table: "song_history",
+            children: &[
+                ("artists", "song_artist_history"),
+                ("credits", "song_credit_history"),
+                ("languages", "song_language_history"),
+                ("localized_titles", "song_localized_title_history"),
+            ],
+        },
+        EntityType::Tag => HistoryTables {
+            table: "tag_history",
+            children: &[
+                ("alternative_names", "tag_alternative_name_history"),
+                ("relations", "tag_relation_history"),
+            ],
+        },
+        EntityType::Event => HistoryTables {
+            table: "event_history",
+            children: &[(
+                "alternative_names",
+                "event_alternative_name_history",
+            )],
+        },
+        EntityType::SongLyrics => HistoryTables {
+            table: "song_lyrics_history",
+            children: &[],
+        },
+        EntityType::CreditRole => HistoryTables {
+            table: "credit_role_history",
+            children: &[("super_roles", "credit_role_inheritance_history")],
+        },
+    }
+}
+
+// Ids of history rows differ between snapshots, so they are left out.
+// Child rows are sorted to make snapshots comparable
+const HISTORY_ID_COLUMNS: &str = "ARRAY['id', 'history_id', 'disc_history_id']";
+
+fn snapshot_sql(tables: &HistoryTables) -> String {
+    let children = tables
+        .children
+        .iter()
+        .map(|(field, table)| {
+            format!(
+                r#"'{field}', (
+    SELECT COALESCE(jsonb_agg(c."row" ORDER BY c."row"), '[]'::jsonb)
+    FROM (
+      SELECT to_jsonb(c) - {HISTORY_ID_COLUMNS} AS "row"
+      FROM "{table}" c
+      WHERE c."history_id" = h."id"
+    ) c
+  )"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n  ");
+
+    format!(
+        r#"
+SELECT (to_jsonb(h) - 'id') || jsonb_build_object(
+  {children}
+) AS "snapshot"
+FROM "{table}" h
+WHERE h."id" = $1
+"#,
+        table = tables.table
+    )
+}
+
+#[derive(FromQueryResult)]
+struct SnapshotRow {
+    snapshot: serde_json::Value,
+}
+
+/// The history record of an entity and its direct relations as a json object
+pub(super) async fn find_history_snapshot(
+    entity_type: EntityType,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<serde_json::Value>, DbErr> {
+    let row = SnapshotRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        snapshot_sql(&history_tables(entity_type)),
+        [history_id.into()],
+    ))
+    .one(db)
+    .await?;
+
+    Ok(row.map(|row| row.snapshot))
+}
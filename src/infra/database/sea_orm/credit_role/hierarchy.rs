@@ -0,0 +1,82 @@
+use entity::{credit_role, credit_role_inheritance};
+use sea_orm::{
+    ConnectionTrait, DbBackend, DbErr, EntityTrait, FromQueryResult,
+    QueryOrder, Statement,
+};
+use sea_query::{BinOper, Expr, IntoColumnRef, SimpleExpr};
+
+use crate::domain::credit_role::{
+    CreditRoleRef, CreditRoleTree, CreditRoleTreeEdge,
+};
+
+// `UNION` discards duplicate rows, so the recursion also terminates
+// if the inheritance somehow already contains a loop
+const SUB_ROLE_IDS: &str = r#"
+WITH RECURSIVE "sub_role" AS (
+    SELECT "role_id" AS "id"
+    FROM "credit_role_inheritance"
+    WHERE "super_id" = $1
+  UNION
+    SELECT i."role_id"
+    FROM "credit_role_inheritance" i
+    INNER JOIN "sub_role" s ON i."super_id" = s."id"
+)
+SELECT "id" FROM "sub_role"
+"#;
+
+#[derive(FromQueryResult)]
+struct IdRow {
+    id: i32,
+}
+
+pub(super) async fn find_sub_role_ids(
+    id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<i32>, DbErr> {
+    let rows = IdRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        SUB_ROLE_IDS,
+        [id.into()],
+    ))
+    .all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(|row| row.id).collect())
+}
+
+/// Matches `column` against the role and all of its sub roles
+pub(crate) fn role_or_sub_role<C>(column: C, role_id: i32) -> SimpleExpr
+where
+    C: IntoColumnRef + Clone,
+{
+    // `IN` already wraps the right hand side in parentheses
+    let sub_role_ids = Expr::cust_with_values(SUB_ROLE_IDS, [role_id]);
+
+    Expr::col(column.clone())
+        .eq(role_id)
+        .or(Expr::col(column).binary(BinOper::In, sub_role_ids))
+}
+
+pub(super) async fn find_tree(
+    db: &impl ConnectionTrait,
+) -> Result<CreditRoleTree, DbErr> {
+    let roles = credit_role::Entity::find()
+        .order_by_asc(credit_role::Column::Id)
+        .all(db)
+        .await?;
+
+    let edges = credit_role_inheritance::Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| CreditRoleTreeEdge {
+            role_id: model.role_id,
+            super_id: model.super_id,
+        })
+        .collect();
+
+    Ok(CreditRoleTree {
+        roles: roles.into_iter().map(CreditRoleRef::from).collect(),
+        edges,
+    })
+}
@@ -13,10 +13,15 @@ use sea_query::{ExprTrait, Func};
 use crate::domain::credit_role::repo::{
     CommonFilter, FindManyFilter, QueryKind,
 };
-use crate::domain::credit_role::{NewCreditRole, Repo, TxRepo};
+use crate::domain::credit_role::{
+    CreditRole, CreditRoleTree, NewCreditRole, Repo, TxRepo,
+};
 use crate::domain::repository::Connection;
 use crate::infra::database::sea_orm::SeaOrmTxRepo;
 
+mod hierarchy;
+pub(crate) use hierarchy::role_or_sub_role;
+
 impl<T> Repo for T
 where
     T: Connection,
@@ -58,6 +63,40 @@ where
 
         Ok(roles.into_iter().map(Into::into).collect())
     }
+
+    async fn find_sub_role_ids(
+        &self,
+        id: i32,
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(hierarchy::find_sub_role_ids(id, self.conn()).await?)
+    }
+
+    async fn find_tree(
+        &self,
+    ) -> Result<CreditRoleTree, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(hierarchy::find_tree(self.conn()).await?)
+    }
+
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<CreditRole>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let history = credit_role_history::Entity::find_by_id(history_id)
+            .one(self.conn())
+            .await?;
+
+        Ok(history.map(|history| {
+            credit_role::Model {
+                id,
+                name: history.name,
+                short_description: history.short_description,
+                description: history.description,
+            }
+            .into()
+        }))
+    }
 }
 
 impl TxRepo for SeaOrmTxRepo {
@@ -53,6 +53,14 @@ where
             );
         find_many_impl(selector, self.conn()).await.boxed()
     }
+
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Event>, Box<dyn std::error::Error + Send + Sync>> {
+        find_history_impl(id, history_id, self.conn()).await.boxed()
+    }
 }
 
 async fn find_many_impl(
@@ -64,7 +72,58 @@ async fn find_many_impl(
     let alt_names =
         events.load_many(event_alternative_name::Entity, db).await?;
 
-    let res = izip!(events, alt_names)
+    Ok(conv_to_domain_models(events, alt_names))
+}
+
+/// The event as recorded in the history, with the id of the event
+async fn find_history_impl(
+    id: i32,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<Event>, DbErr> {
+    let Some(history) = event_history::Entity::find_by_id(history_id)
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let alt_names = event_alternative_name_history::Entity::find()
+        .filter(
+            event_alternative_name_history::Column::HistoryId.eq(history_id),
+        )
+        .order_by_asc(event_alternative_name_history::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|name| event_alternative_name::Model {
+            id: name.id,
+            event_id: id,
+            name: name.name,
+            r#type: name.r#type,
+            language_id: name.language_id,
+        })
+        .collect();
+
+    let event = event::Model {
+        id,
+        name: history.name,
+        short_description: history.short_description,
+        description: history.description,
+        start_date: history.start_date,
+        start_date_precision: history.start_date_precision,
+        end_date: history.end_date,
+        end_date_precision: history.end_date_precision,
+    };
+
+    Ok(conv_to_domain_models(vec![event], vec![alt_names]).pop())
+}
+
+fn conv_to_domain_models(
+    events: Vec<event::Model>,
+    alt_names: Vec<Vec<event_alternative_name::Model>>,
+) -> Vec<Event> {
+    izip!(events, alt_names)
         .map(|(event, alt_name)| Event {
             id: event.id,
             name: event.name,
@@ -92,9 +151,7 @@ async fn find_many_impl(
                 })
                 .collect_vec(),
         })
-        .collect_vec();
-
-    Ok(res)
+        .collect_vec()
 }
 
 impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
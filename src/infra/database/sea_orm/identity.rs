@@ -0,0 +1,87 @@
+use entity::user_identity::{ActiveModel, Column, Entity, Model};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use crate::domain::identity::{ExternalIdentity, Repo, UserIdentity};
+use crate::domain::repository::Connection;
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn find_identity_owner(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Entity::find()
+            .filter(Column::Provider.eq(provider))
+            .filter(Column::Subject.eq(subject))
+            .one(self.conn())
+            .await?
+            .map(|model| model.user_id))
+    }
+
+    async fn find_identities(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<UserIdentity>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        Ok(Entity::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_asc(Column::Id)
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn link_identity(
+        &self,
+        user_id: i32,
+        identity: ExternalIdentity,
+    ) -> Result<UserIdentity, Box<dyn std::error::Error + Send + Sync>> {
+        let model = Entity::insert(ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id),
+            provider: Set(identity.provider),
+            subject: Set(identity.subject),
+            email: Set(identity.email),
+            created_at: NotSet,
+        })
+        .exec_with_returning(self.conn())
+        .await?;
+
+        Ok(model.into())
+    }
+
+    async fn unlink_identity(
+        &self,
+        user_id: i32,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let res = Entity::delete_many()
+            .filter(Column::Id.eq(id))
+            .filter(Column::UserId.eq(user_id))
+            .exec(self.conn())
+            .await?;
+
+        Ok(res.rows_affected > 0)
+    }
+}
+
+impl From<Model> for UserIdentity {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            provider: model.provider,
+            subject: model.subject,
+            email: model.email,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -3,7 +3,7 @@ use libfp::FunctorExt;
 use sea_orm::ActiveValue::{NotSet, Set};
 use sea_orm::{
     ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    IntoActiveValue, QueryFilter,
+    IntoActiveValue, PaginatorTrait, QueryFilter,
 };
 use snafu::ResultExt;
 
@@ -40,6 +40,32 @@ where
             .map(FunctorExt::fmap_into)
             .boxed()
     }
+
+    async fn is_in_use(
+        &self,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let users = entity::user::Entity::find()
+            .filter(
+                entity::user::Column::AvatarId
+                    .eq(id)
+                    .or(entity::user::Column::ProfileBannerId.eq(id)),
+            )
+            .count(self.conn())
+            .await?;
+
+        let artists = entity::artist_image::Entity::find()
+            .filter(entity::artist_image::Column::ImageId.eq(id))
+            .count(self.conn())
+            .await?;
+
+        let releases = entity::release_image::Entity::find()
+            .filter(entity::release_image::Column::ImageId.eq(id))
+            .count(self.conn())
+            .await?;
+
+        Ok(users + artists + releases > 0)
+    }
 }
 
 async fn save_impl(
@@ -14,6 +14,18 @@ where
     T: Connection,
     T::Conn: ConnectionTrait,
 {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<ImageQueue>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        db::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map(|model| model.map(Into::into))
+            .boxed()
+    }
+
     async fn create(
         &self,
         model: NewImageQueue,
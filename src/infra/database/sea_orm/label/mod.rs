@@ -54,6 +54,14 @@ where
             );
         find_many_impl(select, self.conn()).await.boxed()
     }
+
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Label>, Box<dyn std::error::Error + Send + Sync>> {
+        find_history_impl(id, history_id, self.conn()).await.boxed()
+    }
 }
 
 async fn find_many_impl(
@@ -67,6 +75,73 @@ async fn find_many_impl(
     let localized_names =
         labels.load_many(label_localized_name::Entity, db).await?;
 
+    conv_to_domain_models(labels, founders, localized_names, db).await
+}
+
+/// The label as recorded in the history, with the id of the label
+async fn find_history_impl(
+    id: i32,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<Label>, DbErr> {
+    let Some(history) = label_history::Entity::find_by_id(history_id)
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let (founders, localized_names) = tokio::try_join!(
+        label_founder_history::Entity::find()
+            .filter(label_founder_history::Column::HistoryId.eq(history_id))
+            .all(db),
+        label_localized_name_history::Entity::find()
+            .filter(
+                label_localized_name_history::Column::HistoryId.eq(history_id)
+            )
+            .all(db),
+    )?;
+
+    let label = label::Model {
+        id,
+        name: history.name,
+        founded_date: history.founded_date,
+        founded_date_precision: history.founded_date_precision,
+        dissolved_date: history.dissolved_date,
+        dissolved_date_precision: history.dissolved_date_precision,
+    };
+    let founders = founders
+        .into_iter()
+        .map(|founder| label_founder::Model {
+            label_id: id,
+            artist_id: founder.artist_id,
+        })
+        .collect();
+    let localized_names = localized_names
+        .into_iter()
+        .map(|name| label_localized_name::Model {
+            label_id: id,
+            language_id: name.language_id,
+            name: name.name,
+        })
+        .collect();
+
+    Ok(conv_to_domain_models(
+        vec![label],
+        vec![founders],
+        vec![localized_names],
+        db,
+    )
+    .await?
+    .pop())
+}
+
+async fn conv_to_domain_models(
+    labels: Vec<label::Model>,
+    founders: Vec<Vec<label_founder::Model>>,
+    localized_names: Vec<Vec<label_localized_name::Model>>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<Label>, DbErr> {
     let langs = language::Entity::find()
         .filter(
             language::Column::Id.is_in(
@@ -7,40 +7,59 @@ use sea_orm::{
 use snafu::ResultExt;
 
 use crate::domain::error::InfraWhatever;
+use crate::domain::live::{self, LiveEvent};
 use crate::domain::model::auth::UserRoleEnum;
 use crate::domain::repository::{Connection, Transaction, TransactionManager};
+use crate::infra::live::{LivePublisher, Outbox};
 
+mod account;
+mod admin;
 mod artist;
 mod artist_image_queue;
 mod artist_release;
 mod cache;
+mod contribution;
 mod correction;
 mod credit_role;
 pub mod enum_table;
 mod event;
 pub mod ext;
+mod identity;
 mod image;
 mod image_queue;
 mod label;
+mod notification;
 mod release;
 mod release_image;
 mod release_image_queue;
+mod report;
 mod song;
 mod song_lyrics;
 mod tag;
+mod two_factor;
 mod user;
 pub mod utils;
+mod webhook;
 
 /// `DatabaseConnection` is a wrapper of Arc<InnerPool>.
 /// So don't wrap this type in Arc.
 #[derive(Clone)]
 pub struct SeaOrmRepository {
     pub conn: sea_orm::DatabaseConnection,
+    live: Option<LivePublisher>,
 }
 
 impl SeaOrmRepository {
     pub const fn new(conn: sea_orm::DatabaseConnection) -> Self {
-        Self { conn }
+        Self { conn, live: None }
+    }
+
+    /// Publishes the live events of the transactions once they commit
+    pub fn with_live(self, publisher: LivePublisher) -> Self {
+        Self {
+            live: Some(publisher),
+            ..self
+        }
     }
 }
 
@@ -63,7 +82,10 @@ impl TransactionManager for SeaOrmRepository {
     > {
         let tx = self.conn.begin().await?;
         let tx = Arc::new(tx);
-        Ok(Self::TransactionRepository { tx })
+        Ok(Self::TransactionRepository {
+            tx,
+            outbox: Outbox::new(self.live.clone()),
+        })
     }
 
     async fn run<F, T>(
@@ -86,6 +108,7 @@ impl TransactionManager for SeaOrmRepository {
 pub struct SeaOrmTxRepo {
     // Make this can be cloned
     tx: Arc<sea_orm::DatabaseTransaction>,
+    outbox: Outbox,
 }
 
 impl Connection for SeaOrmTxRepo {
@@ -108,6 +131,7 @@ impl TransactionManager for SeaOrmTxRepo {
         let save_point = self.tx.begin().await.boxed()?;
         Ok(Self {
             tx: Arc::new(save_point),
+            outbox: self.outbox.savepoint(),
         })
     }
 
@@ -147,10 +171,18 @@ impl Transaction for SeaOrmTxRepo {
             .await
             .boxed()?;
 
+        self.outbox.flush();
+
         Ok(())
     }
 }
 
+impl live::TxRepo for SeaOrmTxRepo {
+    fn publish(&self, event: LiveEvent) {
+        self.outbox.push(event);
+    }
+}
+
 // TODO: move to elsewhere
 impl TryFrom<user_role::Model> for UserRoleEnum {
     type Error = DbErr;
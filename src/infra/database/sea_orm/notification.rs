@@ -0,0 +1,229 @@
+use entity::{notification, notification_preference};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait,
+};
+use serde_json::json;
+
+use crate::domain::notification::{
+    NewNotification, Notification, NotificationFilter, NotificationPreference,
+    Repo,
+};
+use crate::domain::repository::{Connection, Cursor, Paginated};
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn create_notifications(
+        &self,
+        notifications: Vec<NewNotification>,
+    ) -> Result<Vec<Notification>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let disabled = notification_preference::Entity::find()
+            .filter(
+                notification_preference::Column::UserId
+                    .is_in(notifications.iter().map(|n| n.user_id)),
+            )
+            .filter(notification_preference::Column::Enabled.eq(false))
+            .all(self.conn())
+            .await?;
+
+        let models = notifications
+            .into_iter()
+            .filter(|n| {
+                !disabled.iter().any(|preference| {
+                    preference.user_id == n.user_id
+                        && preference.kind == n.event.kind()
+                })
+            })
+            .map(|n| {
+                // The kind has its own column, only the fields are kept
+                let mut value = serde_json::to_value(n.event)?;
+
+                Ok(notification::ActiveModel {
+                    id: NotSet,
+                    user_id: Set(n.user_id),
+                    kind: Set(n.event.kind()),
+                    data: Set(value["data"].take()),
+                    created_at: NotSet,
+                    read_at: NotSet,
+                })
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+
+        if models.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Ok(notification::Entity::insert_many(models)
+            .exec_with_returning_many(self.conn())
+            .await?
+            .iter()
+            .map(into_notification)
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn find_notifications(
+        &self,
+        user_id: i32,
+        filter: NotificationFilter,
+        pagination: Cursor,
+    ) -> Result<Paginated<Notification>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        // Get one more to check if there are more
+        let mut models = notification::Entity::find()
+            .filter(notification::Column::UserId.eq(user_id))
+            .apply_if(filter.unread.then_some(()), |select, ()| {
+                select.filter(notification::Column::ReadAt.is_null())
+            })
+            .apply_if((pagination.at > 0).then_some(pagination.at), |s, at| {
+                s.filter(notification::Column::Id.lt(at))
+            })
+            .order_by_desc(notification::Column::Id)
+            .limit(u64::from(pagination.limit) + 1)
+            .all(self.conn())
+            .await?;
+
+        let has_more = models.len() > pagination.limit.into();
+
+        if has_more {
+            models.pop();
+        }
+
+        let next_cursor =
+            models.last().map(|model| model.id).filter(|_| has_more);
+
+        Ok(Paginated {
+            items: models
+                .iter()
+                .map(into_notification)
+                .collect::<Result<_, _>>()?,
+            next_cursor,
+        })
+    }
+
+    async fn count_unread(
+        &self,
+        user_id: i32,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(notification::Entity::find()
+            .filter(notification::Column::UserId.eq(user_id))
+            .filter(notification::Column::ReadAt.is_null())
+            .count(self.conn())
+            .await?)
+    }
+
+    async fn mark_read(
+        &self,
+        user_id: i32,
+        id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(model) = notification::Entity::find_by_id(id)
+            .filter(notification::Column::UserId.eq(user_id))
+            .one(self.conn())
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        if model.read_at.is_none() {
+            notification::Entity::update_many()
+                .col_expr(
+                    notification::Column::ReadAt,
+                    Expr::current_timestamp().into(),
+                )
+                .filter(notification::Column::Id.eq(id))
+                .exec(self.conn())
+                .await?;
+        }
+
+        Ok(true)
+    }
+
+    async fn mark_all_read(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        notification::Entity::update_many()
+            .col_expr(
+                notification::Column::ReadAt,
+                Expr::current_timestamp().into(),
+            )
+            .filter(notification::Column::UserId.eq(user_id))
+            .filter(notification::Column::ReadAt.is_null())
+            .exec(self.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_preferences(
+        &self,
+        user_id: i32,
+    ) -> Result<
+        Vec<NotificationPreference>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        Ok(notification_preference::Entity::find()
+            .filter(notification_preference::Column::UserId.eq(user_id))
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(|model| NotificationPreference {
+                kind: model.kind,
+                enabled: model.enabled,
+            })
+            .collect())
+    }
+
+    async fn save_preferences(
+        &self,
+        user_id: i32,
+        preferences: Vec<NotificationPreference>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if preferences.is_empty() {
+            return Ok(());
+        }
+
+        notification_preference::Entity::insert_many(
+            preferences.into_iter().map(|preference| {
+                notification_preference::ActiveModel {
+                    user_id: Set(user_id),
+                    kind: Set(preference.kind),
+                    enabled: Set(preference.enabled),
+                }
+            }),
+        )
+        .on_conflict(
+            OnConflict::columns([
+                notification_preference::Column::UserId,
+                notification_preference::Column::Kind,
+            ])
+            .update_column(notification_preference::Column::Enabled)
+            .to_owned(),
+        )
+        .exec_without_returning(self.conn())
+        .await?;
+
+        Ok(())
+    }
+}
+
+fn into_notification(
+    model: &notification::Model,
+) -> Result<Notification, serde_json::Error> {
+    Ok(Notification {
+        id: model.id,
+        user_id: model.user_id,
+        event: serde_json::from_value(json!({
+            "kind": model.kind,
+            "data": model.data,
+        }))?,
+        created_at: model.created_at,
+        read_at: model.read_at,
+    })
+}
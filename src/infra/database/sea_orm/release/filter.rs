@@ -1,9 +1,12 @@
-use entity::release;
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use entity::{release, release_credit};
+use sea_orm::{
+    ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
 use sea_query::extension::postgres::PgBinOper;
-use sea_query::{ExprTrait, Func};
+use sea_query::{Cond, ExprTrait, Func};
 
 use crate::domain::release::repo::Filter;
+use crate::infra::database::sea_orm::credit_role::role_or_sub_role;
 impl Filter {
     pub(super) fn into_select(self) -> sea_orm::Select<release::Entity> {
         match self {
@@ -25,3 +28,26 @@ impl Filter {
         }
     }
 }
+
+/// Releases crediting the role or any of its sub roles
+pub(super) fn credit_select(
+    role_id: i32,
+    artist_id: Option<i32>,
+) -> sea_orm::Select<release::Entity> {
+    let credited = release_credit::Entity::find()
+        .select_only()
+        .column(release_credit::Column::ReleaseId)
+        .filter(
+            Cond::all()
+                .add(role_or_sub_role(
+                    (release_credit::Entity, release_credit::Column::RoleId),
+                    role_id,
+                ))
+                .add_option(artist_id.map(|artist_id| {
+                    release_credit::Column::ArtistId.eq(artist_id)
+                })),
+        )
+        .into_query();
+
+    release::Entity::find().filter(release::Column::Id.in_subquery(credited))
+}
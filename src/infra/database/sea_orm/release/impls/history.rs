@@ -0,0 +1,204 @@
+use entity::{
+    release_artist_history, release_catalog_number_history,
+    release_credit_history, release_disc_history, release_event_history,
+    release_localized_title_history, release_track_artist_history,
+    release_track_history,
+};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use super::loader::BaseEntities;
+
+/// Loads the rows of a release history as the models of the release tables,
+/// so they can be converted like the current release. Discs and tracks keep
+/// the ids of their history rows
+pub(super) async fn load_base_entities(
+    release_id: i32,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<(BaseEntities, Vec<Vec<entity::artist::Model>>), DbErr> {
+    let (
+        artists,
+        catalog_numbers,
+        localized_titles,
+        discs,
+        tracks,
+        credits,
+        events,
+    ) = tokio::try_join!(
+        release_artist_history::Entity::find()
+            .filter(release_artist_history::Column::HistoryId.eq(history_id))
+            .all(db),
+        release_catalog_number_history::Entity::find()
+            .filter(
+                release_catalog_number_history::Column::HistoryId
+                    .eq(history_id)
+            )
+            .order_by_asc(release_catalog_number_history::Column::Id)
+            .all(db),
+        release_localized_title_history::Entity::find()
+            .filter(
+                release_localized_title_history::Column::HistoryId
+                    .eq(history_id)
+            )
+            .all(db),
+        release_disc_history::Entity::find()
+            .filter(release_disc_history::Column::HistoryId.eq(history_id))
+            .order_by_asc(release_disc_history::Column::Id)
+            .all(db),
+        release_track_history::Entity::find()
+            .filter(release_track_history::Column::HistoryId.eq(history_id))
+            .order_by_asc(release_track_history::Column::Id)
+            .all(db),
+        release_credit_history::Entity::find()
+            .filter(release_credit_history::Column::HistoryId.eq(history_id))
+            .order_by_asc(release_credit_history::Column::Id)
+            .all(db),
+        release_event_history::Entity::find()
+            .filter(release_event_history::Column::HistoryId.eq(history_id))
+            .all(db),
+    )?;
+
+    let track_artists = release_track_artist_history::Entity::find()
+        .filter(
+            release_track_artist_history::Column::TrackHistoryId
+                .is_in(tracks.iter().map(|track| track.id)),
+        )
+        .all(db)
+        .await?;
+
+    let (artist_models, event_models) = tokio::try_join!(
+        entity::artist::Entity::find()
+            .filter(
+                entity::artist::Column::Id.is_in(
+                    artists
+                        .iter()
+                        .map(|artist| artist.artist_id)
+                        .chain(
+                            track_artists.iter().map(|artist| artist.artist_id)
+                        )
+                        .unique()
+                )
+            )
+            .all(db),
+        entity::event::Entity::find()
+            .filter(
+                entity::event::Column::Id
+                    .is_in(events.iter().map(|event| event.event_id))
+            )
+            .all(db),
+    )?;
+
+    let find_artists = |ids: &mut dyn Iterator<Item = i32>| {
+        ids.filter_map(|id| artist_models.iter().find(|a| a.id == id).cloned())
+            .collect_vec()
+    };
+
+    let artists_per_track = tracks
+        .iter()
+        .map(|track| {
+            find_artists(
+                &mut track_artists
+                    .iter()
+                    .filter(|artist| artist.track_history_id == track.id)
+                    .map(|artist| artist.artist_id),
+            )
+        })
+        .collect_vec();
+
+    let rows = HistoryRows {
+        catalog_numbers,
+        localized_titles,
+        discs,
+        tracks,
+        credits,
+    };
+    let artists =
+        find_artists(&mut artists.iter().map(|artist| artist.artist_id));
+
+    Ok((
+        rows.into_base_entities(release_id, artists, event_models),
+        artists_per_track,
+    ))
+}
+
+struct HistoryRows {
+    catalog_numbers: Vec<release_catalog_number_history::Model>,
+    localized_titles: Vec<release_localized_title_history::Model>,
+    discs: Vec<release_disc_history::Model>,
+    tracks: Vec<release_track_history::Model>,
+    credits: Vec<release_credit_history::Model>,
+}
+
+impl HistoryRows {
+    fn into_base_entities(
+        self,
+        release_id: i32,
+        artists: Vec<entity::artist::Model>,
+        events: Vec<entity::event::Model>,
+    ) -> BaseEntities {
+        BaseEntities {
+            artists: vec![artists],
+            catalog_numbers: vec![
+                self.catalog_numbers
+                    .into_iter()
+                    .map(|cn| entity::release_catalog_number::Model {
+                        id: cn.id,
+                        release_id,
+                        catalog_number: cn.catalog_number,
+                        label_id: cn.label_id,
+                    })
+                    .collect(),
+            ],
+            localized_titles: vec![
+                self.localized_titles
+                    .into_iter()
+                    .map(|lt| entity::release_localized_title::Model {
+                        release_id,
+                        language_id: lt.language_id,
+                        title: lt.title,
+                    })
+                    .collect(),
+            ],
+            discs: vec![
+                self.discs
+                    .into_iter()
+                    .map(|disc| entity::release_disc::Model {
+                        id: disc.id,
+                        release_id,
+                        name: disc.name,
+                    })
+                    .collect(),
+            ],
+            tracks: vec![
+                self.tracks
+                    .into_iter()
+                    .map(|track| entity::release_track::Model {
+                        id: track.id,
+                        release_id,
+                        song_id: track.song_id,
+                        track_number: track.track_number,
+                        display_title: track.display_title,
+                        duration: track.duration,
+                        disc_id: track.disc_history_id,
+                    })
+                    .collect(),
+            ],
+            credits: vec![
+                self.credits
+                    .into_iter()
+                    .map(|credit| entity::release_credit::Model {
+                        id: credit.id,
+                        artist_id: credit.artist_id,
+                        release_id,
+                        role_id: credit.role_id,
+                        on: credit.on,
+                    })
+                    .collect(),
+            ],
+            events: vec![events],
+        }
+    }
+}
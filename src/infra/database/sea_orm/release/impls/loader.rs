@@ -29,14 +29,15 @@ pub(super) struct RelatedEntities {
     pub(super) labels: Vec<entity::label::Model>,
 }
 
-struct BaseEntities {
-    artists: Vec<Vec<entity::artist::Model>>,
-    catalog_numbers: Vec<Vec<entity::release_catalog_number::Model>>,
-    localized_titles: Vec<Vec<entity::release_localized_title::Model>>,
-    discs: Vec<Vec<entity::release_disc::Model>>,
-    tracks: Vec<Vec<entity::release_track::Model>>,
-    credits: Vec<Vec<entity::release_credit::Model>>,
-    events: Vec<Vec<entity::event::Model>>,
+pub(super) struct BaseEntities {
+    pub(super) artists: Vec<Vec<entity::artist::Model>>,
+    pub(super) catalog_numbers: Vec<Vec<entity::release_catalog_number::Model>>,
+    pub(super) localized_titles:
+        Vec<Vec<entity::release_localized_title::Model>>,
+    pub(super) discs: Vec<Vec<entity::release_disc::Model>>,
+    pub(super) tracks: Vec<Vec<entity::release_track::Model>>,
+    pub(super) credits: Vec<Vec<entity::release_credit::Model>>,
+    pub(super) events: Vec<Vec<entity::event::Model>>,
 }
 
 struct TrackDetails {
@@ -49,6 +50,52 @@ impl RelatedEntities {
     pub(super) async fn load(
         releases: &[release::Model],
         db: &impl ConnectionTrait,
+    ) -> Result<Self, DbErr> {
+        let base = Self::load_base_entities(releases, db).await?;
+
+        // TODO: remove clone when loader improvement pr merged
+        let artists_per_track = base
+            .tracks
+            .iter()
+            .flatten()
+            .cloned()
+            .collect_vec()
+            .load_many_to_many(
+                entity::artist::Entity,
+                entity::release_track_artist::Entity,
+                db,
+            )
+            .await?;
+
+        Self::load_details(releases, base, artists_per_track, db).await
+    }
+
+    /// Loads the related entities of a release as recorded in one of its
+    /// histories. Referenced entities, eg. artists of credits, are loaded as
+    /// they are now
+    pub(super) async fn load_history(
+        release: &release::Model,
+        history_id: i32,
+        db: &impl ConnectionTrait,
+    ) -> Result<Self, DbErr> {
+        let (base, artists_per_track) =
+            super::history::load_base_entities(release.id, history_id, db)
+                .await?;
+
+        Self::load_details(
+            std::slice::from_ref(release),
+            base,
+            artists_per_track,
+            db,
+        )
+        .await
+    }
+
+    async fn load_details(
+        releases: &[release::Model],
+        base: BaseEntities,
+        artists_per_track: Vec<Vec<entity::artist::Model>>,
+        db: &impl ConnectionTrait,
     ) -> Result<Self, DbErr> {
         let BaseEntities {
             artists,
@@ -58,14 +105,14 @@ impl RelatedEntities {
             tracks,
             credits,
             events,
-        } = Self::load_base_entities(releases, db).await?;
+        } = base;
         let (credit_artists, credit_roles) =
             Self::load_credit_details(&credits, &artists, db).await?;
         let TrackDetails {
             songs: track_songs,
             artists: track_artists,
             artist_ids: track_artist_ids,
-        } = Self::load_track_details(&tracks, db).await?;
+        } = Self::load_track_details(&tracks, artists_per_track, db).await?;
         let cover_arts = Self::load_cover_arts(releases, db).await?;
         let languages = LANGUAGE_CACHE.get_or_init(db).await?;
 
@@ -189,27 +236,17 @@ impl RelatedEntities {
 
     async fn load_track_details(
         tracks: &[Vec<entity::release_track::Model>],
+        artists_per_track: Vec<Vec<entity::artist::Model>>,
         db: &impl ConnectionTrait,
     ) -> Result<TrackDetails, DbErr> {
-        // TODO: remove clone when loader improvement pr merged
-        let flatten_tracks = tracks.iter().flatten().cloned().collect_vec();
-
         let songs = {
-            let song_ids = flatten_tracks.iter().map(|t| t.song_id).unique();
+            let song_ids = tracks.iter().flatten().map(|t| t.song_id).unique();
             entity::song::Entity::find()
                 .filter(entity::song::Column::Id.is_in(song_ids))
                 .all(db)
                 .await?
         };
 
-        let artists_per_track = flatten_tracks
-            .load_many_to_many(
-                entity::artist::Entity,
-                entity::release_track_artist::Entity,
-                db,
-            )
-            .await?;
-
         let artists = artists_per_track
             .iter()
             .flatten()
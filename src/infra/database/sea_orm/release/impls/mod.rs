@@ -1,5 +1,5 @@
 use entity::release;
-use sea_orm::{ConnectionTrait, DbErr};
+use sea_orm::{ConnectionTrait, DbErr, EntityTrait};
 
 use self::conv::conv_to_domain_model;
 use self::loader::RelatedEntities;
@@ -11,6 +11,7 @@ mod conv;
 mod credit;
 mod disc;
 mod event;
+mod history;
 mod loader;
 mod localized_title;
 mod track;
@@ -37,3 +38,33 @@ pub async fn find_many_impl(
 
     Ok(result)
 }
+
+/// The release as recorded in the history, with the id of the release
+pub async fn find_history_impl(
+    id: i32,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<Release>, DbErr> {
+    let Some(history) = entity::release_history::Entity::find_by_id(history_id)
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let release = release::Model {
+        id,
+        title: history.title,
+        release_type: history.release_type,
+        release_date: history.release_date,
+        release_date_precision: history.release_date_precision,
+        recording_date_start: history.recording_date_start,
+        recording_date_start_precision: history.recording_date_start_precision,
+        recording_date_end: history.recording_date_end,
+        recording_date_end_precision: history.recording_date_end_precision,
+    };
+    let related =
+        RelatedEntities::load_history(&release, history_id, db).await?;
+
+    Ok(Some(conv_to_domain_model(&release, &related, 0)))
+}
@@ -1,14 +1,15 @@
 use entity::release;
 use sea_orm::{
     ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
-    QuerySelect,
+    QueryOrder, QuerySelect,
 };
 use snafu::ResultExt;
 
+use super::filter::credit_select;
 use super::impls::*;
 use crate::domain::release::model::Release;
 use crate::domain::release::repo::{Filter, Repo};
-use crate::domain::repository::Connection;
+use crate::domain::repository::{Connection, Cursor, Paginated};
 
 impl<T> Repo for T
 where
@@ -35,6 +36,40 @@ where
             .boxed()
     }
 
+    async fn find_by_credit(
+        &self,
+        role_id: i32,
+        artist_id: Option<i32>,
+        pagination: Cursor,
+    ) -> Result<Paginated<Release>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        // Get one more to check if there are more
+        let mut releases = find_many_impl(
+            credit_select(role_id, artist_id)
+                .filter(release::Column::Id.gt(pagination.at))
+                .order_by_asc(release::Column::Id)
+                .limit(u64::from(pagination.limit) + 1),
+            self.conn(),
+        )
+        .await?;
+
+        let has_more = releases.len() > pagination.limit.into();
+
+        if has_more {
+            releases.pop();
+        }
+
+        let next_cursor = releases
+            .last()
+            .map(|release| release.id)
+            .filter(|_| has_more);
+
+        Ok(Paginated {
+            items: releases,
+            next_cursor,
+        })
+    }
+
     async fn exist(
         &self,
         id: i32,
@@ -48,4 +83,12 @@ where
             .boxed()?
             > 0)
     }
+
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Release>, Box<dyn std::error::Error + Send + Sync>> {
+        find_history_impl(id, history_id, self.conn()).await.boxed()
+    }
 }
@@ -0,0 +1,230 @@
+use chrono::{DateTime, FixedOffset};
+use entity::enums::{ReportState, ReportTarget};
+use entity::report::{ActiveModel, Column, Entity, Model};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbBackend, DbErr, EntityTrait,
+    PaginatorTrait, PrimaryKeyTrait, QueryFilter, QueryTrait, Statement,
+};
+
+use crate::domain::report::{
+    NewReport, Repo, Report, ReportFilter, ReportResolution,
+};
+use crate::domain::repository::{Connection, Cursor, Paginated};
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn create(
+        &self,
+        reporter_id: i32,
+        report: NewReport,
+    ) -> Result<Report, Box<dyn std::error::Error + Send + Sync>> {
+        let model = Entity::insert(ActiveModel {
+            id: NotSet,
+            reporter_id: Set(reporter_id),
+            target: Set(report.target),
+            target_id: Set(report.target_id),
+            reason: Set(report.reason),
+            description: Set(report.description),
+            state: Set(ReportState::Open),
+            assignee_id: Set(None),
+            action: Set(None),
+            action_id: Set(None),
+            note: Set(None),
+            created_at: NotSet,
+            handled_at: Set(None),
+        })
+        .exec_with_returning(self.conn())
+        .await?;
+
+        Ok(model.into())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<Report>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Entity::find_by_id(id)
+            .one(self.conn())
+            .await?
+            .map(Into::into))
+    }
+
+    async fn find_many(
+        &self,
+        filter: ReportFilter,
+        pagination: Cursor,
+    ) -> Result<Paginated<Report>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let states = filter.state.map_or_else(
+            || vec![ReportState::Open, ReportState::Claimed],
+            |state| vec![state],
+        );
+
+        let select = Entity::find()
+            .filter(Column::State.is_in(states))
+            .apply_if(filter.target, |select, target| {
+                select.filter(Column::Target.eq(target))
+            });
+
+        let mut cursor = select.cursor_by(Column::Id);
+
+        cursor.after(pagination.at);
+
+        // Get one more to check if there are more
+        let mut reports = cursor
+            .first((pagination.limit + 1).into())
+            .all(self.conn())
+            .await?;
+
+        let has_more = reports.len() > pagination.limit.into();
+
+        if has_more {
+            reports.pop();
+        }
+
+        let next_cursor =
+            reports.last().map(|report| report.id).filter(|_| has_more);
+
+        Ok(Paginated {
+            items: reports.into_iter().map(Into::into).collect(),
+            next_cursor,
+        })
+    }
+
+    async fn count_by_reporter_since(
+        &self,
+        reporter_id: i32,
+        since: DateTime<FixedOffset>,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Entity::find()
+            .filter(Column::ReporterId.eq(reporter_id))
+            .filter(Column::CreatedAt.gte(since))
+            .count(self.conn())
+            .await?)
+    }
+
+    async fn lock_reporter(
+        &self,
+        reporter_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.conn()
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT pg_advisory_xact_lock(hashtext('report'), $1)",
+                [reporter_id.into()],
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn target_exists(
+        &self,
+        target: ReportTarget,
+        target_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        use entity::{
+            artist, comment, credit_role, event, label, release, song,
+            song_lyrics, tag, user,
+        };
+
+        let db = self.conn();
+
+        let exists = match target {
+            ReportTarget::Artist => {
+                exists::<artist::Entity>(target_id, db).await
+            }
+            ReportTarget::Label => exists::<label::Entity>(target_id, db).await,
+            ReportTarget::Release => {
+                exists::<release::Entity>(target_id, db).await
+            }
+            ReportTarget::Song => exists::<song::Entity>(target_id, db).await,
+            ReportTarget::Tag => exists::<tag::Entity>(target_id, db).await,
+            ReportTarget::Event => exists::<event::Entity>(target_id, db).await,
+            ReportTarget::SongLyrics => {
+                exists::<song_lyrics::Entity>(target_id, db).await
+            }
+            ReportTarget::CreditRole => {
+                exists::<credit_role::Entity>(target_id, db).await
+            }
+            ReportTarget::Comment => {
+                exists::<comment::Entity>(target_id, db).await
+            }
+            ReportTarget::User => exists::<user::Entity>(target_id, db).await,
+        }?;
+
+        Ok(exists)
+    }
+
+    async fn claim(
+        &self,
+        id: i32,
+        assignee_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let res = Entity::update_many()
+            .col_expr(Column::State, Expr::value(ReportState::Claimed))
+            .col_expr(Column::AssigneeId, Expr::value(assignee_id))
+            .filter(Column::Id.eq(id))
+            .filter(Column::State.eq(ReportState::Open))
+            .exec(self.conn())
+            .await?;
+
+        Ok(res.rows_affected > 0)
+    }
+
+    async fn close(
+        &self,
+        id: i32,
+        assignee_id: i32,
+        resolution: ReportResolution,
+    ) -> Result<Report, Box<dyn std::error::Error + Send + Sync>> {
+        let model = Entity::update(ActiveModel {
+            id: Set(id),
+            state: Set(resolution.state),
+            assignee_id: Set(Some(assignee_id)),
+            action: Set(resolution.action),
+            action_id: Set(resolution.action_id),
+            note: Set(resolution.note),
+            handled_at: Set(Some(chrono::Utc::now().into())),
+            ..Default::default()
+        })
+        .exec(self.conn())
+        .await?;
+
+        Ok(model.into())
+    }
+}
+
+async fn exists<E>(id: i32, db: &impl ConnectionTrait) -> Result<bool, DbErr>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+    <E::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>,
+{
+    Ok(E::find_by_id(id).count(db).await? > 0)
+}
+
+impl From<Model> for Report {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            reporter_id: model.reporter_id,
+            target: model.target,
+            target_id: model.target_id,
+            reason: model.reason,
+            description: model.description,
+            state: model.state,
+            assignee_id: model.assignee_id,
+            action: model.action,
+            action_id: model.action_id,
+            note: model.note,
+            created_at: model.created_at,
+            handled_at: model.handled_at,
+        }
+    }
+}
@@ -70,9 +70,16 @@ where
             );
         find_many_impl(select, self.conn()).await.boxed()
     }
+
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Song>, Box<dyn std::error::Error + Send + Sync>> {
+        find_history_impl(id, history_id, self.conn()).await.boxed()
+    }
 }
 
-#[expect(clippy::too_many_lines)]
 async fn find_many_impl(
     select: sea_orm::Select<song::Entity>,
     db: &impl ConnectionTrait,
@@ -82,14 +89,7 @@ async fn find_many_impl(
         return Ok(vec![]);
     }
 
-    let (
-        song_artists_list,
-        song_credits_list,
-        song_langs_list,
-        localized_titles_list,
-        song_releases_list,
-        song_lyrics_list,
-    ) = tokio::try_join!(
+    let (artists, credits, languages, localized_titles, releases, lyrics) = tokio::try_join!(
         songs.load_many_to_many(artist::Entity, song_artist::Entity, db),
         songs.load_many(song_credit::Entity, db),
         songs.load_many(song_language::Entity::find(), db),
@@ -102,6 +102,129 @@ async fn find_many_impl(
         songs.load_many(song_lyrics::Entity, db),
     )?;
 
+    conv_to_domain_models(
+        songs,
+        RelatedEntities {
+            artists,
+            credits,
+            languages,
+            localized_titles,
+            releases,
+            lyrics,
+        },
+        db,
+    )
+    .await
+}
+
+/// The song as recorded in the history, with the id of the song. Releases
+/// and lyrics aren't part of the history of songs, they are loaded as they
+/// are now
+async fn find_history_impl(
+    id: i32,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<Song>, DbErr> {
+    let Some(history) =
+        song_history::Entity::find_by_id(history_id).one(db).await?
+    else {
+        return Ok(None);
+    };
+
+    let songs = vec![song::Model {
+        id,
+        title: history.title,
+    }];
+
+    let (artists, credits, languages, localized_titles, releases, lyrics) = tokio::try_join!(
+        artist::Entity::find()
+            .inner_join(song_artist_history::Entity)
+            .filter(song_artist_history::Column::HistoryId.eq(history_id))
+            .all(db),
+        song_credit_history::Entity::find()
+            .filter(song_credit_history::Column::HistoryId.eq(history_id))
+            .order_by_asc(song_credit_history::Column::Id)
+            .all(db),
+        song_language_history::Entity::find()
+            .filter(song_language_history::Column::HistoryId.eq(history_id))
+            .all(db),
+        song_localized_title_history::Entity::find()
+            .filter(
+                song_localized_title_history::Column::HistoryId.eq(history_id)
+            )
+            .order_by_asc(song_localized_title_history::Column::Id)
+            .all(db),
+        songs.load_many_to_many(
+            entity::release::Entity,
+            entity::release_track::Entity,
+            db,
+        ),
+        songs.load_many(song_lyrics::Entity, db),
+    )?;
+
+    let related = RelatedEntities {
+        artists: vec![artists],
+        credits: vec![
+            credits
+                .into_iter()
+                .map(|credit| song_credit::Model {
+                    song_id: id,
+                    artist_id: credit.artist_id,
+                    role_id: credit.role_id,
+                    id: credit.id,
+                })
+                .collect(),
+        ],
+        languages: vec![
+            languages
+                .into_iter()
+                .map(|language| song_language::Model {
+                    song_id: id,
+                    language_id: language.language_id,
+                })
+                .collect(),
+        ],
+        localized_titles: vec![
+            localized_titles
+                .into_iter()
+                .map(|title| song_localized_title::Model {
+                    id: title.id,
+                    song_id: id,
+                    language_id: title.language_id,
+                    title: title.title,
+                })
+                .collect(),
+        ],
+        releases,
+        lyrics,
+    };
+
+    Ok(conv_to_domain_models(songs, related, db).await?.pop())
+}
+
+struct RelatedEntities {
+    artists: Vec<Vec<artist::Model>>,
+    credits: Vec<Vec<song_credit::Model>>,
+    languages: Vec<Vec<song_language::Model>>,
+    localized_titles: Vec<Vec<song_localized_title::Model>>,
+    releases: Vec<Vec<entity::release::Model>>,
+    lyrics: Vec<Vec<song_lyrics::Model>>,
+}
+
+async fn conv_to_domain_models(
+    songs: Vec<song::Model>,
+    related: RelatedEntities,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<Song>, DbErr> {
+    let RelatedEntities {
+        artists: song_artists_list,
+        credits: song_credits_list,
+        languages: song_langs_list,
+        localized_titles: localized_titles_list,
+        releases: song_releases_list,
+        lyrics: song_lyrics_list,
+    } = related;
+
     let (song_credits_artists_ids, song_credits_roles_ids): (Vec<_>, Vec<_>) =
         song_credits_list
             .iter()
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use entity::sea_orm_active_enums::{TagRelationType, TagType};
+use entity::{tag, tag_relation};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbBackend, DbErr, EntityTrait,
+    FromQueryResult, QueryFilter, QueryOrder, Statement,
+};
+
+use crate::domain::tag::model::{TagRef, TagTree, TagTreeEdge};
+
+#[derive(Clone, Copy)]
+pub(super) enum Direction {
+    Ancestors,
+    Descendants,
+}
+
+impl Direction {
+    // `UNION` discards duplicate rows, so the recursion also terminates
+    // if the relations somehow already contain a loop
+    const fn sql(self) -> &'static str {
+        match self {
+            Self::Ancestors => {
+                r#"
+WITH RECURSIVE "edge" AS (
+    SELECT "tag_id", "related_tag_id", "type"
+    FROM "tag_relation"
+    WHERE "tag_id" = $1
+  UNION
+    SELECT r."tag_id", r."related_tag_id", r."type"
+    FROM "tag_relation" r
+    INNER JOIN "edge" e ON r."tag_id" = e."related_tag_id"
+)
+SELECT "tag_id", "related_tag_id", "type"::text AS "relation_type"
+FROM "edge"
+"#
+            }
+            Self::Descendants => {
+                r#"
+WITH RECURSIVE "edge" AS (
+    SELECT "tag_id", "related_tag_id", "type"
+    FROM "tag_relation"
+    WHERE "related_tag_id" = $1
+  UNION
+    SELECT r."tag_id", r."related_tag_id", r."type"
+    FROM "tag_relation" r
+    INNER JOIN "edge" e ON r."related_tag_id" = e."tag_id"
+)
+SELECT "tag_id", "related_tag_id", "type"::text AS "relation_type"
+FROM "edge"
+"#
+            }
+        }
+    }
+}
+
+#[derive(FromQueryResult)]
+struct EdgeRow {
+    tag_id: i32,
+    related_tag_id: i32,
+    relation_type: TagRelationType,
+}
+
+impl From<EdgeRow> for TagTreeEdge {
+    fn from(row: EdgeRow) -> Self {
+        Self {
+            tag_id: row.tag_id,
+            parent_id: row.related_tag_id,
+            r#type: row.relation_type,
+        }
+    }
+}
+
+impl From<tag_relation::Model> for TagTreeEdge {
+    fn from(model: tag_relation::Model) -> Self {
+        Self {
+            tag_id: model.tag_id,
+            parent_id: model.related_tag_id,
+            r#type: model.r#type,
+        }
+    }
+}
+
+impl From<tag::Model> for TagRef {
+    fn from(model: tag::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            r#type: model.r#type,
+        }
+    }
+}
+
+pub(super) async fn find_hierarchy(
+    id: i32,
+    direction: Direction,
+    db: &impl ConnectionTrait,
+) -> Result<Option<TagTree>, DbErr> {
+    let edges = EdgeRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        direction.sql(),
+        [id.into()],
+    ))
+    .all(db)
+    .await?
+    .into_iter()
+    .map(TagTreeEdge::from)
+    .collect::<Vec<_>>();
+
+    let ids = edges
+        .iter()
+        .flat_map(|edge| [edge.tag_id, edge.parent_id])
+        .chain([id])
+        .collect::<HashSet<_>>();
+
+    let tags = tag::Entity::find()
+        .filter(tag::Column::Id.is_in(ids))
+        .order_by_asc(tag::Column::Id)
+        .all(db)
+        .await?;
+
+    if !tags.iter().any(|tag| tag.id == id) {
+        return Ok(None);
+    }
+
+    Ok(Some(TagTree {
+        tags: tags.into_iter().map(Into::into).collect(),
+        edges,
+    }))
+}
+
+pub(super) async fn find_tree(
+    r#type: TagType,
+    db: &impl ConnectionTrait,
+) -> Result<TagTree, DbErr> {
+    let tags = tag::Entity::find()
+        .filter(tag::Column::Type.eq(r#type))
+        .order_by_asc(tag::Column::Id)
+        .all(db)
+        .await?;
+
+    let ids = tags.iter().map(|tag| tag.id).collect::<Vec<_>>();
+
+    let edges = if ids.is_empty() {
+        vec![]
+    } else {
+        tag_relation::Entity::find()
+            .filter(tag_relation::Column::TagId.is_in(ids.iter().copied()))
+            .filter(tag_relation::Column::RelatedTagId.is_in(ids))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    };
+
+    Ok(TagTree {
+        tags: tags.into_iter().map(Into::into).collect(),
+        edges,
+    })
+}
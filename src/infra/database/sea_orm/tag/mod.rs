@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use entity::sea_orm_active_enums::TagType;
 use entity::tag::Column::Name;
 use entity::{
     tag, tag_alternative_name, tag_alternative_name_history, tag_history,
@@ -17,11 +18,13 @@ use snafu::ResultExt;
 
 use crate::domain::repository::Connection;
 use crate::domain::tag::model::{
-    AlternativeName, NewTag, NewTagRelation, Tag, TagRef, TagRelation,
+    AlternativeName, NewTag, NewTagRelation, Tag, TagRef, TagRelation, TagTree,
 };
 use crate::domain::tag::{Repo, TxRepo};
 
+mod hierarchy;
 mod impls;
+use hierarchy::Direction;
 use impls::*;
 
 impl<T> Repo for T
@@ -57,6 +60,39 @@ where
             );
         find_many_impl(select, self.conn()).await.boxed()
     }
+
+    async fn find_history(
+        &self,
+        id: i32,
+        history_id: i32,
+    ) -> Result<Option<Tag>, Box<dyn std::error::Error + Send + Sync>> {
+        find_history_impl(id, history_id, self.conn()).await.boxed()
+    }
+
+    async fn find_ancestors(
+        &self,
+        id: i32,
+    ) -> Result<Option<TagTree>, Box<dyn std::error::Error + Send + Sync>> {
+        hierarchy::find_hierarchy(id, Direction::Ancestors, self.conn())
+            .await
+            .boxed()
+    }
+
+    async fn find_descendants(
+        &self,
+        id: i32,
+    ) -> Result<Option<TagTree>, Box<dyn std::error::Error + Send + Sync>> {
+        hierarchy::find_hierarchy(id, Direction::Descendants, self.conn())
+            .await
+            .boxed()
+    }
+
+    async fn find_tree(
+        &self,
+        r#type: TagType,
+    ) -> Result<TagTree, Box<dyn std::error::Error + Send + Sync>> {
+        hierarchy::find_tree(r#type, self.conn()).await.boxed()
+    }
 }
 
 async fn find_many_impl(
@@ -68,7 +104,72 @@ async fn find_many_impl(
     let alt_names = tags.load_many(tag_alternative_name::Entity, db).await?;
     let tag_relations = load_tag_relations(&tags, db).await?;
 
-    Ok(izip!(tags, alt_names, tag_relations)
+    Ok(conv_to_domain_models(tags, alt_names, tag_relations))
+}
+
+/// The tag as recorded in the history, with the id of the tag
+async fn find_history_impl(
+    id: i32,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Option<Tag>, DbErr> {
+    let Some(history) =
+        tag_history::Entity::find_by_id(history_id).one(db).await?
+    else {
+        return Ok(None);
+    };
+
+    let (alt_names, relations) = tokio::try_join!(
+        tag_alternative_name_history::Entity::find()
+            .filter(
+                tag_alternative_name_history::Column::HistoryId.eq(history_id)
+            )
+            .order_by_asc(tag_alternative_name_history::Column::Id)
+            .all(db),
+        tag_relation_history::Entity::find()
+            .filter(tag_relation_history::Column::HistoryId.eq(history_id))
+            .all(db),
+    )?;
+
+    let tag = tag::Model {
+        id,
+        name: history.name,
+        r#type: history.r#type,
+        short_description: history.short_description,
+        description: history.description,
+    };
+    let alt_names = alt_names
+        .into_iter()
+        .map(|name| tag_alternative_name::Model {
+            id: name.id,
+            tag_id: id,
+            name: name.name,
+            is_origin_language: name.is_origin_language,
+            language_id: name.language_id,
+        })
+        .collect();
+    let relations = relations
+        .into_iter()
+        .map(|relation| tag_relation::Model {
+            tag_id: id,
+            related_tag_id: relation.related_tag_id,
+            r#type: relation.r#type,
+        })
+        .collect();
+
+    let tags = vec![tag];
+    let tag_relations =
+        resolve_tag_relations(&tags, vec![relations], db).await?;
+
+    Ok(conv_to_domain_models(tags, vec![alt_names], tag_relations).pop())
+}
+
+fn conv_to_domain_models(
+    tags: Vec<tag::Model>,
+    alt_names: Vec<Vec<tag_alternative_name::Model>>,
+    tag_relations: Vec<Vec<TagRelation>>,
+) -> Vec<Tag> {
+    izip!(tags, alt_names, tag_relations)
         .map(|(tag, alt_names, relations)| Tag {
             id: tag.id,
             name: tag.name,
@@ -84,7 +185,7 @@ async fn find_many_impl(
                 .collect(),
             relations,
         })
-        .collect())
+        .collect()
 }
 
 async fn load_tag_relations(
@@ -112,6 +213,15 @@ async fn load_tag_relations(
         .map(|tag| grouped_relations.remove(&tag.id).unwrap_or_default())
         .collect::<Vec<_>>();
 
+    resolve_tag_relations(tags, relation_models, db).await
+}
+
+/// Resolves the related tags of the relations of each tag
+async fn resolve_tag_relations(
+    tags: &[tag::Model],
+    relation_models: Vec<Vec<tag_relation::Model>>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<Vec<TagRelation>>, DbErr> {
     let tag_ids = tags.iter().map(|tag| tag.id).collect::<HashSet<_>>();
 
     let missing_related_tag_ids = relation_models
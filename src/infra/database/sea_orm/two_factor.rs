@@ -0,0 +1,172 @@
+use entity::{user_recovery_code, user_totp};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::{Condition, Expr, OnConflict};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+use crate::domain::repository::Connection;
+use crate::domain::two_factor::{Repo, TotpSecret, UserTotp};
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn find_totp(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<UserTotp>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        user_totp::Entity::find_by_id(user_id)
+            .one(self.conn())
+            .await?
+            .map(TryInto::try_into)
+            .transpose()
+    }
+
+    async fn save_pending_totp(
+        &self,
+        user_id: i32,
+        secret: &TotpSecret,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        user_totp::Entity::insert(user_totp::ActiveModel {
+            user_id: Set(user_id),
+            secret: Set(secret.to_base32()),
+            enabled_at: Set(None),
+            last_used_step: Set(None),
+            created_at: NotSet,
+        })
+        .on_conflict(
+            OnConflict::column(user_totp::Column::UserId)
+                .update_columns([
+                    user_totp::Column::Secret,
+                    user_totp::Column::EnabledAt,
+                    user_totp::Column::LastUsedStep,
+                ])
+                .value(user_totp::Column::CreatedAt, Expr::current_timestamp())
+                .to_owned(),
+        )
+        .exec(self.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn enable_totp(
+        &self,
+        user_id: i32,
+        step: u64,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        user_totp::Entity::update_many()
+            .col_expr(
+                user_totp::Column::EnabledAt,
+                Expr::current_timestamp().into(),
+            )
+            .col_expr(
+                user_totp::Column::LastUsedStep,
+                Expr::value(i64::try_from(step)?),
+            )
+            .filter(user_totp::Column::UserId.eq(user_id))
+            .exec(self.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn use_totp_step(
+        &self,
+        user_id: i32,
+        step: u64,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let step = i64::try_from(step)?;
+
+        let res = user_totp::Entity::update_many()
+            .col_expr(user_totp::Column::LastUsedStep, Expr::value(step))
+            .filter(user_totp::Column::UserId.eq(user_id))
+            .filter(
+                Condition::any()
+                    .add(user_totp::Column::LastUsedStep.is_null())
+                    .add(user_totp::Column::LastUsedStep.lt(step)),
+            )
+            .exec(self.conn())
+            .await?;
+
+        Ok(res.rows_affected > 0)
+    }
+
+    async fn delete_totp(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        user_recovery_code::Entity::delete_many()
+            .filter(user_recovery_code::Column::UserId.eq(user_id))
+            .exec(self.conn())
+            .await?;
+
+        user_totp::Entity::delete_by_id(user_id)
+            .exec(self.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: i32,
+        code_hashes: Vec<String>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        user_recovery_code::Entity::delete_many()
+            .filter(user_recovery_code::Column::UserId.eq(user_id))
+            .exec(self.conn())
+            .await?;
+
+        user_recovery_code::Entity::insert_many(code_hashes.into_iter().map(
+            |code_hash| user_recovery_code::ActiveModel {
+                id: NotSet,
+                user_id: Set(user_id),
+                code_hash: Set(code_hash),
+                used_at: Set(None),
+            },
+        ))
+        .on_empty_do_nothing()
+        .exec(self.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn use_recovery_code(
+        &self,
+        user_id: i32,
+        code_hash: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let res = user_recovery_code::Entity::update_many()
+            .col_expr(
+                user_recovery_code::Column::UsedAt,
+                Expr::current_timestamp().into(),
+            )
+            .filter(user_recovery_code::Column::UserId.eq(user_id))
+            .filter(user_recovery_code::Column::CodeHash.eq(code_hash))
+            .filter(user_recovery_code::Column::UsedAt.is_null())
+            .exec(self.conn())
+            .await?;
+
+        Ok(res.rows_affected > 0)
+    }
+}
+
+impl TryFrom<user_totp::Model> for UserTotp {
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn try_from(model: user_totp::Model) -> Result<Self, Self::Error> {
+        Ok(Self {
+            user_id: model.user_id,
+            secret: TotpSecret::from_base32(&model.secret)
+                .ok_or("Invalid TOTP secret")?,
+            enabled_at: model.enabled_at,
+            last_used_step: model
+                .last_used_step
+                .map(u64::try_from)
+                .transpose()?,
+        })
+    }
+}
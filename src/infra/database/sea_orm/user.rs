@@ -2,29 +2,36 @@ use std::path::PathBuf;
 
 use entity::relation::UserRelationExt;
 use entity::user::ActiveModel;
-use entity::user_following;
+use entity::{role_permission, user_following};
 use itertools::Itertools;
 use macros::FieldEnum;
 use sea_orm::ActiveValue::{NotSet, Set};
 use sea_orm::prelude::Expr;
 use sea_orm::sea_query::IntoCondition;
 use sea_orm::{
-    ColumnTrait, DbErr, EntityTrait, FromQueryResult, IntoActiveModel,
-    JoinType, PaginatorTrait, QueryFilter, QuerySelect, QueryTrait,
-    RelationTrait, TransactionTrait,
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult,
+    IntoActiveModel, JoinType, PaginatorTrait, QueryFilter, QuerySelect,
+    QueryTrait, RelationTrait, TransactionTrait,
 };
 use sea_orm_migration::prelude::Alias;
 
 use super::{SeaOrmRepository, SeaOrmTxRepo};
 use crate::domain;
-use crate::domain::model::auth::UserRoleEnum;
+use crate::domain::contribution::ContributorStats;
+use crate::domain::model::auth::{
+    PermissionGrant, Permissions, UserRole, UserRoleEnum,
+};
 use crate::domain::model::markdown::Markdown;
 use crate::domain::repository::Connection;
 use crate::domain::user::{
     NewUser, User, UserProfile, {self},
 };
 
-impl user::Repository for SeaOrmRepository {
+impl<T> user::Repository for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
     async fn find_by_id(
         &self,
         id: i32,
@@ -69,6 +76,8 @@ impl user::TxRepo for SeaOrmTxRepo {
         let mut user = User::from(model);
 
         user.roles = vec![UserRoleEnum::User.into()];
+        user.permissions =
+            permissions_of(&user.roles, &find_grants(&user.roles, &tx).await?);
 
         tx.commit().await?;
 
@@ -86,7 +95,7 @@ impl user::TxRepo for SeaOrmTxRepo {
             .await?;
 
         let roles = user_roles
-            .into_iter()
+            .iter()
             .map(|role| entity::user_role::ActiveModel {
                 user_id: Set(model.id),
                 role_id: Set(role.id),
@@ -98,13 +107,15 @@ impl user::TxRepo for SeaOrmTxRepo {
             .exec(tx)
             .await?;
 
-        let roles = entity::user_role::Entity::insert_many(roles)
-            .exec_with_returning_many(tx)
+        entity::user_role::Entity::insert_many(roles)
+            .exec_without_returning(tx)
             .await?;
 
         let mut user = User::from(model);
 
-        user.roles = roles.into_iter().map(TryInto::try_into).try_collect()?;
+        user.permissions =
+            permissions_of(&user_roles, &find_grants(&user_roles, tx).await?);
+        user.roles = user_roles;
 
         Ok(user)
     }
@@ -114,8 +125,8 @@ async fn find_many_impl(
     filter: impl IntoCondition,
     conn: &impl sea_orm::ConnectionTrait,
 ) -> Result<Vec<User>, DbErr> {
-    entity::user::Entity::find()
-        .find_with_related(entity::user_role::Entity)
+    let users = entity::user::Entity::find()
+        .find_with_related(entity::role::Entity)
         .filter(filter)
         .all(conn)
         .await?
@@ -123,10 +134,51 @@ async fn find_many_impl(
         .map(|(model, roles)| {
             let mut user = User::from(model);
 
-            user.roles =
-                roles.into_iter().map(TryInto::try_into).try_collect()?;
+            user.roles = roles.into_iter().map(Into::into).collect();
+
+            user
+        })
+        .collect_vec();
+
+    let roles = users
+        .iter()
+        .flat_map(|user| user.roles.iter().cloned())
+        .collect_vec();
+    let grants = find_grants(&roles, conn).await?;
+
+    Ok(users
+        .into_iter()
+        .map(|mut user| {
+            user.permissions = permissions_of(&user.roles, &grants);
+
+            user
+        })
+        .collect_vec())
+}
+
+async fn find_grants(
+    roles: &[UserRole],
+    conn: &impl sea_orm::ConnectionTrait,
+) -> Result<Vec<role_permission::Model>, DbErr> {
+    role_permission::Entity::find()
+        .filter(
+            role_permission::Column::RoleId
+                .is_in(roles.iter().map(|role| role.id).unique()),
+        )
+        .all(conn)
+        .await
+}
 
-            Ok(user)
+fn permissions_of(
+    roles: &[UserRole],
+    grants: &[role_permission::Model],
+) -> Permissions {
+    grants
+        .iter()
+        .filter(|grant| roles.iter().any(|role| role.id == grant.role_id))
+        .map(|grant| PermissionGrant {
+            permission: grant.permission,
+            entity_type: grant.entity_type,
         })
         .collect()
 }
@@ -141,6 +193,7 @@ impl From<entity::user::Model> for User {
             profile_banner_id: value.profile_banner_id,
             last_login: value.last_login,
             roles: vec![],
+            permissions: Permissions::default(),
             bio: value.bio.map(Markdown::new_unchecked),
         }
     }
@@ -214,12 +267,10 @@ impl user::ProfileRepository for SeaOrmRepository {
             }
         }
 
-        impl TryFrom<(UserProfileRaw, Vec<user_role::Model>)> for UserProfile {
-            type Error = DbErr;
-
-            fn try_from(
-                (profile, roles): (UserProfileRaw, Vec<user_role::Model>),
-            ) -> Result<Self, Self::Error> {
+        impl From<(UserProfileRaw, Vec<role::Model>)> for UserProfile {
+            fn from(
+                (profile, roles): (UserProfileRaw, Vec<role::Model>),
+            ) -> Self {
                 let avatar_url = if let Some(dir) = profile.avatar_url_dir
                     && let Some(filename) = profile.avatar_url_filename
                 {
@@ -246,18 +297,18 @@ impl user::ProfileRepository for SeaOrmRepository {
                     None
                 };
 
-                Ok(Self {
+                Self {
+                    id: profile.id,
                     name: profile.name,
                     last_login: profile.last_login,
                     avatar_url,
                     banner_url,
-                    roles: roles
-                        .into_iter()
-                        .map(TryInto::try_into)
-                        .try_collect()?,
+                    roles: roles.into_iter().map(Into::into).collect(),
                     is_following: None,
                     bio: profile.bio,
-                })
+                    // Filled in from the cache
+                    contributions: ContributorStats::default(),
+                }
             }
         }
 
@@ -304,13 +355,13 @@ impl user::ProfileRepository for SeaOrmRepository {
             return Ok(None);
         };
 
-        let user_roles = user_role::Entity::find()
-            .column(user_role::Column::RoleId)
+        let roles = role::Entity::find()
+            .inner_join(user_role::Entity)
             .filter(user_role::Column::UserId.eq(profile.id))
             .all(&self.conn)
             .await?;
 
-        Ok(Some((profile, user_roles).try_into()?))
+        Ok(Some((profile, roles).into()))
     }
 
     async fn with_following(
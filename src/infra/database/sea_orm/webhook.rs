@@ -0,0 +1,314 @@
+use chrono::TimeDelta;
+use entity::enums::{WebhookDeliveryStatus, WebhookEventKind};
+use entity::{user_suspension, webhook, webhook_delivery};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult,
+    QueryFilter, QueryOrder, QuerySelect, QueryTrait, Statement,
+};
+use serde_json::Value;
+
+use crate::domain::repository::{Connection, Cursor, Paginated};
+use crate::domain::webhook::model::{DeliveryAttempt, PendingDelivery};
+use crate::domain::webhook::{
+    NewWebhook, Repo, Webhook, WebhookDelivery, WebhookEvent,
+};
+
+/// Leases the due deliveries, the locked ones are being claimed by other
+/// workers
+const CLAIM_DUE: &str = r#"
+UPDATE "webhook_delivery" d
+SET "next_attempt_at" = NOW() + $2::float8 * INTERVAL '1 second'
+FROM "webhook" w
+WHERE w."id" = d."webhook_id" AND d."id" IN (
+    SELECT "id" FROM "webhook_delivery"
+    WHERE "status" = 'Pending' AND "next_attempt_at" <= NOW()
+    ORDER BY "next_attempt_at"
+    LIMIT $1
+    FOR UPDATE SKIP LOCKED
+)
+RETURNING d."id", d."event", d."payload", d."attempts", w."url", w."secret"
+"#;
+
+#[derive(FromQueryResult)]
+struct PendingDeliveryRow {
+    id: i32,
+    event: WebhookEventKind,
+    payload: Value,
+    attempts: i32,
+    url: String,
+    secret: String,
+}
+
+impl<T> Repo for T
+where
+    T: Connection,
+    T::Conn: ConnectionTrait,
+{
+    async fn create_webhook(
+        &self,
+        owner_id: i32,
+        secret: String,
+        webhook: NewWebhook,
+    ) -> Result<Webhook, Box<dyn std::error::Error + Send + Sync>> {
+        let model = webhook::Entity::insert(webhook::ActiveModel {
+            id: NotSet,
+            owner_id: Set(owner_id),
+            url: Set(webhook.url),
+            secret: Set(secret),
+            events: Set(serde_json::to_value(webhook.events)?),
+            entity_types: Set(serde_json::to_value(webhook.entity_types)?),
+            created_at: NotSet,
+        })
+        .exec_with_returning(self.conn())
+        .await?;
+
+        Ok(into_webhook(&model)?)
+    }
+
+    async fn find_webhook(
+        &self,
+        id: i32,
+    ) -> Result<Option<Webhook>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(webhook::Entity::find_by_id(id)
+            .one(self.conn())
+            .await?
+            .as_ref()
+            .map(into_webhook)
+            .transpose()?)
+    }
+
+    async fn find_webhooks(
+        &self,
+        owner_id: i32,
+    ) -> Result<Vec<Webhook>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(webhook::Entity::find()
+            .filter(webhook::Column::OwnerId.eq(owner_id))
+            .order_by_asc(webhook::Column::Id)
+            .all(self.conn())
+            .await?
+            .iter()
+            .map(into_webhook)
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn delete_webhook(
+        &self,
+        id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        webhook::Entity::delete_by_id(id).exec(self.conn()).await?;
+
+        Ok(())
+    }
+
+    async fn enqueue(
+        &self,
+        events: Vec<WebhookEvent>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        // Suspended users keep their webhooks, but don't receive events
+        let webhooks = webhook::Entity::find()
+            .filter(
+                webhook::Column::OwnerId.not_in_subquery(
+                    user_suspension::Entity::find()
+                        .select_only()
+                        .column(user_suspension::Column::UserId)
+                        .filter(user_suspension::Column::LiftedAt.is_null())
+                        .filter(
+                            Expr::col(user_suspension::Column::SuspendedUntil)
+                                .gt(Expr::current_timestamp()),
+                        )
+                        .into_query(),
+                ),
+            )
+            .all(self.conn())
+            .await?
+            .iter()
+            .map(into_webhook)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let models = events
+            .iter()
+            .flat_map(|event| {
+                webhooks
+                    .iter()
+                    .filter(|webhook| webhook.accepts(event))
+                    .map(|webhook| new_delivery(webhook.id, *event))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if models.is_empty() {
+            return Ok(());
+        }
+
+        webhook_delivery::Entity::insert_many(models)
+            .exec_without_returning(self.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn enqueue_for(
+        &self,
+        webhook_id: i32,
+        event: WebhookEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        webhook_delivery::Entity::insert(new_delivery(webhook_id, event)?)
+            .exec_without_returning(self.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_deliveries(
+        &self,
+        webhook_id: i32,
+        pagination: Cursor,
+    ) -> Result<
+        Paginated<WebhookDelivery>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        // Get one more to check if there are more
+        let mut models = webhook_delivery::Entity::find()
+            .filter(webhook_delivery::Column::WebhookId.eq(webhook_id))
+            .apply_if((pagination.at > 0).then_some(pagination.at), |s, at| {
+                s.filter(webhook_delivery::Column::Id.lt(at))
+            })
+            .order_by_desc(webhook_delivery::Column::Id)
+            .limit(u64::from(pagination.limit) + 1)
+            .all(self.conn())
+            .await?;
+
+        let has_more = models.len() > pagination.limit.into();
+
+        if has_more {
+            models.pop();
+        }
+
+        let next_cursor =
+            models.last().map(|model| model.id).filter(|_| has_more);
+
+        Ok(Paginated {
+            items: models
+                .into_iter()
+                .map(|model| WebhookDelivery {
+                    id: model.id,
+                    webhook_id: model.webhook_id,
+                    event: model.event,
+                    payload: model.payload,
+                    status: model.status,
+                    attempts: model.attempts,
+                    next_attempt_at: model.next_attempt_at,
+                    response_status: model.response_status,
+                    error: model.error,
+                    created_at: model.created_at,
+                    delivered_at: model.delivered_at,
+                })
+                .collect(),
+            next_cursor,
+        })
+    }
+
+    async fn claim_due(
+        &self,
+        limit: u64,
+        lease: TimeDelta,
+    ) -> Result<Vec<PendingDelivery>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let rows = PendingDeliveryRow::find_by_statement(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                CLAIM_DUE,
+                [i64::try_from(limit)?.into(), lease.as_seconds_f64().into()],
+            ),
+        )
+        .all(self.conn())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PendingDelivery {
+                id: row.id,
+                event: row.event,
+                payload: row.payload,
+                attempts: row.attempts,
+                url: row.url,
+                secret: row.secret,
+            })
+            .collect())
+    }
+
+    async fn record_attempt(
+        &self,
+        delivery_id: i32,
+        attempt: DeliveryAttempt,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        webhook_delivery::Entity::update_many()
+            .col_expr(
+                webhook_delivery::Column::Attempts,
+                Expr::col(webhook_delivery::Column::Attempts).add(1),
+            )
+            .col_expr(webhook_delivery::Column::Status, attempt.status.into())
+            .col_expr(
+                webhook_delivery::Column::ResponseStatus,
+                attempt.response_status.into(),
+            )
+            .col_expr(webhook_delivery::Column::Error, attempt.error.into())
+            .apply_if(attempt.next_attempt_at, |update, at| {
+                update.col_expr(
+                    webhook_delivery::Column::NextAttemptAt,
+                    at.into(),
+                )
+            })
+            .apply_if(
+                (attempt.status == WebhookDeliveryStatus::Succeeded)
+                    .then_some(()),
+                |update, ()| {
+                    update.col_expr(
+                        webhook_delivery::Column::DeliveredAt,
+                        Expr::current_timestamp().into(),
+                    )
+                },
+            )
+            .filter(webhook_delivery::Column::Id.eq(delivery_id))
+            .exec(self.conn())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn new_delivery(
+    webhook_id: i32,
+    event: WebhookEvent,
+) -> Result<webhook_delivery::ActiveModel, serde_json::Error> {
+    Ok(webhook_delivery::ActiveModel {
+        id: NotSet,
+        webhook_id: Set(webhook_id),
+        event: Set(event.kind()),
+        payload: Set(serde_json::to_value(event)?),
+        status: NotSet,
+        attempts: NotSet,
+        next_attempt_at: NotSet,
+        response_status: NotSet,
+        error: NotSet,
+        created_at: NotSet,
+        delivered_at: NotSet,
+    })
+}
+
+fn into_webhook(model: &webhook::Model) -> Result<Webhook, serde_json::Error> {
+    Ok(Webhook {
+        id: model.id,
+        owner_id: model.owner_id,
+        url: model.url.clone(),
+        secret: model.secret.clone(),
+        events: serde_json::from_value(model.events.clone())?,
+        entity_types: serde_json::from_value(model.entity_types.clone())?,
+        created_at: model.created_at,
+    })
+}
@@ -0,0 +1,156 @@
+use std::sync::{Arc, Mutex};
+
+use fred::prelude::{
+    Builder, ClientLike, EventInterface, Pool, PubsubInterface, ReconnectPolicy,
+};
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+use crate::domain::live::LiveEvent;
+
+const CHANNEL: &str = "live_event";
+
+/// Events waiting for delivery per instance, slow clients miss the older ones
+const CAPACITY: usize = 256;
+
+#[derive(Clone)]
+pub struct LivePublisher {
+    pool: Pool,
+}
+
+impl LivePublisher {
+    pub const fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn publish(&self, events: Vec<LiveEvent>) {
+        for event in events {
+            let json = match serde_json::to_string(&event) {
+                Ok(json) => json,
+                Err(e) => {
+                    tracing::error!("Failed to encode live event: {e}");
+                    continue;
+                }
+            };
+
+            if let Err(e) =
+                self.pool.next().publish::<i64, _, _>(CHANNEL, json).await
+            {
+                tracing::warn!("Failed to publish live event: {e}");
+            }
+        }
+    }
+}
+
+/// The events of a transaction, which are published when it commits
+#[derive(Clone, Default)]
+pub struct Outbox {
+    publisher: Option<LivePublisher>,
+    events: Arc<Mutex<Vec<LiveEvent>>>,
+    // Savepoints leave the events to the outer transaction
+    is_savepoint: bool,
+}
+
+impl Outbox {
+    pub fn new(publisher: Option<LivePublisher>) -> Self {
+        Self {
+            publisher,
+            ..Default::default()
+        }
+    }
+
+    pub fn savepoint(&self) -> Self {
+        Self {
+            is_savepoint: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn push(&self, event: LiveEvent) {
+        self.events
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(event);
+    }
+
+    pub fn flush(self) {
+        if self.is_savepoint {
+            return;
+        }
+
+        let events = std::mem::take(
+            &mut *self
+                .events
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+
+        if let Some(publisher) = self.publisher
+            && !events.is_empty()
+        {
+            tokio::spawn(async move { publisher.publish(events).await });
+        }
+    }
+}
+
+/// Receives the events published by every instance and fans them out to the
+/// clients connected to this one
+#[derive(Clone)]
+pub struct LiveHub {
+    sender: Sender<LiveEvent>,
+}
+
+impl LiveHub {
+    pub async fn init(pool: &Pool) -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+
+        let mut builder = Builder::from_config(pool.next().client_config());
+        builder.set_policy(ReconnectPolicy::default());
+        let client = builder.build_subscriber_client().unwrap();
+        client.init().await.unwrap();
+        // Subscribes again after reconnecting
+        client.manage_subscriptions();
+        client.subscribe(CHANNEL).await.unwrap();
+
+        let mut message_rx = client.message_rx();
+        let forward = sender.clone();
+
+        tokio::spawn(async move {
+            // The client lives as long as the task
+            let _client = client;
+            tracing::info!("Live event subscriber started");
+            loop {
+                match message_rx.recv().await {
+                    Ok(message) => {
+                        let event = message
+                            .value
+                            .as_str()
+                            .map(|json| serde_json::from_str(&json));
+
+                        match event {
+                            Some(Ok(event)) => {
+                                // No one is connected
+                                let _ = forward.send(event);
+                            }
+                            Some(Err(e)) => {
+                                tracing::error!(
+                                    "Failed to decode live event: {e}"
+                                );
+                            }
+                            None => {}
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(count)) => {
+                        tracing::warn!("Skipped {count} live events");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> Receiver<LiveEvent> {
+        self.sender.subscribe()
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::TimeDelta;
+use fred::prelude::{Expiration, KeysInterface};
+
+use crate::domain::login_throttle::{FailedLogins, Repo, ThrottleKey};
+
+#[derive(Clone)]
+pub struct RedisLoginThrottleRepo {
+    pool: fred::prelude::Pool,
+}
+
+impl RedisLoginThrottleRepo {
+    pub const fn new(pool: fred::prelude::Pool) -> Self {
+        Self { pool }
+    }
+}
+
+fn failures_key(key: &ThrottleKey) -> String {
+    format!("login_failures:{key}")
+}
+
+impl Repo for RedisLoginThrottleRepo {
+    async fn find_failures(
+        &self,
+        key: &ThrottleKey,
+    ) -> Result<Option<FailedLogins>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let json: Option<String> = self.pool.get(failures_key(key)).await?;
+
+        Ok(json.map(|json| serde_json::from_str(&json)).transpose()?)
+    }
+
+    async fn save_failures(
+        &self,
+        key: &ThrottleKey,
+        failures: FailedLogins,
+        ttl: TimeDelta,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let () = self
+            .pool
+            .set(
+                failures_key(key),
+                serde_json::to_string(&failures)?,
+                Some(Expiration::EX(ttl.num_seconds().max(1))),
+                None,
+                false,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn clear_failures(
+        &self,
+        key: &ThrottleKey,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let () = self.pool.del(failures_key(key)).await?;
+
+        Ok(())
+    }
+}
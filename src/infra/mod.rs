@@ -1,13 +1,19 @@
 pub mod config;
+pub mod contribution;
 pub mod database;
 pub mod email;
 pub mod error;
+pub mod live;
 pub mod logger;
+pub mod login_throttle;
 pub mod mapper;
+pub mod oidc;
 pub mod redis;
+pub mod session;
 pub mod singleton;
 pub mod state;
 pub mod storage;
+pub mod webhook;
 pub mod worker;
 
 pub use error::Error;
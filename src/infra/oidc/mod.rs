@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use openidconnect::core::{
+    CoreAuthenticationFlow, CoreClient, CoreProviderMetadata,
+};
+use openidconnect::{
+    AuthorizationCode, ClientId, ClientSecret, CsrfToken, EndpointMaybeSet,
+    EndpointNotSet, EndpointSet, IssuerUrl, Nonce, PkceCodeChallenge,
+    PkceCodeVerifier, RedirectUrl, Scope, TokenResponse, reqwest,
+};
+
+use crate::domain::identity::{
+    AuthorizationRequest, ExternalIdentity, IdentityProvider, OidcConfig,
+    OidcProvider, PendingAuthorization,
+};
+
+#[cfg(test)]
+mod tests;
+
+type DiscoveredClient = CoreClient<
+    EndpointSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointMaybeSet,
+    EndpointMaybeSet,
+>;
+
+#[derive(Clone)]
+pub struct OidcClient {
+    http: reqwest::Client,
+    config: Arc<OidcConfig>,
+}
+
+impl OidcClient {
+    pub fn new(config: OidcConfig) -> Self {
+        Self {
+            // Providers are never expected to redirect, following them would
+            // allow SSRF
+            http: reqwest::ClientBuilder::new()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .expect("Failed to build http client"),
+            config: Arc::new(config),
+        }
+    }
+
+    /// Discovered on each use, so rotated signing keys are picked up
+    async fn client(
+        &self,
+        key: &str,
+    ) -> Result<DiscoveredClient, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let provider = self
+            .config
+            .providers
+            .get(key)
+            .ok_or_else(|| format!("Unknown identity provider {key}"))?;
+
+        let metadata = CoreProviderMetadata::discover_async(
+            IssuerUrl::new(provider.issuer_url.clone())?,
+            &self.http,
+        )
+        .await?;
+
+        let redirect_url = format!(
+            "{}/oidc/{key}/callback",
+            self.config.redirect_base_url.trim_end_matches('/')
+        );
+
+        Ok(CoreClient::from_provider_metadata(
+            metadata,
+            ClientId::new(provider.client_id.clone()),
+            provider.client_secret.clone().map(ClientSecret::new),
+        )
+        .set_redirect_uri(RedirectUrl::new(redirect_url)?))
+    }
+}
+
+impl IdentityProvider for OidcClient {
+    fn providers(&self) -> Vec<OidcProvider> {
+        self.config
+            .providers
+            .iter()
+            .map(|(key, provider)| OidcProvider {
+                key: key.clone(),
+                name: provider.name.clone(),
+            })
+            .collect()
+    }
+
+    async fn authorize(
+        &self,
+        provider: &str,
+        link_user_id: Option<i32>,
+    ) -> Result<AuthorizationRequest, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let client = self.client(provider).await?;
+
+        let (pkce_challenge, pkce_verifier) =
+            PkceCodeChallenge::new_random_sha256();
+
+        let (url, state, nonce) = client
+            .authorize_url(
+                CoreAuthenticationFlow::AuthorizationCode,
+                CsrfToken::new_random,
+                Nonce::new_random,
+            )
+            .add_scope(Scope::new("email".to_owned()))
+            .add_scope(Scope::new("profile".to_owned()))
+            .set_pkce_challenge(pkce_challenge)
+            .url();
+
+        Ok(AuthorizationRequest {
+            url,
+            pending: PendingAuthorization {
+                provider: provider.to_owned(),
+                state: state.into_secret(),
+                nonce: nonce.secret().clone(),
+                pkce_verifier: pkce_verifier.into_secret(),
+                link_user_id,
+            },
+        })
+    }
+
+    async fn exchange(
+        &self,
+        pending: &PendingAuthorization,
+        code: String,
+    ) -> Result<ExternalIdentity, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let client = self.client(&pending.provider).await?;
+
+        let response = client
+            .exchange_code(AuthorizationCode::new(code))?
+            .set_pkce_verifier(PkceCodeVerifier::new(
+                pending.pkce_verifier.clone(),
+            ))
+            .request_async(&self.http)
+            .await?;
+
+        let id_token = response
+            .id_token()
+            .ok_or("The identity provider returned no ID token")?;
+
+        let claims = id_token.claims(
+            &client.id_token_verifier(),
+            &Nonce::new(pending.nonce.clone()),
+        )?;
+
+        Ok(ExternalIdentity {
+            provider: pending.provider.clone(),
+            subject: claims.subject().to_string(),
+            email: claims.email().map(|email| email.as_str().to_owned()),
+            preferred_username: claims
+                .preferred_username()
+                .map(|name| name.as_str().to_owned()),
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Form, Json, Router};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{TimeDelta, Utc};
+use openidconnect::core::{
+    CoreIdToken, CoreIdTokenClaims, CoreJsonWebKeySet, CoreJwsSigningAlgorithm,
+    CoreRsaPrivateSigningKey,
+};
+use openidconnect::{
+    Audience, EmptyAdditionalClaims, EndUserEmail, EndUserUsername, IssuerUrl,
+    JsonWebKeyId, Nonce, PrivateSigningKey, StandardClaims, SubjectIdentifier,
+};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use super::OidcClient;
+use crate::domain::identity::model::OidcProviderConfig;
+use crate::domain::identity::{IdentityProvider, OidcConfig};
+
+const CLIENT_ID: &str = "thcdb";
+const CODE: &str = "mock-code";
+
+/// A local provider that signs in everyone as alice
+struct MockProvider {
+    issuer: String,
+    key: CoreRsaPrivateSigningKey,
+    /// The code challenge and nonce of the last authorization
+    authorization: Mutex<Option<(String, String)>>,
+}
+
+async fn discovery(State(mock): State<Arc<MockProvider>>) -> Json<Value> {
+    Json(json!({
+        "issuer": mock.issuer,
+        "authorization_endpoint": format!("{}/authorize", mock.issuer),
+        "token_endpoint": format!("{}/token", mock.issuer),
+        "jwks_uri": format!("{}/jwks", mock.issuer),
+        "response_types_supported": ["code"],
+        "subject_types_supported": ["public"],
+        "id_token_signing_alg_values_supported": ["RS256"],
+    }))
+}
+
+async fn jwks(State(mock): State<Arc<MockProvider>>) -> Json<Value> {
+    Json(
+        serde_json::to_value(CoreJsonWebKeySet::new(vec![
+            mock.key.as_verification_key(),
+        ]))
+        .unwrap(),
+    )
+}
+
+async fn token(
+    State(mock): State<Arc<MockProvider>>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    let (challenge, nonce) =
+        mock.authorization.lock().unwrap().clone().unwrap();
+
+    let verifier_matches = form.get("code_verifier").is_some_and(|verifier| {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(verifier)) == challenge
+    });
+
+    if form.get("code").map(String::as_str) != Some(CODE) || !verifier_matches {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "invalid_grant" })),
+        ));
+    }
+
+    let claims = CoreIdTokenClaims::new(
+        IssuerUrl::new(mock.issuer.clone()).unwrap(),
+        vec![Audience::new(CLIENT_ID.to_owned())],
+        Utc::now() + TimeDelta::hours(1),
+        Utc::now(),
+        StandardClaims::new(SubjectIdentifier::new("alice-sub".to_owned()))
+            .set_email(Some(EndUserEmail::new("alice@example.com".to_owned())))
+            .set_preferred_username(Some(EndUserUsername::new(
+                "alice".to_owned(),
+            ))),
+        EmptyAdditionalClaims {},
+    )
+    .set_nonce(Some(Nonce::new(nonce)));
+
+    let id_token = CoreIdToken::new(
+        claims,
+        &mock.key,
+        CoreJwsSigningAlgorithm::RsaSsaPkcs1V15Sha256,
+        None,
+        None,
+    )
+    .unwrap();
+
+    Ok(Json(json!({
+        "access_token": "mock-access-token",
+        "token_type": "bearer",
+        "id_token": id_token,
+    })))
+}
+
+async fn start_mock_provider() -> Arc<MockProvider> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+    let mock = Arc::new(MockProvider {
+        issuer: format!("http://{}", listener.local_addr().unwrap()),
+        key: CoreRsaPrivateSigningKey::from_pem(
+            include_str!("test_key.pem"),
+            Some(JsonWebKeyId::new("test".to_owned())),
+        )
+        .unwrap(),
+        authorization: Mutex::new(None),
+    });
+
+    let router = Router::new()
+        .route("/.well-known/openid-configuration", get(discovery))
+        .route("/jwks", get(jwks))
+        .route("/token", post(token))
+        .with_state(mock.clone());
+
+    tokio::spawn(async move { axum::serve(listener, router).await });
+
+    mock
+}
+
+#[tokio::test]
+async fn authorization_code_flow_with_pkce() {
+    let mock = start_mock_provider().await;
+
+    let client = OidcClient::new(OidcConfig {
+        redirect_base_url: "http://localhost:12345/".to_owned(),
+        providers: BTreeMap::from([(
+            "mock".to_owned(),
+            OidcProviderConfig {
+                name: "Mock".to_owned(),
+                issuer_url: mock.issuer.clone(),
+                client_id: CLIENT_ID.to_owned(),
+                client_secret: Some("secret".to_owned()),
+            },
+        )]),
+    });
+
+    let request = client.authorize("mock", None).await.unwrap();
+    let params = request
+        .url
+        .query_pairs()
+        .into_owned()
+        .collect::<HashMap<_, _>>();
+
+    assert!(request.url.as_str().starts_with(&mock.issuer));
+    assert_eq!(params["code_challenge_method"], "S256");
+    assert_eq!(params["state"], request.pending.state);
+    assert_eq!(
+        params["redirect_uri"],
+        "http://localhost:12345/oidc/mock/callback"
+    );
+
+    *mock.authorization.lock().unwrap() =
+        Some((params["code_challenge"].clone(), params["nonce"].clone()));
+
+    let identity = client
+        .exchange(&request.pending, CODE.to_owned())
+        .await
+        .unwrap();
+
+    assert_eq!(identity.provider, "mock");
+    assert_eq!(identity.subject, "alice-sub");
+    assert_eq!(identity.email.as_deref(), Some("alice@example.com"));
+    assert_eq!(identity.preferred_username.as_deref(), Some("alice"));
+
+    // Another verifier than the one of the challenge
+    let mut tampered = request.pending.clone();
+    tampered.pkce_verifier = "x".repeat(43);
+
+    assert!(client.exchange(&tampered, CODE.to_owned()).await.is_err());
+
+    // The ID token has to carry the nonce of the authorization
+    let mut tampered = request.pending;
+    tampered.nonce = "other".to_owned();
+
+    assert!(client.exchange(&tampered, CODE.to_owned()).await.is_err());
+}
@@ -0,0 +1,249 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use fred::prelude::{Expiration, KeysInterface, SetsInterface};
+use rand::distr::{Alphanumeric, SampleString};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::user::{SessionClient, SessionRepo, UserSession};
+
+/// Sessions expire after this many days without requests
+pub const SESSION_EXPIRY_DAYS: i64 = 30;
+
+/// Requests in quick succession only update the last seen time once
+const TOUCH_INTERVAL: TimeDelta = TimeDelta::minutes(1);
+
+/// Indexes the sessions of [`tower_sessions_redis_store::RedisStore`] by
+/// user. The store keys each session by its id only
+#[derive(Clone)]
+pub struct RedisSessionRepo {
+    pool: fred::prelude::Pool,
+}
+
+impl RedisSessionRepo {
+    pub const fn new(pool: fred::prelude::Pool) -> Self {
+        Self { pool }
+    }
+}
+
+fn sessions_key(user_id: i32) -> String {
+    format!("user_sessions:{user_id}")
+}
+
+fn info_key(session_id: &str) -> String {
+    format!("session_info:{session_id}")
+}
+
+/// Stored next to each session, expiring with it
+#[derive(Serialize, Deserialize)]
+struct SessionInfo {
+    id: String,
+    created_at: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+    user_agent: Option<String>,
+    ip: Option<String>,
+}
+
+impl RedisSessionRepo {
+    async fn find_info(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<SessionInfo>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let json: Option<String> = self.pool.get(info_key(session_id)).await?;
+
+        Ok(json.map(|json| serde_json::from_str(&json)).transpose()?)
+    }
+
+    async fn save_info(
+        &self,
+        session_id: &str,
+        info: &SessionInfo,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let () = self
+            .pool
+            .set(
+                info_key(session_id),
+                serde_json::to_string(info)?,
+                Some(Expiration::EX(SESSION_EXPIRY_DAYS * 24 * 60 * 60)),
+                None,
+                false,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Forgets the sessions that expired and returns the others
+    async fn live_sessions(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+        let key = sessions_key(user_id);
+
+        let session_ids: Vec<String> = self.pool.smembers(&key).await?;
+
+        let mut live = Vec::with_capacity(session_ids.len());
+
+        for id in session_ids {
+            let exists: bool = self.pool.exists(&id).await?;
+
+            if exists {
+                live.push(id);
+            } else {
+                let () = self.pool.srem(&key, &id).await?;
+                let () = self.pool.del(info_key(&id)).await?;
+            }
+        }
+
+        Ok(live)
+    }
+
+    async fn delete_sessions(
+        &self,
+        user_id: i32,
+        session_ids: Vec<String>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if session_ids.is_empty() {
+            return Ok(());
+        }
+
+        let keys = session_ids
+            .iter()
+            .flat_map(|id| [id.clone(), info_key(id)])
+            .collect::<Vec<_>>();
+
+        let () = self.pool.del(keys).await?;
+        let () = self.pool.srem(sessions_key(user_id), session_ids).await?;
+
+        Ok(())
+    }
+}
+
+impl SessionRepo for RedisSessionRepo {
+    async fn track_session(
+        &self,
+        user_id: i32,
+        session_id: String,
+        client: SessionClient,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // Forget the sessions that expired since the last sign in
+        self.live_sessions(user_id).await?;
+
+        let now = Utc::now();
+        let info = SessionInfo {
+            id: Alphanumeric.sample_string(&mut rand::rng(), 22),
+            created_at: now,
+            last_seen: now,
+            user_agent: client.user_agent,
+            ip: client.ip,
+        };
+
+        self.save_info(&session_id, &info).await?;
+
+        let () = self.pool.sadd(sessions_key(user_id), session_id).await?;
+
+        Ok(())
+    }
+
+    async fn is_signed_in(
+        &self,
+        session_id: String,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self.pool.exists(session_id).await?)
+    }
+
+    async fn touch_session(
+        &self,
+        session_id: String,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(mut info) = self.find_info(&session_id).await? else {
+            return Ok(());
+        };
+
+        let now = Utc::now();
+
+        if now - info.last_seen >= TOUCH_INTERVAL {
+            info.last_seen = now;
+
+            self.save_info(&session_id, &info).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_sessions(
+        &self,
+        user_id: i32,
+        current_session_id: String,
+    ) -> Result<Vec<UserSession>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let mut sessions = vec![];
+
+        for session_id in self.live_sessions(user_id).await? {
+            // Sessions signed in before their info was recorded
+            let Some(info) = self.find_info(&session_id).await? else {
+                continue;
+            };
+
+            sessions.push(UserSession {
+                id: info.id,
+                created_at: info.created_at,
+                last_seen: info.last_seen,
+                user_agent: info.user_agent,
+                ip: info.ip,
+                current: session_id == current_session_id,
+            });
+        }
+
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.last_seen));
+
+        Ok(sessions)
+    }
+
+    async fn revoke_session(
+        &self,
+        user_id: i32,
+        id: String,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        for session_id in self.live_sessions(user_id).await? {
+            if self
+                .find_info(&session_id)
+                .await?
+                .is_some_and(|info| info.id == id)
+            {
+                self.delete_sessions(user_id, vec![session_id]).await?;
+
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn revoke_sessions(
+        &self,
+        user_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let session_ids: Vec<String> =
+            self.pool.smembers(sessions_key(user_id)).await?;
+
+        self.delete_sessions(user_id, session_ids).await
+    }
+
+    async fn revoke_other_sessions(
+        &self,
+        user_id: i32,
+        session_id: String,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let session_ids: Vec<String> =
+            self.pool.smembers(sessions_key(user_id)).await?;
+
+        self.delete_sessions(
+            user_id,
+            session_ids
+                .into_iter()
+                .filter(|id| *id != session_id)
+                .collect(),
+        )
+        .await
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use lettre::transport::smtp::authentication::Credentials;
 use lettre::{AsyncSmtpTransport, Tokio1Executor};
 use sea_orm::DatabaseConnection;
@@ -5,7 +7,13 @@ use sea_orm::DatabaseConnection;
 use super::config::Config;
 use super::database::get_connection;
 use super::database::sea_orm::SeaOrmRepository;
+use super::live::{LiveHub, LivePublisher};
+use super::oidc::OidcClient;
 use super::redis::Pool;
+use crate::domain::correction::CorrectionPolicy;
+use crate::domain::report::ReportPolicy;
+use crate::domain::two_factor::TwoFactorPolicy;
+use crate::domain::webhook::WebhookPolicy;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -16,12 +24,27 @@ pub struct AppState {
     pub transport: AsyncSmtpTransport<Tokio1Executor>,
 
     pub sea_orm_repo: SeaOrmRepository,
+
+    pub oidc: OidcClient,
+
+    pub live: LiveHub,
+
+    pub correction_policy: Arc<CorrectionPolicy>,
+
+    pub report_policy: ReportPolicy,
+
+    pub two_factor_policy: Arc<TwoFactorPolicy>,
+
+    pub webhook_policy: WebhookPolicy,
 }
 
 impl AppState {
     pub async fn init(config: &Config) -> Self {
         let conn = get_connection(&config.database_url).await;
         let redis_pool = Pool::init(&config.redis_url).await.inner;
+        let live = LiveHub::init(&redis_pool).await;
+        let sea_orm_repo = SeaOrmRepository::new(conn.clone())
+            .with_live(LivePublisher::new(redis_pool.clone()));
         let stmp_conf = &config.email;
         let creds = Credentials::new(
             stmp_conf.creds.username.clone(),
@@ -37,7 +60,13 @@ impl AppState {
             database: conn.clone(),
             redis_pool,
             transport,
-            sea_orm_repo: SeaOrmRepository::new(conn.clone()),
+            sea_orm_repo,
+            oidc: OidcClient::new(config.oidc.clone()),
+            live,
+            correction_policy: Arc::new(config.correction.clone()),
+            report_policy: config.report,
+            two_factor_policy: Arc::new(config.two_factor.clone()),
+            webhook_policy: config.webhook,
         }
     }
 }
@@ -60,6 +60,13 @@ impl FsStorage {
         }
     }
 
+    async fn read(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<u8>, std::io::Error> {
+        tokio::fs::read(self.prepend_prefix(path)).await
+    }
+
     async fn remove(
         &self,
         path: impl AsRef<std::path::Path> + Send + Sync,
@@ -73,6 +73,12 @@ impl AsyncFileStorage for GenericFileStorage {
             }
         }
     }
+
+    async fn read(&self, image: &Image) -> Result<Vec<u8>, Self::Error> {
+        match image.backend {
+            StorageBackend::Fs => self.fs.read(image.full_path()).await,
+        }
+    }
 }
 
 async fn enqueue_delete_task(
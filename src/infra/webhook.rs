@@ -0,0 +1,177 @@
+use std::net::IpAddr;
+use std::time::Duration;
+
+use crate::domain::webhook::{Client, WebhookPolicy};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct HttpClient {
+    policy: WebhookPolicy,
+}
+
+impl HttpClient {
+    pub const fn new(policy: WebhookPolicy) -> Self {
+        Self { policy }
+    }
+
+    /// Receivers are never expected to redirect, following them would allow
+    /// SSRF. Unless private hosts are allowed, the host is resolved up front
+    /// and pinned, so it can't be rebound to a private address afterwards
+    async fn http(
+        &self,
+        url: &reqwest::Url,
+    ) -> Result<reqwest::Client, Box<dyn std::error::Error + Send + Sync>> {
+        let builder = reqwest::ClientBuilder::new()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(TIMEOUT);
+
+        if self.policy.allow_private_hosts {
+            return Ok(builder.build()?);
+        }
+
+        let host = url.host_str().ok_or("Url has no host")?;
+        let port = url.port_or_known_default().ok_or("Url has no port")?;
+        let addrs = tokio::net::lookup_host((host, port))
+            .await?
+            .collect::<Vec<_>>();
+
+        if let Some(addr) = addrs.iter().find(|addr| is_private(addr.ip())) {
+            return Err(format!(
+                "Refused to deliver to private address {}",
+                addr.ip()
+            )
+            .into());
+        }
+
+        let addr = addrs.first().ok_or("Host has no address")?;
+
+        Ok(builder.resolve(host, *addr).build()?)
+    }
+}
+
+impl Client for HttpClient {
+    async fn post(
+        &self,
+        url: &str,
+        headers: Vec<(&'static str, String)>,
+        body: String,
+    ) -> Result<u16, Box<dyn std::error::Error + Send + Sync>> {
+        let url = reqwest::Url::parse(url)?;
+
+        let request = headers.into_iter().fold(
+            self.http(&url)
+                .await?
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json"),
+            |request, (name, value)| request.header(name, value),
+        );
+
+        Ok(request.body(body).send().await?.status().as_u16())
+    }
+}
+
+fn is_private(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+        }
+        IpAddr::V6(ip) => ip.to_ipv4_mapped().map_or_else(
+            || {
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local()
+            },
+            |ip| is_private(IpAddr::V4(ip)),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use axum::Router;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+
+    use super::*;
+    use crate::domain::webhook::model::sign;
+
+    type Received = Arc<Mutex<Option<(HeaderMap, String)>>>;
+
+    async fn receive(
+        State(received): State<Received>,
+        headers: HeaderMap,
+        body: String,
+    ) -> StatusCode {
+        *received.lock().unwrap() = Some((headers, body));
+
+        StatusCode::NO_CONTENT
+    }
+
+    /// A local receiver which keeps the last request
+    async fn receiver() -> (String, Received) {
+        let received = Received::default();
+        let app = Router::new()
+            .route("/hook", post(receive))
+            .with_state(received.clone());
+        let listener =
+            tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        (url, received)
+    }
+
+    const fn policy(allow_private_hosts: bool) -> WebhookPolicy {
+        WebhookPolicy {
+            max_attempts: 3,
+            retry_base_seconds: 1,
+            allow_private_hosts,
+            max_webhooks_per_owner: 10,
+        }
+    }
+
+    #[tokio::test]
+    async fn delivers_signed_body() {
+        let (url, received) = receiver().await;
+        let body = r#"{"event":"Ping","data":{"webhook_id":1}}"#;
+        let signature = sign("secret", 1_700_000_000, body);
+
+        let status = HttpClient::new(policy(true))
+            .post(
+                &url,
+                vec![("Webhook-Signature", signature.clone())],
+                body.to_owned(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(status, 204);
+
+        let (headers, received_body) = received.lock().unwrap().take().unwrap();
+
+        assert_eq!(received_body, body);
+        assert_eq!(headers["webhook-signature"], signature.as_str());
+        assert_eq!(headers["content-type"], "application/json");
+    }
+
+    #[tokio::test]
+    async fn refuses_private_hosts() {
+        let (url, received) = receiver().await;
+
+        let result = HttpClient::new(policy(false))
+            .post(&url, vec![], "{}".to_owned())
+            .await;
+
+        assert!(result.is_err());
+        assert!(received.lock().unwrap().is_none());
+    }
+}
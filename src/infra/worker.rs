@@ -2,19 +2,65 @@ use std::time::Duration;
 
 use fred::prelude::{Client, ClientLike, ListInterface, Options};
 
+use super::database::sea_orm::SeaOrmRepository;
+use super::singleton::APP_CONFIG;
 use super::storage::file::REMOVE_FILE_FAIELD_KEY;
+use super::webhook::HttpClient;
+use crate::domain::correction::DraftRepo;
+use crate::domain::webhook::Dispatcher;
 use crate::utils::retry_async;
 
 pub struct Worker {
     pub redis_pool: fred::prelude::Pool,
+    pub sea_orm_repo: SeaOrmRepository,
 }
 
 impl Worker {
     pub fn init(self) {
         init_remove_file(self.redis_pool);
+        init_remove_expired_drafts(self.sea_orm_repo.clone());
+        init_deliver_webhooks(self.sea_orm_repo);
     }
 }
 
+fn init_deliver_webhooks(repo: SeaOrmRepository) {
+    let dispatcher = Dispatcher::new(
+        repo,
+        HttpClient::new(APP_CONFIG.webhook),
+        APP_CONFIG.webhook,
+    );
+
+    tokio::spawn(async move {
+        tracing::info!("Webhook delivery worker started");
+        let mut interval = tokio::time::interval(Duration::from_secs(5));
+        loop {
+            interval.tick().await;
+            if let Err(e) = dispatcher.deliver_due().await {
+                tracing::error!("Failed to deliver webhooks: {}", e);
+            }
+        }
+    });
+}
+
+fn init_remove_expired_drafts(repo: SeaOrmRepository) {
+    tokio::spawn(async move {
+        tracing::info!("Expired draft removal worker started");
+        let mut interval = tokio::time::interval(Duration::from_hours(1));
+        loop {
+            interval.tick().await;
+            match repo.delete_expired_drafts().await {
+                Ok(0) => {}
+                Ok(count) => {
+                    tracing::info!("Deleted {} expired drafts", count);
+                }
+                Err(e) => {
+                    tracing::error!("Failed to delete expired drafts: {}", e);
+                }
+            }
+        }
+    });
+}
+
 fn init_remove_file(redis_pool: fred::prelude::Pool) {
     let client = Client::clone_new(redis_pool.next()).with_options(&Options {
         timeout: Duration::from_secs(0).into(),
@@ -69,6 +69,7 @@ async fn main() -> Result<(), Whatever> {
 
     Worker {
         redis_pool: state.redis_pool(),
+        sea_orm_repo: state.sea_orm_repo.clone(),
     }
     .init();
 
@@ -204,6 +204,43 @@ impl IntoResponse for Error {
     }
 }
 
+/// Error response carrying data the client needs to resolve the error
+#[derive(ToSchema, Serialize)]
+pub struct ErrorWithData<T> {
+    #[schema(schema_with = status_err_schema)]
+    status: Status,
+    message: String,
+    data: T,
+    #[serde(skip)]
+    status_code: StatusCode,
+}
+
+impl<T> ErrorWithData<T>
+where
+    T: Serialize,
+{
+    pub fn from_api_error<E>(err: &E, data: T) -> Self
+    where
+        E: ApiError + Display,
+    {
+        Self {
+            status: Status::Err,
+            message: err.to_string(),
+            data,
+            status_code: err.as_status_code(),
+        }
+    }
+}
+
+impl<T> IntoResponse for ErrorWithData<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        (self.status_code, Json(self)).into_response()
+    }
+}
+
 pub trait ErrResponseDef {
     fn build_err_responses() -> utoipa::openapi::Responses;
 }
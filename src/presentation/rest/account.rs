@@ -0,0 +1,81 @@
+use axum::Json;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, AuthSession, {self},
+};
+use crate::application::account::{DeleteAccount, Error};
+use crate::presentation::api_response::Message;
+
+const TAG: &str = "Account";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(export))
+        .routes(routes!(delete_account))
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/profile/export",
+    responses(
+        (
+            status = 200,
+            content_type = "application/zip",
+            body = Vec<u8>,
+        ),
+        (status = 401),
+        Error
+    ),
+)]
+async fn export(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::AccountService>,
+) -> Result<impl IntoResponse, Error> {
+    let file_name = format!("{}.zip", user.name);
+    let archive = service.export(user).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+        ],
+        archive,
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/profile",
+    request_body = DeleteAccount,
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn delete_account(
+    CurrentUser(user): CurrentUser,
+    mut auth_session: AuthSession,
+    State(service): State<state::AccountService>,
+    Json(input): Json<DeleteAccount>,
+) -> Result<Message, Error> {
+    service.delete(&user, input).await?;
+
+    // The session is already revoked, this only clears the cookie
+    if let Err(err) = auth_session.logout().await {
+        tracing::warn!("Failed to sign out a deleted user: {err}");
+    }
+
+    Ok(Message::ok())
+}
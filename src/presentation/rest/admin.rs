@@ -0,0 +1,243 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::application::admin::Error;
+use crate::domain::admin::{
+    AuditLogEntry, AuditLogFilter, NewRole, NewUserSuspension, Role,
+    UserSuspension,
+};
+use crate::domain::model::auth::PermissionGrant;
+use crate::domain::repository::{Cursor, Paginated};
+use crate::presentation::api_response::{Data, Message};
+
+const TAG: &str = "Admin";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(find_roles, create_role))
+        .routes(routes!(grant_role_permission, revoke_role_permission))
+        .routes(routes!(grant_user_role, revoke_user_role))
+        .routes(routes!(suspend_user, lift_user_suspension))
+        .routes(routes!(find_audit_log))
+}
+
+super::data! {
+    DataRole, Role
+    DataVecRole, Vec<Role>
+    DataUserSuspension, UserSuspension
+    DataPaginatedAuditLogEntry, Paginated<AuditLogEntry>
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/admin/role",
+    responses(
+        (status = 200, body = DataVecRole),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_roles(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+) -> Result<Data<Vec<Role>>, Error> {
+    service.find_roles(&admin).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/admin/role",
+    request_body = NewRole,
+    responses(
+        (status = 200, body = DataRole),
+        (status = 401),
+        Error
+    ),
+)]
+async fn create_role(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Json(role): Json<NewRole>,
+) -> Result<Data<Role>, Error> {
+    service.create_role(&admin, role).await.map(Into::into)
+}
+
+#[utoipa::path(
+    put,
+    tag = TAG,
+    path = "/admin/role/{id}/permission",
+    request_body = PermissionGrant,
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn grant_role_permission(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Path(id): Path<i32>,
+    Json(grant): Json<PermissionGrant>,
+) -> Result<Message, Error> {
+    service.grant_permission(&admin, id, grant).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/admin/role/{id}/permission",
+    params(PermissionGrant),
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn revoke_role_permission(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Path(id): Path<i32>,
+    Query(grant): Query<PermissionGrant>,
+) -> Result<Message, Error> {
+    service.revoke_permission(&admin, id, grant).await?;
+
+    Ok(Message::ok())
+}
+
+#[derive(Deserialize, IntoParams)]
+struct UserRolePath {
+    id: i32,
+    role_id: i32,
+}
+
+#[utoipa::path(
+    put,
+    tag = TAG,
+    path = "/admin/user/{id}/role/{role_id}",
+    params(UserRolePath),
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn grant_user_role(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Path(UserRolePath { id, role_id }): Path<UserRolePath>,
+) -> Result<Message, Error> {
+    service.grant_role(&admin, id, role_id).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/admin/user/{id}/role/{role_id}",
+    params(UserRolePath),
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn revoke_user_role(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Path(UserRolePath { id, role_id }): Path<UserRolePath>,
+) -> Result<Message, Error> {
+    service.revoke_role(&admin, id, role_id).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/admin/user/{id}/suspension",
+    request_body = NewUserSuspension,
+    responses(
+        (status = 200, body = DataUserSuspension),
+        (status = 401),
+        Error
+    ),
+)]
+async fn suspend_user(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Path(id): Path<i32>,
+    Json(suspension): Json<NewUserSuspension>,
+) -> Result<Data<UserSuspension>, Error> {
+    service
+        .suspend(&admin, id, suspension)
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/admin/user/{id}/suspension",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn lift_user_suspension(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.lift_suspension(&admin, id).await?;
+
+    Ok(Message::ok())
+}
+
+#[derive(Deserialize, IntoParams)]
+struct AuditLogQuery {
+    /// The id of the last entry of the previous page, 0 for the newest
+    cursor: u32,
+    limit: u8,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/admin/audit-log",
+    params(AuditLogQuery, AuditLogFilter),
+    responses(
+        (status = 200, body = DataPaginatedAuditLogEntry),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_audit_log(
+    CurrentUser(admin): CurrentUser,
+    State(service): State<state::AdminService>,
+    Query(query): Query<AuditLogQuery>,
+    Query(filter): Query<AuditLogFilter>,
+) -> Result<Data<Paginated<AuditLogEntry>>, Error> {
+    let pagination = Cursor {
+        at: query.cursor,
+        limit: query.limit,
+    };
+
+    service
+        .find_audit_log(&admin, filter, pagination)
+        .await
+        .map(Into::into)
+}
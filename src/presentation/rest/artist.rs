@@ -10,15 +10,17 @@ use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
-use super::data;
 use super::extract::CurrentUser;
 use super::state::{
     ArcAppState, {self},
 };
+use super::{AsOfQuery, data};
 use crate::application::artist_image::{
     ArtistProfileImageInput, {self},
 };
-use crate::application::correction::NewCorrectionDto;
+use crate::application::correction::{
+    InvalidReferences, NewCorrectionDto, PossibleDuplicates, Protected,
+};
 use crate::application::error::Unauthorized;
 use crate::domain;
 use crate::domain::artist::CommonFilter;
@@ -28,6 +30,7 @@ use crate::domain::artist_release::{
     Appearance, AppearanceQuery, Credit, CreditQuery, Discography,
     DiscographyQuery,
 };
+use crate::domain::correction::SourceError;
 use crate::domain::release::model::Release;
 use crate::domain::repository::{Cursor, Paginated};
 use crate::infra::error::Error;
@@ -61,8 +64,9 @@ data!(
     get,
     tag = TAG,
     path = "/artist/{id}",
-       params(
-        CommonFilter
+    params(
+        CommonFilter,
+        AsOfQuery
     ),
     responses(
         (status = 200, body = DataOptionArtist),
@@ -71,14 +75,19 @@ data!(
 )]
 async fn find_artist_by_id(
     State(repo): State<state::SeaOrmRepository>,
+    State(service): State<state::ArtistService>,
     Path(id): Path<i32>,
     axum_extra::extract::Query(common): axum_extra::extract::Query<
         CommonFilter,
     >,
+    Query(query): Query<AsOfQuery>,
 ) -> Result<Data<Option<Artist>>, Error> {
-    domain::artist::repo::Repo::find_one(&repo, id, common)
-        .await
-        .bimap_into()
+    match query.at() {
+        Some(at) => service.find_as_of(id, at).await.bimap_into(),
+        None => domain::artist::repo::Repo::find_one(&repo, id, common)
+            .await
+            .bimap_into(),
+    }
 }
 
 #[derive(Deserialize, IntoParams)]
@@ -127,7 +136,10 @@ async fn find_many_artist(
     responses(
         (status = 200, body = Message),
         Error,
-        domain::artist::ValidationError
+        domain::artist::ValidationError,
+        InvalidReferences,
+        PossibleDuplicates,
+        SourceError
     ),
 )]
 // #[axum::debug_handler]
@@ -141,8 +153,11 @@ async fn create_artist(
         .await
         .map(|()| Message::ok())
         .map_err(|e| match e.to_enum() {
-            eros::E2::A(e) => e.into_api_response(),
-            eros::E2::B(e) => e.into_api_response(),
+            eros::E5::A(e) => e.into_api_response(),
+            eros::E5::B(e) => e.into_api_response(),
+            eros::E5::C(e) => e.into_api_response(),
+            eros::E5::D(e) => e.into_api_response(),
+            eros::E5::E(e) => e.into_api_response(),
         })
 }
 
@@ -155,7 +170,10 @@ async fn create_artist(
         (status = 200, body = Message),
         Error,
         domain::artist::ValidationError,
-        Unauthorized
+        Unauthorized,
+        InvalidReferences,
+        SourceError,
+        Protected
     ),
 )]
 async fn upsert_artist_correction(
@@ -169,9 +187,12 @@ async fn upsert_artist_correction(
         .await
         .map(|()| Message::ok())
         .map_err(|x| match x.to_enum() {
-            eros::E3::A(e) => e.into_api_response(),
-            eros::E3::B(e) => e.into_api_response(),
-            eros::E3::C(e) => e.into_api_response(),
+            eros::E6::A(e) => e.into_api_response(),
+            eros::E6::B(e) => e.into_api_response(),
+            eros::E6::C(e) => e.into_api_response(),
+            eros::E6::D(e) => e.into_api_response(),
+            eros::E6::E(e) => e.into_api_response(),
+            eros::E6::F(e) => e.into_api_response(),
         })
 }
 
@@ -257,12 +278,15 @@ async fn find_artist_apperances(
 struct CreditQueryDto {
     cursor: u32,
     limit: u8,
+    /// Only include credits of this role or any of its sub roles
+    role_id: Option<i32>,
 }
 
 impl CreditQueryDto {
     const fn into_query(self, artist_id: i32) -> CreditQuery {
         CreditQuery {
             artist_id,
+            role_id: self.role_id,
             pagination: Cursor {
                 at: self.cursor,
                 limit: self.limit,
@@ -0,0 +1,46 @@
+use axum::extract::{Query, State};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::domain::contribution::{LeaderboardEntry, LeaderboardWindow};
+use crate::infra::error::Error;
+use crate::presentation::api_response::Data;
+
+const TAG: &str = "Contribution";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new().routes(routes!(leaderboard))
+}
+
+super::data! {
+    DataVecLeaderboardEntry, Vec<LeaderboardEntry>
+}
+
+#[derive(Deserialize, IntoParams)]
+struct LeaderboardQuery {
+    #[serde(default)]
+    #[param(inline)]
+    window: LeaderboardWindow,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/leaderboard",
+    params(LeaderboardQuery),
+    responses(
+        (status = 200, body = DataVecLeaderboardEntry),
+        Error
+    ),
+)]
+async fn leaderboard(
+    State(service): State<state::ContributionService>,
+    Query(query): Query<LeaderboardQuery>,
+) -> Result<Data<Vec<LeaderboardEntry>>, Error> {
+    service.leaderboard(query.window).await.map(Into::into)
+}
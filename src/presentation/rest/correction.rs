@@ -1,5 +1,6 @@
-use axum::extract::{Path, Query, State};
-use axum::response::IntoResponse;
+use axum::Json;
+use axum::extract::{FromRef, Path, Query, State};
+use axum::response::{IntoResponse, Response};
 use entity::enums::EntityType;
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
@@ -8,16 +9,22 @@ use utoipa_axum::routes;
 
 use super::extract::CurrentUser;
 use super::state::{
-    ArcAppState, SeaOrmTxRepo, {self},
+    ArcAppState, {self},
 };
 use crate::application;
+use crate::application::correction::{
+    DraftError, NewPatchCorrection, PatchError, ProtectionError,
+    UntypedCorrection, draft_correction,
+};
 use crate::domain::correction::{
-    ApproveCorrectionContext, CorrectionFilter, {self},
+    Changeset, CorrectionDetail, CorrectionDraft, CorrectionFilter,
+    EntityProtection, NewChangeset, NewCorrectionDraft, NewCorrectionVote,
+    NewEntityProtection, {self},
 };
-use crate::domain::repository::TransactionManager;
+use crate::domain::user::User;
 use crate::infra::error::Error;
 use crate::presentation::api_response::{
-    Data, Message, {self},
+    Data, IntoApiResponse, Message, {self},
 };
 
 const TAG: &str = "Correction";
@@ -25,7 +32,40 @@ const TAG: &str = "Correction";
 pub fn router() -> OpenApiRouter<ArcAppState> {
     OpenApiRouter::new()
         .routes(routes!(handle_correction))
+        .routes(routes!(find_correction_by_id))
+        .routes(routes!(vote_correction))
+        .routes(routes!(add_correction_co_author))
         .routes(routes!(pending_correction))
+        .routes(routes!(latest_history))
+        .routes(routes!(create_changeset))
+        .routes(routes!(find_changeset_by_id))
+        .routes(routes!(create_correction_draft))
+        .routes(routes!(find_correction_draft_by_id))
+        .routes(routes!(update_correction_draft))
+        .routes(routes!(delete_correction_draft))
+        .routes(routes!(find_own_correction_drafts))
+        .routes(routes!(submit_correction_draft))
+        .routes(routes!(patch_correction))
+        .routes(routes!(
+            find_entity_protection,
+            set_entity_protection,
+            delete_entity_protection
+        ))
+}
+
+super::data! {
+    DataOptionCorrectionDetail, Option<CorrectionDetail>
+    DataChangeset, Changeset
+    DataOptionChangeset, Option<Changeset>
+    DataCorrectionDraft, CorrectionDraft
+    DataVecCorrectionDraft, Vec<CorrectionDraft>
+    DataEntityProtection, EntityProtection
+    DataOptionEntityProtection, Option<EntityProtection>
+}
+
+#[derive(ToSchema, Deserialize)]
+struct NewCoAuthor {
+    user_id: i32,
 }
 
 #[derive(ToSchema, Deserialize)]
@@ -57,19 +97,11 @@ async fn handle_correction(
     CurrentUser(user): CurrentUser,
     Path(id): Path<i32>,
     Query(query): Query<HandleCorrectionQuery>,
-    state: State<state::ArcAppState>,
     State(service): State<state::CorrectionService>,
 ) -> Result<Message, impl IntoResponse> {
-    let tx_repo = state
-        .sea_orm_repo
-        .begin()
-        .await
-        .map_err(Error::from)
-        .map_err(IntoResponse::into_response)?;
-
     match query.method {
         HandleCorrectionMethod::Approve => service
-            .approve(id, user, tx_repo)
+            .approve(id, user)
             .await
             .map_err(IntoResponse::into_response)
             .map(|()| Message::ok()),
@@ -79,46 +111,377 @@ async fn handle_correction(
     }
 }
 
-impl ApproveCorrectionContext for SeaOrmTxRepo {
-    type ArtistRepo = Self;
-    type ReleaseRepo = Self;
-    type SongRepo = Self;
-    type LabelRepo = Self;
-    type EventRepo = Self;
-    type TagRepo = Self;
-    type SongLyricsRepo = Self;
-    type CreditRoleRepo = Self;
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/correction/{id}",
+    responses(
+        (status = 200, body = DataOptionCorrectionDetail),
+        Error
+    ),
+)]
+async fn find_correction_by_id(
+    Path(id): Path<i32>,
+    State(service): State<state::CorrectionService>,
+) -> Result<Data<Option<CorrectionDetail>>, Error> {
+    service.find_detail(id).await.map(Into::into)
+}
 
-    fn artist_repo(self) -> Self::ArtistRepo {
-        self
-    }
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/correction/{id}/vote",
+    request_body = NewCorrectionVote,
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        application::correction::Error
+    ),
+)]
+async fn vote_correction(
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<i32>,
+    State(service): State<state::CorrectionService>,
+    Json(vote): Json<NewCorrectionVote>,
+) -> Result<Message, application::correction::Error> {
+    service.vote(id, user, vote).await?;
 
-    fn release_repo(self) -> Self::ReleaseRepo {
-        self
-    }
+    Ok(Message::ok())
+}
 
-    fn song_repo(self) -> Self::SongRepo {
-        self
-    }
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/correction/{id}/co-author",
+    request_body = NewCoAuthor,
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        application::correction::Error
+    ),
+)]
+async fn add_correction_co_author(
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<i32>,
+    State(service): State<state::CorrectionService>,
+    Json(NewCoAuthor { user_id }): Json<NewCoAuthor>,
+) -> Result<Message, application::correction::Error> {
+    service.add_co_author(id, user, user_id).await?;
 
-    fn label_repo(self) -> Self::LabelRepo {
-        self
-    }
+    Ok(Message::ok())
+}
 
-    fn event_repo(self) -> Self::EventRepo {
-        self
-    }
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/changeset",
+    request_body = NewChangeset,
+    responses(
+        (status = 200, body = DataChangeset),
+        (status = 401),
+        application::correction::Error
+    ),
+)]
+async fn create_changeset(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::CorrectionService>,
+    Json(changeset): Json<NewChangeset>,
+) -> Result<Data<Changeset>, application::correction::Error> {
+    service
+        .create_changeset(user, changeset)
+        .await
+        .map(Into::into)
+}
 
-    fn tag_repo(self) -> Self::TagRepo {
-        self
-    }
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/changeset/{id}",
+    responses(
+        (status = 200, body = DataOptionChangeset),
+        Error
+    ),
+)]
+async fn find_changeset_by_id(
+    Path(id): Path<i32>,
+    State(repo): State<state::SeaOrmRepository>,
+) -> Result<Data<Option<Changeset>>, Error> {
+    Ok(correction::Repo::find_changeset(&repo, id).await?.into())
+}
 
-    fn song_lyrics_repo(self) -> Self::SongLyricsRepo {
-        self
-    }
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/correction-draft",
+    request_body = NewCorrectionDraft,
+    responses(
+        (status = 200, body = DataCorrectionDraft),
+        (status = 401),
+        Error
+    ),
+)]
+async fn create_correction_draft(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::CorrectionService>,
+    Json(draft): Json<NewCorrectionDraft>,
+) -> Result<Data<CorrectionDraft>, Error> {
+    service.create_draft(&user, draft).await.map(Into::into)
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/correction-draft/{id}",
+    responses(
+        (status = 200, body = DataCorrectionDraft),
+        (status = 401),
+        DraftError
+    ),
+)]
+async fn find_correction_draft_by_id(
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<i32>,
+    State(service): State<state::CorrectionService>,
+) -> Result<Data<CorrectionDraft>, DraftError> {
+    service.find_draft(&user, id).await.map(Into::into)
+}
+
+#[utoipa::path(
+    put,
+    tag = TAG,
+    path = "/correction-draft/{id}",
+    request_body = NewCorrectionDraft,
+    responses(
+        (status = 200, body = DataCorrectionDraft),
+        (status = 401),
+        DraftError
+    ),
+)]
+async fn update_correction_draft(
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<i32>,
+    State(service): State<state::CorrectionService>,
+    Json(draft): Json<NewCorrectionDraft>,
+) -> Result<Data<CorrectionDraft>, DraftError> {
+    service.update_draft(&user, id, draft).await.map(Into::into)
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/correction-draft/{id}",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        DraftError
+    ),
+)]
+async fn delete_correction_draft(
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<i32>,
+    State(service): State<state::CorrectionService>,
+) -> Result<Message, DraftError> {
+    service.delete_draft(&user, id).await?;
+
+    Ok(Message::ok())
+}
 
-    fn credit_role_repo(self) -> Self::CreditRoleRepo {
-        self
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/profile/correction-draft",
+    responses(
+        (status = 200, body = DataVecCorrectionDraft),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_own_correction_drafts(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::CorrectionService>,
+) -> Result<Data<Vec<CorrectionDraft>>, Error> {
+    service.find_drafts(&user).await.map(Into::into)
+}
+
+#[derive(IntoParams, Deserialize)]
+struct SubmitDraftQuery {
+    /// Create the entity even if similar entities exist
+    #[serde(default)]
+    acknowledge_duplicates: bool,
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/correction-draft/{id}/submit",
+    params(SubmitDraftQuery),
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        DraftError
+    ),
+)]
+async fn submit_correction_draft(
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<i32>,
+    Query(query): Query<SubmitDraftQuery>,
+    State(state): State<ArcAppState>,
+) -> Result<Message, Response> {
+    let service = state::CorrectionService::from_ref(&state);
+    let draft = service
+        .find_draft(&user, id)
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    submit(
+        &state,
+        user.clone(),
+        draft_correction(draft, query.acknowledge_duplicates),
+        |err| {
+            DraftError::Incomplete {
+                id,
+                message: err.to_string(),
+            }
+            .into_response()
+        },
+    )
+    .await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/{entity_type}/{id}/patch",
+    params(EntityPath),
+    request_body = NewPatchCorrection,
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        PatchError
+    ),
+)]
+async fn patch_correction(
+    CurrentUser(user): CurrentUser,
+    Path(EntityPath { entity_type, id }): Path<EntityPath>,
+    State(state): State<ArcAppState>,
+    Json(patch): Json<NewPatchCorrection>,
+) -> Result<Message, Response> {
+    let correction = state::CorrectionService::from_ref(&state)
+        .patch_correction(entity_type.into(), id, patch)
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    submit(&state, user, correction, |err| {
+        PatchError::InvalidData {
+            message: err.to_string(),
+        }
+        .into_response()
+    })
+    .await?;
+
+    Ok(Message::ok())
+}
+
+macro_rules! submit_with {
+    (
+        $service:ty, $state:expr, $correction:expr, $user:expr, $invalid:expr
+    ) => {{
+        let service = <$service>::from_ref($state);
+        let entity_id = $correction.entity_id;
+        let correction = $correction.typed($user).map_err($invalid)?;
+
+        match entity_id {
+            Some(id) => service
+                .upsert_correction(id, correction)
+                .await
+                .map_err(IntoResponse::into_response),
+            None => service
+                .create(correction)
+                .await
+                .map_err(IntoResponse::into_response),
+        }
+    }};
+}
+
+/// Submits the correction through the create or update service of its
+/// entity type. `invalid` maps data that doesn't parse as the input form of
+/// the entity type
+async fn submit(
+    state: &ArcAppState,
+    user: User,
+    correction: UntypedCorrection,
+    invalid: impl FnOnce(serde_json::Error) -> Response,
+) -> Result<(), Response> {
+    match correction.entity_type {
+        EntityType::Artist => {
+            let service = state::ArtistService::from_ref(state);
+            let entity_id = correction.entity_id;
+            let correction = correction.typed(user).map_err(invalid)?;
+
+            match entity_id {
+                Some(id) => service
+                    .upsert_correction(id, correction)
+                    .await
+                    .map_err(|e| match e.to_enum() {
+                        eros::E6::A(e) => e.into_api_response(),
+                        eros::E6::B(e) => e.into_api_response(),
+                        eros::E6::C(e) => e.into_api_response(),
+                        eros::E6::D(e) => e.into_api_response(),
+                        eros::E6::E(e) => e.into_api_response(),
+                        eros::E6::F(e) => e.into_api_response(),
+                    }),
+                None => service.create(correction).await.map_err(|e| {
+                    match e.to_enum() {
+                        eros::E5::A(e) => e.into_api_response(),
+                        eros::E5::B(e) => e.into_api_response(),
+                        eros::E5::C(e) => e.into_api_response(),
+                        eros::E5::D(e) => e.into_api_response(),
+                        eros::E5::E(e) => e.into_api_response(),
+                    }
+                }),
+            }
+        }
+        EntityType::Label => {
+            submit_with!(state::LabelService, state, correction, user, invalid)
+        }
+        EntityType::Release => {
+            submit_with!(
+                state::ReleaseService,
+                state,
+                correction,
+                user,
+                invalid
+            )
+        }
+        EntityType::Song => {
+            submit_with!(state::SongService, state, correction, user, invalid)
+        }
+        EntityType::Tag => {
+            submit_with!(state::TagService, state, correction, user, invalid)
+        }
+        EntityType::Event => {
+            submit_with!(state::EventService, state, correction, user, invalid)
+        }
+        EntityType::SongLyrics => {
+            submit_with!(
+                state::SongLyricsService,
+                state,
+                correction,
+                user,
+                invalid
+            )
+        }
+        EntityType::CreditRole => {
+            submit_with!(
+                state::CreditRoleService,
+                state,
+                correction,
+                user,
+                invalid
+            )
+        }
     }
 }
 
@@ -151,8 +514,7 @@ impl From<EntityTypePath> for EntityType {
 }
 
 #[derive(Deserialize, IntoParams)]
-
-struct PendingCorrectionPath {
+struct EntityPath {
     // https://github.com/scalar/scalar/issues/4309
     // External Bug: Not shown in docs if not inline
     // TODO: remove inline after bug fix
@@ -165,7 +527,7 @@ struct PendingCorrectionPath {
 	get,
     tag = TAG,
 	path = "/{entity_type}/{id}/pending-correction",
-    params(PendingCorrectionPath),
+    params(EntityPath),
 	responses(
 		(status = 200, body = Data<Option<i32>>),
 		(status = 401),
@@ -174,9 +536,7 @@ struct PendingCorrectionPath {
 )]
 async fn pending_correction(
     CurrentUser(_user): CurrentUser,
-    Path(PendingCorrectionPath { entity_type, id }): Path<
-        PendingCorrectionPath,
-    >,
+    Path(EntityPath { entity_type, id }): Path<EntityPath>,
     State(repo): State<state::SeaOrmRepository>,
 ) -> Result<Data<Option<i32>>, Error> {
     Ok(correction::Repo::find_one(
@@ -187,3 +547,95 @@ async fn pending_correction(
     .map(|x| x.id)
     .into())
 }
+
+#[utoipa::path(
+	get,
+    tag = TAG,
+	path = "/{entity_type}/{id}/latest-history",
+    params(EntityPath),
+	responses(
+		(status = 200, body = Data<Option<i32>>),
+		Error
+	),
+)]
+async fn latest_history(
+    Path(EntityPath { entity_type, id }): Path<EntityPath>,
+    State(repo): State<state::SeaOrmRepository>,
+) -> Result<Data<Option<i32>>, Error> {
+    Ok(
+        correction::Repo::find_current_history_id(
+            &repo,
+            entity_type.into(),
+            id,
+        )
+        .await?
+        .into(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/{entity_type}/{id}/protection",
+    params(EntityPath),
+    responses(
+        (status = 200, body = DataOptionEntityProtection),
+        Error
+    ),
+)]
+async fn find_entity_protection(
+    Path(EntityPath { entity_type, id }): Path<EntityPath>,
+    State(service): State<state::CorrectionService>,
+) -> Result<Data<Option<EntityProtection>>, Error> {
+    service
+        .find_protection(entity_type.into(), id)
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    put,
+    tag = TAG,
+    path = "/{entity_type}/{id}/protection",
+    params(EntityPath),
+    request_body = NewEntityProtection,
+    responses(
+        (status = 200, body = DataEntityProtection),
+        (status = 401),
+        ProtectionError
+    ),
+)]
+async fn set_entity_protection(
+    CurrentUser(user): CurrentUser,
+    Path(EntityPath { entity_type, id }): Path<EntityPath>,
+    State(service): State<state::CorrectionService>,
+    Json(protection): Json<NewEntityProtection>,
+) -> Result<Data<EntityProtection>, ProtectionError> {
+    service
+        .set_protection(&user, entity_type.into(), id, protection)
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/{entity_type}/{id}/protection",
+    params(EntityPath),
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        ProtectionError
+    ),
+)]
+async fn delete_entity_protection(
+    CurrentUser(user): CurrentUser,
+    Path(EntityPath { entity_type, id }): Path<EntityPath>,
+    State(service): State<state::CorrectionService>,
+) -> Result<Message, ProtectionError> {
+    service
+        .delete_protection(&user, entity_type.into(), id)
+        .await?;
+
+    Ok(Message::ok())
+}
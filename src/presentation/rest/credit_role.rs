@@ -6,6 +6,7 @@ use utoipa::IntoParams;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+use super::AsOfQuery;
 use super::extract::CurrentUser;
 use crate::application::correction::NewCorrectionDto;
 use crate::application::credit_role::{CreateError, UpsertCorrectionError};
@@ -21,6 +22,7 @@ const TAG: &str = "Credit Role";
 pub fn router() -> OpenApiRouter<ArcAppState> {
     OpenApiRouter::new()
         .routes(routes!(find_many_credit_roles_summary))
+        .routes(routes!(find_credit_role_tree))
         .routes(routes!(find_credit_role_by_id))
         .routes(routes!(create_credit_role))
         .routes(routes!(upsert_credit_role_correction))
@@ -29,6 +31,7 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
 super::data! {
     DataVecCreditRoleSummary, Vec<CreditRoleSummary>
     DataOptionCreditRole, Option<CreditRole>
+    DataCreditRoleTree, CreditRoleTree
 }
 
 #[derive(Deserialize, IntoParams)]
@@ -65,12 +68,28 @@ async fn find_many_credit_roles_summary(
         .bimap_into()
 }
 
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/credit-role/tree",
+    responses(
+        (status = 200, body = DataCreditRoleTree),
+        Error
+    ),
+)]
+async fn find_credit_role_tree(
+    State(service): State<CreditRoleService>,
+) -> Result<Data<CreditRoleTree>, Error> {
+    service.find_tree().await.bimap_into()
+}
+
 #[utoipa::path(
     get,
     tag = TAG,
     path = "/credit-role/{id}",
     params(
-        CommonFilter
+        CommonFilter,
+        AsOfQuery
     ),
     responses(
         (status = 200, body = DataOptionCreditRole),
@@ -83,11 +102,15 @@ async fn find_credit_role_by_id(
     axum_extra::extract::Query(common): axum_extra::extract::Query<
         CommonFilter,
     >,
+    Query(query): Query<AsOfQuery>,
 ) -> Result<Data<Option<CreditRole>>, Error> {
-    service
-        .find_one::<query_kind::Full>(id, common)
-        .await
-        .bimap_into()
+    match query.at() {
+        Some(at) => service.find_as_of(id, at).await.bimap_into(),
+        None => service
+            .find_one::<query_kind::Full>(id, common)
+            .await
+            .bimap_into(),
+    }
 }
 
 #[utoipa::path(
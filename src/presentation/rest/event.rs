@@ -8,6 +8,7 @@ use utoipa::IntoParams;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+use super::AsOfQuery;
 use super::extract::CurrentUser;
 use super::state::{
     ArcAppState, {self},
@@ -39,6 +40,7 @@ super::data! {
     get,
     tag = TAG,
     path = "/event/{id}",
+    params(AsOfQuery),
     responses(
         (status = 200, body = Data<Event>),
         ApiError
@@ -47,8 +49,14 @@ super::data! {
 async fn find_event_by_id(
     State(service): State<state::EventService>,
     Path(id): Path<i32>,
+    Query(query): Query<AsOfQuery>,
 ) -> Result<Data<Option<Event>>, ApiError> {
-    service.find_by_id(id).await?.pipe(Data::new).pipe(Ok)
+    match query.at() {
+        Some(at) => service.find_as_of(id, at).await,
+        None => service.find_by_id(id).await,
+    }?
+    .pipe(Data::new)
+    .pipe(Ok)
 }
 
 #[derive(Deserialize, IntoParams)]
@@ -6,6 +6,7 @@ use axum::response::IntoResponse;
 
 mod auth;
 pub use auth::CurrentUser;
+mod client;
 mod json;
 
 #[trait_variant::make(Send)]
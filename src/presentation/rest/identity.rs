@@ -0,0 +1,195 @@
+use axum::extract::{Path, Query, State};
+use axum::response::{IntoResponse, Redirect, Response};
+use chrono::Utc;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, AuthSession, {self},
+};
+use super::user::{complete_sign_in, require_two_factor, session_error};
+use crate::application::auth::{AuthServiceTrait, SignInOutcome};
+use crate::application::identity::{CallbackOutcome, Error};
+use crate::domain::identity::{
+    OidcProvider, PendingAuthorization, UserIdentity,
+};
+use crate::domain::two_factor::PendingSignIn;
+use crate::domain::user::{SessionClient, UserProfile};
+use crate::infra::session::RedisSessionRepo;
+use crate::presentation::api_response::{Data, IntoApiResponse, Message};
+
+const TAG: &str = "Identity";
+
+const PENDING_AUTHORIZATION_KEY: &str = "pending_authorization";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(providers))
+        .routes(routes!(authorize))
+        .routes(routes!(callback))
+        .routes(routes!(identities))
+        .routes(routes!(unlink))
+}
+
+super::data! {
+    DataVecOidcProvider, Vec<OidcProvider>
+    DataVecUserIdentity, Vec<UserIdentity>
+    DataUserProfile, UserProfile
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/oidc/providers",
+    responses(
+        (status = 200, body = DataVecOidcProvider),
+    ),
+)]
+async fn providers(
+    State(service): State<state::IdentityService>,
+) -> Data<Vec<OidcProvider>> {
+    service.providers().into()
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/oidc/{provider}/authorize",
+    responses(
+        (status = 303, description = "Redirect to the identity provider"),
+        Error
+    ),
+)]
+async fn authorize(
+    auth_session: AuthSession,
+    State(service): State<state::IdentityService>,
+    Path(provider): Path<String>,
+) -> Result<Redirect, Response> {
+    let request = service
+        .authorize(&provider, auth_session.user.as_ref())
+        .await
+        .map_err(IntoApiResponse::into_api_response)?;
+
+    auth_session
+        .session
+        .insert(PENDING_AUTHORIZATION_KEY, &request.pending)
+        .await
+        .map_err(session_error)?;
+
+    Ok(Redirect::to(request.url.as_str()))
+}
+
+#[derive(Deserialize, IntoParams)]
+struct CallbackQuery {
+    code: String,
+    state: String,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/oidc/{provider}/callback",
+    params(CallbackQuery),
+    responses(
+        (status = 200, body = DataUserProfile),
+        Error
+    ),
+)]
+async fn callback(
+    mut auth_session: AuthSession,
+    State(service): State<state::IdentityService>,
+    State(use_case): State<state::UserProfileService>,
+    State(sessions): State<RedisSessionRepo>,
+    client: SessionClient,
+    Path(provider): Path<String>,
+    Query(query): Query<CallbackQuery>,
+) -> Result<Response, Response> {
+    // Each authorization can only be used once
+    let pending = auth_session
+        .session
+        .remove::<PendingAuthorization>(PENDING_AUTHORIZATION_KEY)
+        .await
+        .map_err(session_error)?
+        .ok_or_else(|| Error::InvalidState.into_api_response())?;
+
+    let outcome = service
+        .callback(
+            pending,
+            auth_session.user.as_ref(),
+            &provider,
+            &query.state,
+            query.code,
+        )
+        .await
+        .map_err(IntoApiResponse::into_api_response)?;
+
+    let user = match outcome {
+        CallbackOutcome::Linked => return Ok(Message::ok().into_response()),
+        CallbackOutcome::SignIn(user) => user,
+    };
+
+    let user = match auth_session
+        .backend
+        .sign_in_with_identity(user)
+        .await
+        .map_err(IntoApiResponse::into_api_response)?
+    {
+        SignInOutcome::Complete(user) => user,
+        SignInOutcome::TwoFactorRequired { user_id, username } => {
+            return Err(require_two_factor(
+                &auth_session,
+                PendingSignIn::new(
+                    user_id,
+                    username,
+                    client.ip.clone(),
+                    Utc::now(),
+                ),
+            )
+            .await);
+        }
+    };
+
+    complete_sign_in(&mut auth_session, &use_case, &sessions, user, client)
+        .await
+        .map(IntoResponse::into_response)
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/identities",
+    responses(
+        (status = 200, body = DataVecUserIdentity),
+        (status = 401),
+        Error
+    ),
+)]
+async fn identities(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::IdentityService>,
+) -> Result<Data<Vec<UserIdentity>>, Error> {
+    service.find_identities(&user).await.map(Into::into)
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/identities/{id}",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn unlink(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::IdentityService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.unlink(&user, id).await?;
+
+    Ok(Message::ok())
+}
@@ -0,0 +1,100 @@
+use axum::extract::{Path, State};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::application::image_queue::Error;
+use crate::presentation::api_response::Message;
+
+const TAG: &str = "Image Queue";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(approve_image))
+        .routes(routes!(reject_image))
+        .routes(routes!(revert_image))
+        .routes(routes!(cancel_image))
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/image-queue/{id}/approve",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn approve_image(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ImageQueueService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.approve(id, &user).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/image-queue/{id}/reject",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn reject_image(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ImageQueueService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.reject(id, &user).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/image-queue/{id}/revert",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn revert_image(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ImageQueueService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.revert(id, &user).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/image-queue/{id}/cancel",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn cancel_image(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ImageQueueService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.cancel(id, &user).await?;
+
+    Ok(Message::ok())
+}
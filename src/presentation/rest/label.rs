@@ -7,8 +7,8 @@ use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
 use super::extract::CurrentUser;
-use super::state;
 use super::state::ArcAppState;
+use super::{AsOfQuery, state};
 use crate::application::correction::NewCorrectionDto;
 use crate::application::label::{CreateError, UpsertCorrectionError};
 use crate::domain::label::{Label, NewLabel};
@@ -34,6 +34,7 @@ super::data! {
     get,
     tag = TAG,
     path = "/label/{id}",
+    params(AsOfQuery),
     responses(
         (status = 200, body = DataOptionLabel),
         (status = 401),
@@ -43,8 +44,12 @@ super::data! {
 async fn find_label_by_id(
     label_service: State<state::LabelService>,
     Path(id): Path<i32>,
+    Query(query): Query<AsOfQuery>,
 ) -> Result<Data<Option<Label>>, Error> {
-    label_service.find_by_id(id).await.bimap_into()
+    match query.at() {
+        Some(at) => label_service.find_as_of(id, at).await.bimap_into(),
+        None => label_service.find_by_id(id).await.bimap_into(),
+    }
 }
 
 #[derive(IntoParams, Deserialize)]
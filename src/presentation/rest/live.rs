@@ -0,0 +1,126 @@
+use std::convert::Infallible;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum_login::AuthnBackend;
+use futures_util::Stream;
+use futures_util::stream::unfold;
+use tokio::sync::broadcast::error::RecvError;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{self, ArcAppState, AuthSession};
+use crate::domain::live::LiveEvent;
+use crate::domain::user::{SessionRepo, User};
+use crate::infra::live::LiveHub;
+use crate::infra::session::RedisSessionRepo;
+
+const TAG: &str = "Live";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new().routes(routes!(live_events))
+}
+
+/// The user the stream was opened by, reloaded before each event so that
+/// permissions, suspensions and revoked sessions take effect on open
+/// streams
+struct Subscriber {
+    user: User,
+    session_id: String,
+    auth: state::AuthService,
+    sessions: RedisSessionRepo,
+}
+
+impl Subscriber {
+    /// `false` once the stream should be closed
+    async fn refresh(&mut self) -> bool {
+        match self.sessions.is_signed_in(self.session_id.clone()).await {
+            Ok(true) => {}
+            Ok(false) => return false,
+            Err(err) => {
+                tracing::warn!("Failed to check live session: {err}");
+                return false;
+            }
+        }
+
+        match self.auth.get_user(&self.user.id).await {
+            Ok(Some(user)) => {
+                self.user = user;
+                true
+            }
+            Ok(None) => false,
+            Err(err) => {
+                tracing::warn!("Failed to reload live user: {err}");
+                false
+            }
+        }
+    }
+}
+
+/// Server-sent events named after the type of the event. A `lagged` event
+/// means some events were missed and the client should refetch. The stream
+/// ends once the session is signed out or the user is suspended
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/live",
+    responses(
+        (status = 200, body = LiveEvent, content_type = "text/event-stream"),
+        (status = 401),
+    ),
+)]
+async fn live_events(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(hub): State<LiveHub>,
+    State(auth): State<state::AuthService>,
+    State(sessions): State<RedisSessionRepo>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, StatusCode> {
+    let session_id = auth_session
+        .session
+        .id()
+        .ok_or(StatusCode::UNAUTHORIZED)?
+        .to_string();
+
+    let subscriber = Subscriber {
+        user,
+        session_id,
+        auth,
+        sessions,
+    };
+
+    let stream = unfold(
+        (hub.subscribe(), subscriber),
+        |(mut rx, mut subscriber)| async move {
+            loop {
+                let event = match rx.recv().await {
+                    Ok(event) if event.is_for(&subscriber.user) => {
+                        if !subscriber.refresh().await {
+                            return None;
+                        }
+
+                        if !event.is_for(&subscriber.user) {
+                            continue;
+                        }
+
+                        Event::default()
+                            .event(event.name())
+                            .json_data(&event)
+                            .unwrap_or_else(|_| Event::default().event("error"))
+                    }
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(_)) => {
+                        Event::default().event("lagged")
+                    }
+                    Err(RecvError::Closed) => return None,
+                };
+
+                return Some((Ok(event), (rx, subscriber)));
+            }
+        },
+    );
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
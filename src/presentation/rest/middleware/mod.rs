@@ -2,11 +2,13 @@ use std::convert::Infallible;
 use std::env;
 use std::sync::Arc;
 
-use axum::extract::{FromRef, Request};
-use axum::response::IntoResponse;
+use axum::extract::{FromRef, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
 use axum::routing::Route;
 use axum::{Router, http};
 use axum_login::AuthManagerLayerBuilder;
+use axum_login::tower_sessions::cookie::SameSite;
 use axum_login::tower_sessions::cookie::time::Duration;
 use axum_login::tower_sessions::{Expiry, SessionManagerLayer};
 use governor::clock::QuantaInstant;
@@ -17,8 +19,10 @@ use tower_http::cors::{Any, CorsLayer};
 use tower_sessions_redis_store::RedisStore;
 
 use super::state::{
-    ArcAppState, {self},
+    ArcAppState, AuthSession, {self},
 };
+use crate::domain::user::SessionRepo;
+use crate::infra::session::{RedisSessionRepo, SESSION_EXPIRY_DAYS};
 use crate::infra::singleton::APP_CONFIG;
 
 pub trait AxumLayerBounds = where
@@ -43,6 +47,10 @@ where
         .call();
 
     router
+        .layer(axum::middleware::from_fn_with_state(
+            RedisSessionRepo::from_ref(state),
+            touch_session,
+        ))
         .layer(auth_layer(state))
         .layer(limit_layer)
         .layer(cors_layer())
@@ -54,7 +62,10 @@ fn auth_layer(state: &ArcAppState) -> impl AxumLayerBounds {
 
     let session_layer = SessionManagerLayer::new(session_store)
         .with_name("session_token")
-        .with_expiry(Expiry::OnInactivity(Duration::days(30)))
+        .with_expiry(Expiry::OnInactivity(Duration::days(SESSION_EXPIRY_DAYS)))
+        // Identity providers redirect back with a top-level navigation from
+        // their site, a strict cookie wouldn't be sent with it
+        .with_same_site(SameSite::Lax)
         .with_secure(
             env::var("SESSION_SECURE")
                 .as_deref()
@@ -69,6 +80,27 @@ fn auth_layer(state: &ArcAppState) -> impl AxumLayerBounds {
     .build()
 }
 
+/// Records when the sessions of signed in users were last seen
+async fn touch_session(
+    State(sessions): State<RedisSessionRepo>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let session_id = request
+        .extensions()
+        .get::<AuthSession>()
+        .filter(|auth_session| auth_session.user.is_some())
+        .and_then(|auth_session| auth_session.session.id());
+
+    if let Some(id) = session_id
+        && let Err(err) = sessions.touch_session(id.to_string()).await
+    {
+        tracing::warn!("Failed to update session last seen: {err}");
+    }
+
+    next.run(request).await
+}
+
 fn cors_layer() -> CorsLayer {
     use http::Method;
 
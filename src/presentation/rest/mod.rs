@@ -7,14 +7,16 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::get;
 use axum::{Json, Router};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime};
 use flow::{Pipe, TapMut};
 use maud::{DOCTYPE, html};
 use middleware::append_global_middlewares;
+use serde::Deserialize;
 use state::{ArcAppState, AuthSession};
 use tokio::net::TcpListener;
 use tokio::signal;
 use tower_http::services::ServeDir;
-use utoipa::OpenApi;
+use utoipa::{IntoParams, OpenApi};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 use utoipa_scalar::{Scalar, Servable};
@@ -24,20 +26,31 @@ use crate::constant::{IMAGE_DIR, PUBLIC_DIR};
 use crate::domain::artist::CommonFilter as ArtistCommonFilter;
 use crate::infra::state::AppState;
 
+mod account;
+mod admin;
 mod artist;
+mod contribution;
 mod correction;
 mod credit_role;
 mod enum_table;
 mod event;
 mod extract;
+mod identity;
+mod image_queue;
 mod label;
+mod live;
 mod middleware;
+mod notification;
 mod release;
+mod report;
+mod session;
 mod song;
 mod song_lyrics;
 mod state;
 mod tag;
+mod two_factor;
 mod user;
+mod webhook;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -84,16 +97,27 @@ pub async fn listen(
 
 fn router(state: ArcAppState) -> Router {
     let api_router = OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .merge(account::router())
+        .merge(admin::router())
         .merge(artist::router())
+        .merge(contribution::router())
         .merge(correction::router())
         .merge(event::router())
+        .merge(identity::router())
+        .merge(image_queue::router())
         .merge(label::router())
+        .merge(live::router())
         .merge(enum_table::router())
+        .merge(notification::router())
         .merge(release::router())
+        .merge(report::router())
+        .merge(session::router())
         .merge(song::router())
         .merge(song_lyrics::router())
         .merge(tag::router())
+        .merge(two_factor::router())
         .merge(user::router())
+        .merge(webhook::router())
         .merge(credit_role::router())
         .routes(routes!(health_check));
 
@@ -179,6 +203,22 @@ macro_rules! data {
 }
 use data;
 
+/// Reads the entity as it was in the past, from the history of its approved
+/// corrections
+#[derive(Deserialize, IntoParams)]
+struct AsOfQuery {
+    /// The entity as it was at the end of the day (UTC)
+    as_of: Option<NaiveDate>,
+}
+
+impl AsOfQuery {
+    fn at(&self) -> Option<DateTime<FixedOffset>> {
+        self.as_of
+            .and_then(|date| date.succ_opt())
+            .map(|date| date.and_time(NaiveTime::MIN).and_utc().fixed_offset())
+    }
+}
+
 macro_rules! router_new {
     () => {};
     ($($name:ident),*) => {
@@ -0,0 +1,167 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::application::notification::Error;
+use crate::domain::notification::{
+    Notification, NotificationFilter, NotificationPreference,
+};
+use crate::domain::repository::{Cursor, Paginated};
+use crate::presentation::api_response::{Data, Message};
+
+const TAG: &str = "Notification";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(find_notifications))
+        .routes(routes!(count_unread_notifications))
+        .routes(routes!(mark_notification_read))
+        .routes(routes!(mark_all_notifications_read))
+        .routes(routes!(
+            find_notification_preferences,
+            update_notification_preferences
+        ))
+}
+
+super::data! {
+    DataPaginatedNotification, Paginated<Notification>
+    DataU64, u64
+    DataVecNotificationPreference, Vec<NotificationPreference>
+}
+
+#[derive(Deserialize, IntoParams)]
+struct NotificationQuery {
+    /// The id of the last notification of the previous page, 0 for the newest
+    cursor: u32,
+    limit: u8,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/notifications",
+    params(NotificationQuery, NotificationFilter),
+    responses(
+        (status = 200, body = DataPaginatedNotification),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_notifications(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::NotificationService>,
+    Query(query): Query<NotificationQuery>,
+    Query(filter): Query<NotificationFilter>,
+) -> Result<Data<Paginated<Notification>>, Error> {
+    let pagination = Cursor {
+        at: query.cursor,
+        limit: query.limit,
+    };
+
+    service
+        .find(&user, filter, pagination)
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/notifications/unread-count",
+    responses(
+        (status = 200, body = DataU64),
+        (status = 401),
+        Error
+    ),
+)]
+async fn count_unread_notifications(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::NotificationService>,
+) -> Result<Data<u64>, Error> {
+    service.count_unread(&user).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/notifications/{id}/read",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn mark_notification_read(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::NotificationService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.mark_read(&user, id).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/notifications/read",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn mark_all_notifications_read(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::NotificationService>,
+) -> Result<Message, Error> {
+    service.mark_all_read(&user).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/notifications/preferences",
+    responses(
+        (status = 200, body = DataVecNotificationPreference),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_notification_preferences(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::NotificationService>,
+) -> Result<Data<Vec<NotificationPreference>>, Error> {
+    service.find_preferences(&user).await.map(Into::into)
+}
+
+#[utoipa::path(
+    put,
+    tag = TAG,
+    path = "/notifications/preferences",
+    request_body = Vec<NotificationPreference>,
+    responses(
+        (status = 200, body = DataVecNotificationPreference),
+        (status = 401),
+        Error
+    ),
+)]
+async fn update_notification_preferences(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::NotificationService>,
+    Json(preferences): Json<Vec<NotificationPreference>>,
+) -> Result<Data<Vec<NotificationPreference>>, Error> {
+    service
+        .update_preferences(&user, preferences)
+        .await
+        .map(Into::into)
+}
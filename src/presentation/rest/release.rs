@@ -8,6 +8,7 @@ use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+use super::AsOfQuery;
 use super::extract::CurrentUser;
 use super::state::{
     ArcAppState, {self},
@@ -18,6 +19,7 @@ use crate::application::release::{CreateError, UpsertCorrectionError};
 use crate::application::release_image::ReleaseCoverArtInput;
 use crate::domain::release::repo::Filter;
 use crate::domain::release::{NewRelease, Release};
+use crate::domain::repository::{Cursor, Paginated};
 use crate::infra::error::Error;
 use crate::presentation::api_response::{Data, Message};
 
@@ -30,6 +32,7 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
         .routes(routes!(create_release))
         .routes(routes!(update_release))
         .routes(routes!(find_release_by_keyword))
+        .routes(routes!(find_release_by_credit))
         .routes(routes!(find_release_by_id))
         .routes(routes!(upload_release_cover_art))
 }
@@ -37,12 +40,14 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
 super::data! {
     DataOptionRelease, Option<Release>
     DataVecRelease, Vec<Release>
+    DataPaginatedRelease, Paginated<Release>
 }
 
 #[utoipa::path(
     get,
     tag = TAG,
     path = "/release/{id}",
+    params(AsOfQuery),
     responses(
 		(status = 200, body = DataOptionRelease),
 		Error,
@@ -51,8 +56,12 @@ super::data! {
 async fn find_release_by_id(
     State(service): State<Service>,
     Path(id): Path<i32>,
+    Query(query): Query<AsOfQuery>,
 ) -> Result<Data<Option<Release>>, Error> {
-    service.find_one(Filter::Id(id)).await.bimap_into()
+    match query.at() {
+        Some(at) => service.find_as_of(id, at).await.bimap_into(),
+        None => service.find_one(Filter::Id(id)).await.bimap_into(),
+    }
 }
 
 #[derive(IntoParams, Deserialize)]
@@ -80,6 +89,40 @@ async fn find_release_by_keyword(
         .bimap_into()
 }
 
+#[derive(IntoParams, Deserialize)]
+struct CreditQuery {
+    /// Also matches credits of any sub role
+    role_id: i32,
+    artist_id: Option<i32>,
+    cursor: u32,
+    limit: u8,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/release/credit",
+    params(CreditQuery),
+    responses(
+		(status = 200, body = DataPaginatedRelease),
+		Error,
+    ),
+)]
+async fn find_release_by_credit(
+    State(service): State<Service>,
+    Query(query): Query<CreditQuery>,
+) -> Result<Data<Paginated<Release>>, Error> {
+    let pagination = Cursor {
+        at: query.cursor,
+        limit: query.limit,
+    };
+
+    service
+        .find_by_credit(query.role_id, query.artist_id, pagination)
+        .await
+        .bimap_into()
+}
+
 #[derive(IntoParams)]
 struct RandomReleaseQuery {
     count: u64,
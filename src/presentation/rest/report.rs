@@ -0,0 +1,161 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::application::report::{DismissReport, Error, ResolveReport};
+use crate::domain::report::{NewReport, Report, ReportFilter};
+use crate::domain::repository::{Cursor, Paginated};
+use crate::presentation::api_response::Data;
+
+const TAG: &str = "Report";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_report))
+        .routes(routes!(find_report_queue))
+        .routes(routes!(find_report_by_id))
+        .routes(routes!(claim_report))
+        .routes(routes!(resolve_report))
+        .routes(routes!(dismiss_report))
+}
+
+super::data! {
+    DataReport, Report
+    DataPaginatedReport, Paginated<Report>
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/report",
+    request_body = NewReport,
+    responses(
+        (status = 200, body = DataReport),
+        (status = 401),
+        Error
+    ),
+)]
+async fn create_report(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReportService>,
+    Json(report): Json<NewReport>,
+) -> Result<Data<Report>, Error> {
+    service.create(&user, report).await.map(Into::into)
+}
+
+#[derive(Deserialize, IntoParams)]
+struct ReportQueueQuery {
+    cursor: u32,
+    limit: u8,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/report",
+    params(ReportQueueQuery, ReportFilter),
+    responses(
+        (status = 200, body = DataPaginatedReport),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_report_queue(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReportService>,
+    Query(query): Query<ReportQueueQuery>,
+    Query(filter): Query<ReportFilter>,
+) -> Result<Data<Paginated<Report>>, Error> {
+    let pagination = Cursor {
+        at: query.cursor,
+        limit: query.limit,
+    };
+
+    service
+        .find_many(&user, filter, pagination)
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/report/{id}",
+    responses(
+        (status = 200, body = DataReport),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_report_by_id(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReportService>,
+    Path(id): Path<i32>,
+) -> Result<Data<Report>, Error> {
+    service.find_by_id(&user, id).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/report/{id}/claim",
+    responses(
+        (status = 200, body = DataReport),
+        (status = 401),
+        Error
+    ),
+)]
+async fn claim_report(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReportService>,
+    Path(id): Path<i32>,
+) -> Result<Data<Report>, Error> {
+    service.claim(&user, id).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/report/{id}/resolve",
+    request_body = ResolveReport,
+    responses(
+        (status = 200, body = DataReport),
+        (status = 401),
+        Error
+    ),
+)]
+async fn resolve_report(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReportService>,
+    Path(id): Path<i32>,
+    Json(resolution): Json<ResolveReport>,
+) -> Result<Data<Report>, Error> {
+    service.resolve(&user, id, resolution).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/report/{id}/dismiss",
+    request_body = DismissReport,
+    responses(
+        (status = 200, body = DataReport),
+        (status = 401),
+        Error
+    ),
+)]
+async fn dismiss_report(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReportService>,
+    Path(id): Path<i32>,
+    Json(dismissal): Json<DismissReport>,
+) -> Result<Data<Report>, Error> {
+    service.dismiss(&user, id, dismissal).await.map(Into::into)
+}
@@ -0,0 +1,93 @@
+use axum::extract::{Path, State};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, AuthSession, {self},
+};
+use crate::application::session::Error;
+use crate::domain::user::UserSession;
+use crate::presentation::api_response::{Data, Message};
+
+const TAG: &str = "Session";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(find_sessions, revoke_other_sessions))
+        .routes(routes!(revoke_session))
+}
+
+super::data! {
+    DataVecUserSession, Vec<UserSession>
+}
+
+fn current_session_id(auth_session: &AuthSession) -> String {
+    auth_session
+        .session
+        .id()
+        .map_or_default(|id| id.to_string())
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/sessions",
+    responses(
+        (status = 200, body = DataVecUserSession),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_sessions(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(service): State<state::SessionService>,
+) -> Result<Data<Vec<UserSession>>, Error> {
+    service
+        .find_sessions(&user, current_session_id(&auth_session))
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/sessions",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn revoke_other_sessions(
+    CurrentUser(user): CurrentUser,
+    auth_session: AuthSession,
+    State(service): State<state::SessionService>,
+) -> Result<Message, Error> {
+    service
+        .revoke_others(&user, current_session_id(&auth_session))
+        .await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/sessions/{id}",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn revoke_session(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::SessionService>,
+    Path(id): Path<String>,
+) -> Result<Message, Error> {
+    service.revoke(&user, id).await?;
+
+    Ok(Message::ok())
+}
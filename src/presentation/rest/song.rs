@@ -6,6 +6,7 @@ use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+use super::AsOfQuery;
 use super::extract::CurrentUser;
 use super::state::{
     ArcAppState, {self},
@@ -35,6 +36,7 @@ super::data! {
     get,
     tag = TAG,
     path = "/song/{id}",
+    params(AsOfQuery),
     responses(
 		(status = 200, body = DataOptionSong),
 		Error
@@ -43,8 +45,12 @@ super::data! {
 async fn find_song_by_id(
     State(service): State<state::SongService>,
     Path(id): Path<i32>,
+    Query(query): Query<AsOfQuery>,
 ) -> Result<Data<Option<Song>>, Error> {
-    service.find_by_id(id).await.bimap_into()
+    match query.at() {
+        Some(at) => service.find_as_of(id, at).await.bimap_into(),
+        None => service.find_by_id(id).await.bimap_into(),
+    }
 }
 
 #[derive(Deserialize, ToSchema, IntoParams)]
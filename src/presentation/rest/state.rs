@@ -5,12 +5,18 @@ use axum::extract::FromRef;
 
 use super::extract::TryFromRef;
 use crate::application::{self, user_profile};
+use crate::domain::login_throttle::{LoginThrottle, SystemClock};
 use crate::domain::repository::TransactionManager;
+use crate::infra::contribution::RedisContributionCache;
 pub(super) use crate::infra::database::sea_orm::{
     SeaOrmRepository, SeaOrmTxRepo,
 };
 use crate::infra::error::Error;
-use crate::infra::singleton::FS_IMAGE_BASE_PATH;
+use crate::infra::live::LiveHub;
+use crate::infra::login_throttle::RedisLoginThrottleRepo;
+use crate::infra::oidc::OidcClient;
+use crate::infra::session::RedisSessionRepo;
+use crate::infra::singleton::{APP_CONFIG, FS_IMAGE_BASE_PATH};
 use crate::infra::state::AppState;
 use crate::infra::storage::{GenericFileStorage, GenericFileStorageConfig};
 
@@ -31,7 +37,39 @@ impl ArcAppState {
     }
 }
 
-pub(super) type AuthService = application::auth::AuthService<SeaOrmRepository>;
+pub(super) type AccountService = application::account::Service<
+    SeaOrmRepository,
+    GenericFileStorage,
+    RedisSessionRepo,
+>;
+
+impl FromRef<ArcAppState> for AccountService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+            storage: GenericFileStorage::new(GenericFileStorageConfig {
+                fs_base_path: FS_IMAGE_BASE_PATH.to_path_buf(),
+                redis_pool: input.redis_pool(),
+            }),
+            sessions: RedisSessionRepo::from_ref(input),
+        }
+    }
+}
+
+pub(super) type AdminService =
+    application::admin::Service<SeaOrmRepository, RedisSessionRepo>;
+
+impl FromRef<ArcAppState> for AdminService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+            sessions: RedisSessionRepo::from_ref(input),
+        }
+    }
+}
+
+pub(super) type AuthService =
+    application::auth::AuthService<SeaOrmRepository, RedisLoginThrottleRepo>;
 
 pub(super) type AuthSession = axum_login::AuthSession<AuthService>;
 
@@ -41,23 +79,48 @@ pub(super) type ArtistImageService =
 pub(super) type ReleaseImageService =
     application::release_image::Service<SeaOrmRepository, GenericFileStorage>;
 
+pub(super) type ContributionService = application::contribution::Service<
+    SeaOrmRepository,
+    RedisContributionCache,
+>;
+
+impl FromRef<ArcAppState> for ContributionService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+            cache: RedisContributionCache::new(input.redis_pool()),
+        }
+    }
+}
+
 pub(super) type CorrectionService =
     application::correction::Service<SeaOrmRepository>;
 
 impl FromRef<ArcAppState> for CorrectionService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self::new(input.sea_orm_repo.clone(), input.correction_policy.clone())
+    }
+}
+
+pub(super) type EventService = application::event::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for EventService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
         }
     }
 }
 
-pub(super) type EventService = application::event::Service<SeaOrmRepository>;
+pub(super) type IdentityService =
+    application::identity::Service<SeaOrmRepository, OidcClient>;
 
-impl FromRef<ArcAppState> for EventService {
+impl FromRef<ArcAppState> for IdentityService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
+            provider: input.oidc.clone(),
         }
     }
 }
@@ -65,9 +128,32 @@ impl FromRef<ArcAppState> for EventService {
 pub(super) type ImageService =
     crate::domain::image::Service<SeaOrmTxRepo, GenericFileStorage>;
 
+pub(super) type ImageQueueService =
+    application::image_queue::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for ImageQueueService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+        }
+    }
+}
+
 pub(super) type LabelService = application::label::Service<SeaOrmRepository>;
 
 impl FromRef<ArcAppState> for LabelService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
+        }
+    }
+}
+
+pub(super) type NotificationService =
+    application::notification::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for NotificationService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
@@ -82,6 +168,18 @@ impl FromRef<ArcAppState> for ReleaseService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
+        }
+    }
+}
+
+pub(super) type ReportService = application::report::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for ReportService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+            policy: input.report_policy,
         }
     }
 }
@@ -92,6 +190,7 @@ impl FromRef<ArcAppState> for SongService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
         }
     }
 }
@@ -103,6 +202,7 @@ impl FromRef<ArcAppState> for SongLyricsService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
         }
     }
 }
@@ -113,13 +213,39 @@ impl FromRef<ArcAppState> for TagService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
+        }
+    }
+}
+
+pub(super) type TwoFactorService =
+    application::two_factor::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for TwoFactorService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+            policy: input.two_factor_policy.clone(),
         }
     }
 }
 
 pub(super) type UserImageService =
     application::user_image::Service<SeaOrmRepository, GenericFileStorage>;
-pub(super) type UserProfileService = user_profile::Service<SeaOrmRepository>;
+pub(super) type UserProfileService =
+    user_profile::Service<SeaOrmRepository, RedisContributionCache>;
+
+pub(super) type WebhookService =
+    application::webhook::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for WebhookService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+            policy: input.webhook_policy,
+        }
+    }
+}
 
 impl FromRef<ArcAppState> for SeaOrmRepository {
     fn from_ref(input: &ArcAppState) -> Self {
@@ -127,9 +253,35 @@ impl FromRef<ArcAppState> for SeaOrmRepository {
     }
 }
 
+impl FromRef<ArcAppState> for LiveHub {
+    fn from_ref(input: &ArcAppState) -> Self {
+        input.live.clone()
+    }
+}
+
+impl FromRef<ArcAppState> for RedisSessionRepo {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self::new(input.redis_pool())
+    }
+}
+
+pub(super) type SessionService =
+    application::session::Service<RedisSessionRepo>;
+
+impl FromRef<ArcAppState> for SessionService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            sessions: RedisSessionRepo::from_ref(input),
+        }
+    }
+}
+
 impl FromRef<ArcAppState> for UserProfileService {
     fn from_ref(input: &ArcAppState) -> Self {
-        Self::new(input.sea_orm_repo.clone())
+        Self::new(
+            input.sea_orm_repo.clone(),
+            RedisContributionCache::new(input.redis_pool()),
+        )
     }
 }
 
@@ -137,6 +289,7 @@ impl FromRef<ArcAppState> for ArtistService {
     fn from_ref(input: &ArcAppState) -> Self {
         ArtistService {
             conn: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
         }
     }
 }
@@ -162,7 +315,15 @@ impl TryFromRef<ArcAppState> for ImageService {
 
 impl FromRef<ArcAppState> for AuthService {
     fn from_ref(input: &ArcAppState) -> Self {
-        Self::new(input.sea_orm_repo.clone())
+        Self::new(
+            input.sea_orm_repo.clone(),
+            LoginThrottle::new(
+                RedisLoginThrottleRepo::new(input.redis_pool()),
+                SystemClock,
+                APP_CONFIG.login_throttle,
+            ),
+            input.two_factor_policy.clone(),
+        )
     }
 }
 
@@ -217,6 +378,7 @@ impl FromRef<ArcAppState> for CreditRoleService {
     fn from_ref(input: &ArcAppState) -> Self {
         Self {
             repo: input.sea_orm_repo.clone(),
+            correction_policy: input.correction_policy.clone(),
         }
     }
 }
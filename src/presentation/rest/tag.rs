@@ -1,11 +1,13 @@
 use axum::Json;
 use axum::extract::{Path, Query, State};
+use entity::sea_orm_active_enums::TagType;
 use libfp::BifunctorExt;
 use serde::Deserialize;
 use utoipa::IntoParams;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+use super::AsOfQuery;
 use super::extract::CurrentUser;
 use super::state::{
     ArcAppState, {self},
@@ -13,7 +15,7 @@ use super::state::{
 use crate::application::correction::NewCorrectionDto;
 use crate::application::tag::{CreateError, UpsertCorrectionError};
 use crate::domain::tag::NewTag;
-use crate::domain::tag::model::Tag;
+use crate::domain::tag::model::{Tag, TagTree};
 use crate::infra::error::Error;
 use crate::presentation::api_response::{
     Data, {self},
@@ -27,17 +29,23 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
         .routes(routes!(upsert_tag_correction))
         .routes(routes!(find_tag_by_id))
         .routes(routes!(find_tag_by_keyword))
+        .routes(routes!(find_tag_ancestors))
+        .routes(routes!(find_tag_descendants))
+        .routes(routes!(find_tag_tree))
 }
 
 super::data! {
     DataOptionTag, Option<Tag>
     DataVecTag, Vec<Tag>
+    DataOptionTagTree, Option<TagTree>
+    DataTagTree, TagTree
 }
 
 #[utoipa::path(
     get,
     tag = TAG,
     path = "/tag/{id}",
+    params(AsOfQuery),
     responses(
 		(status = 200, body = DataOptionTag),
 		(status = 401),
@@ -47,8 +55,12 @@ super::data! {
 async fn find_tag_by_id(
     State(tag_service): State<state::TagService>,
     Path(id): Path<i32>,
+    Query(query): Query<AsOfQuery>,
 ) -> Result<Data<Option<Tag>>, Error> {
-    tag_service.find_by_id(id).await.bimap_into()
+    match query.at() {
+        Some(at) => tag_service.find_as_of(id, at).await.bimap_into(),
+        None => tag_service.find_by_id(id).await.bimap_into(),
+    }
 }
 
 #[derive(IntoParams, Deserialize)]
@@ -77,6 +89,61 @@ async fn find_tag_by_keyword(
         .bimap_into()
 }
 
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/tag/{id}/ancestors",
+    responses(
+		(status = 200, body = DataOptionTagTree),
+        Error
+    ),
+)]
+async fn find_tag_ancestors(
+    State(tag_service): State<state::TagService>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<TagTree>>, Error> {
+    tag_service.find_ancestors(id).await.bimap_into()
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/tag/{id}/descendants",
+    responses(
+		(status = 200, body = DataOptionTagTree),
+        Error
+    ),
+)]
+async fn find_tag_descendants(
+    State(tag_service): State<state::TagService>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<TagTree>>, Error> {
+    tag_service.find_descendants(id).await.bimap_into()
+}
+
+#[derive(IntoParams, Deserialize)]
+struct TreeQuery {
+    #[param(inline)]
+    r#type: TagType,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/tag/tree",
+    params(TreeQuery),
+    responses(
+		(status = 200, body = DataTagTree),
+        Error
+    ),
+)]
+async fn find_tag_tree(
+    State(tag_service): State<state::TagService>,
+    Query(query): Query<TreeQuery>,
+) -> Result<Data<TagTree>, Error> {
+    tag_service.find_tree(query.r#type).await.bimap_into()
+}
+
 #[utoipa::path(
     post,
     path = "/tag",
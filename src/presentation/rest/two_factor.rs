@@ -0,0 +1,104 @@
+use axum::Json;
+use axum::extract::State;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::application::two_factor::Error;
+use crate::domain::two_factor::{RecoveryCodes, TotpEnrollment, TwoFactorCode};
+use crate::presentation::api_response::{Data, Message};
+
+const TAG: &str = "Two-Factor";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(enroll))
+        .routes(routes!(confirm))
+        .routes(routes!(regenerate_recovery_codes))
+        .routes(routes!(disable))
+}
+
+super::data! {
+    DataTotpEnrollment, TotpEnrollment
+    DataRecoveryCodes, RecoveryCodes
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/two-factor/enroll",
+    responses(
+        (status = 200, body = DataTotpEnrollment),
+        (status = 401),
+        Error
+    ),
+)]
+async fn enroll(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::TwoFactorService>,
+) -> Result<Data<TotpEnrollment>, Error> {
+    service.enroll(&user).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/two-factor/confirm",
+    request_body = TwoFactorCode,
+    responses(
+        (status = 200, body = DataRecoveryCodes),
+        (status = 401),
+        Error
+    ),
+)]
+async fn confirm(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::TwoFactorService>,
+    Json(code): Json<TwoFactorCode>,
+) -> Result<Data<RecoveryCodes>, Error> {
+    service.confirm(&user, code).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/two-factor/recovery-codes",
+    request_body = TwoFactorCode,
+    responses(
+        (status = 200, body = DataRecoveryCodes),
+        (status = 401),
+        Error
+    ),
+)]
+async fn regenerate_recovery_codes(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::TwoFactorService>,
+    Json(code): Json<TwoFactorCode>,
+) -> Result<Data<RecoveryCodes>, Error> {
+    service
+        .regenerate_recovery_codes(&user, code)
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/two-factor/disable",
+    request_body = TwoFactorCode,
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn disable(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::TwoFactorService>,
+    Json(code): Json<TwoFactorCode>,
+) -> Result<Message, Error> {
+    service.disable(&user, code).await.map(|()| Message::ok())
+}
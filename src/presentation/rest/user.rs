@@ -1,8 +1,9 @@
 use axum::Json;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::response::IntoResponse;
+use axum::response::{IntoResponse, Response};
 use axum_typed_multipart::TypedMultipart;
+use chrono::Utc;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
@@ -11,7 +12,8 @@ use super::state::{
     ArcAppState, AuthSession, {self},
 };
 use crate::application::auth::{
-    AuthServiceTrait, SessionBackendError, SignInError, SignUpError,
+    AuthServiceTrait, ChangePassword, ChangePasswordError, SessionBackendError,
+    SessionError, SignInError, SignInOutcome, SignUpError,
 };
 use crate::application::user_image::{
     Error as UserImageError, UploadAvatar, UploadProfileBanner,
@@ -21,12 +23,16 @@ use crate::domain::model::auth::AuthCredential;
 use crate::domain::model::markdown::{
     Markdown, {self},
 };
-use crate::domain::user::UserProfile;
+use crate::domain::two_factor::{PendingSignIn, TwoFactorCode};
+use crate::domain::user::{SessionClient, SessionRepo, User, UserProfile};
 use crate::infra::error::Error;
+use crate::infra::session::RedisSessionRepo;
 use crate::presentation::api_response::{self, Data, IntoApiResponse, Message};
 
 const TAG: &str = "User";
 
+const PENDING_SIGN_IN_KEY: &str = "pending_sign_in";
+
 pub fn router() -> OpenApiRouter<ArcAppState> {
     OpenApiRouter::new()
         .routes(routes!(upload_profile_banner))
@@ -36,7 +42,9 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
         .routes(routes!(update_bio))
         .routes(routes!(profile_with_name))
         .routes(routes!(sign_in))
+        .routes(routes!(sign_in_two_factor))
         .routes(routes!(sign_up))
+        .routes(routes!(change_password))
 }
 
 super::data! {
@@ -92,6 +100,8 @@ async fn sign_up(
     mut auth_session: AuthSession,
     State(use_case): State<state::UserProfileService>,
     State(auth_service): State<state::AuthService>,
+    State(sessions): State<RedisSessionRepo>,
+    client: SessionClient,
     Json(creds): Json<AuthCredential>,
 ) -> Result<Data<UserProfile>, impl IntoResponse> {
     let user = auth_service
@@ -104,6 +114,8 @@ async fn sign_up(
         .await
         .map_err(|e| SessionBackendError::from(e).into_response())?;
 
+    track_session(&auth_session, &sessions, user.id, client).await?;
+
     profile_impl(&use_case, &user.name, None).await
 }
 
@@ -121,25 +133,197 @@ async fn sign_up(
 async fn sign_in(
     mut auth_session: state::AuthSession,
     State(use_case): State<state::UserProfileService>,
+    State(auth_service): State<state::AuthService>,
+    State(sessions): State<RedisSessionRepo>,
+    client: SessionClient,
     Json(creds): Json<AuthCredential>,
 ) -> Result<Data<UserProfile>, impl IntoResponse> {
     if auth_session.user.is_some() {
         return Err(SignInError::already_signed_in().into_api_response());
     }
-    let user = auth_session
-        .authenticate(creds)
+
+    let user = match auth_service
+        .sign_in(creds, client.ip.as_deref())
         .await
-        .map_err(SessionBackendError::from)
         .map_err(IntoApiResponse::into_api_response)?
-        .ok_or_else(|| StatusCode::UNAUTHORIZED.into_response())?;
+    {
+        SignInOutcome::Complete(user) => user,
+        SignInOutcome::TwoFactorRequired { user_id, username } => {
+            return Err(require_two_factor(
+                &auth_session,
+                PendingSignIn::new(
+                    user_id,
+                    username,
+                    client.ip.clone(),
+                    Utc::now(),
+                ),
+            )
+            .await);
+        }
+    };
+
+    complete_sign_in(&mut auth_session, &use_case, &sessions, user, client)
+        .await
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/sign-in/two-factor",
+    request_body = TwoFactorCode,
+    responses(
+        (status = 200, body = DataUserProfile),
+        (status = 401),
+        SignInError,
+    )
+)]
+async fn sign_in_two_factor(
+    mut auth_session: state::AuthSession,
+    State(use_case): State<state::UserProfileService>,
+    State(auth_service): State<state::AuthService>,
+    State(sessions): State<RedisSessionRepo>,
+    client: SessionClient,
+    Json(code): Json<TwoFactorCode>,
+) -> Result<Data<UserProfile>, Response> {
+    if auth_session.user.is_some() {
+        return Err(SignInError::already_signed_in().into_api_response());
+    }
+
+    let mut pending = auth_session
+        .session
+        .get::<PendingSignIn>(PENDING_SIGN_IN_KEY)
+        .await
+        .map_err(session_error)?
+        .ok_or_else(|| SignInError::TwoFactorExpired.into_api_response())?;
+
+    if !pending.attempt(Utc::now()) {
+        auth_session
+            .session
+            .remove::<PendingSignIn>(PENDING_SIGN_IN_KEY)
+            .await
+            .map_err(session_error)?;
+
+        return Err(SignInError::TwoFactorExpired.into_api_response());
+    }
+
+    auth_session
+        .session
+        .insert(PENDING_SIGN_IN_KEY, &pending)
+        .await
+        .map_err(session_error)?;
+
+    let user = auth_service
+        .sign_in_two_factor(&pending, code)
+        .await
+        .map_err(IntoApiResponse::into_api_response)?;
+
+    auth_session
+        .session
+        .remove::<PendingSignIn>(PENDING_SIGN_IN_KEY)
+        .await
+        .map_err(session_error)?;
+
+    complete_sign_in(&mut auth_session, &use_case, &sessions, user, client)
+        .await
+}
+
+/// Remembers who passed the first step until the code is sent
+pub(super) async fn require_two_factor(
+    auth_session: &AuthSession,
+    pending: PendingSignIn,
+) -> Response {
+    if let Err(err) = auth_session
+        .session
+        .insert(PENDING_SIGN_IN_KEY, pending)
+        .await
+    {
+        return session_error(err);
+    }
+
+    SignInError::TwoFactorRequired.into_api_response()
+}
 
+pub(super) async fn complete_sign_in(
+    auth_session: &mut AuthSession,
+    use_case: &state::UserProfileService,
+    sessions: &RedisSessionRepo,
+    user: User,
+    client: SessionClient,
+) -> Result<Data<UserProfile>, Response> {
     auth_session
         .login(&user)
         .await
         .map_err(SessionBackendError::from)
         .map_err(IntoResponse::into_response)?;
 
-    profile_impl(&use_case, &user.name, None).await
+    track_session(auth_session, sessions, user.id, client).await?;
+
+    profile_impl(use_case, &user.name, None).await
+}
+
+pub(super) fn session_error(
+    err: axum_login::tower_sessions::session::Error,
+) -> Response {
+    SessionBackendError::from(SessionError::new(err)).into_response()
+}
+
+/// Saves the new session right away, its id is only known once stored
+async fn track_session(
+    auth_session: &AuthSession,
+    sessions: &RedisSessionRepo,
+    user_id: i32,
+    client: SessionClient,
+) -> Result<(), Response> {
+    auth_session.session.save().await.map_err(session_error)?;
+
+    if let Some(id) = auth_session.session.id() {
+        sessions
+            .track_session(user_id, id.to_string(), client)
+            .await
+            .map_err(|e| Error::from(e).into_response())?;
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/password",
+    request_body = ChangePassword,
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        ChangePasswordError,
+    )
+)]
+async fn change_password(
+    CurrentUser(user): CurrentUser,
+    mut auth_session: AuthSession,
+    State(auth_service): State<state::AuthService>,
+    State(sessions): State<RedisSessionRepo>,
+    client: SessionClient,
+    Json(change): Json<ChangePassword>,
+) -> Result<Message, Response> {
+    let user = auth_service
+        .change_password(user, change)
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    // Sign out every session, the current one continues with a new session
+    sessions
+        .revoke_sessions(user.id)
+        .await
+        .map_err(|e| Error::from(e).into_response())?;
+
+    auth_session
+        .login(&user)
+        .await
+        .map_err(|e| SessionBackendError::from(e).into_response())?;
+
+    track_session(&auth_session, &sessions, user.id, client).await?;
+
+    Ok(Message::ok())
 }
 
 #[utoipa::path(
@@ -0,0 +1,144 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::application::webhook::Error;
+use crate::domain::repository::{Cursor, Paginated};
+use crate::domain::webhook::{
+    CreatedWebhook, NewWebhook, Webhook, WebhookDelivery,
+};
+use crate::presentation::api_response::{Data, Message};
+
+const TAG: &str = "Webhook";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .routes(routes!(find_webhooks, create_webhook))
+        .routes(routes!(delete_webhook))
+        .routes(routes!(find_webhook_deliveries))
+        .routes(routes!(ping_webhook))
+}
+
+super::data! {
+    DataVecWebhook, Vec<Webhook>
+    DataCreatedWebhook, CreatedWebhook
+    DataPaginatedWebhookDelivery, Paginated<WebhookDelivery>
+}
+
+#[derive(Deserialize, IntoParams)]
+struct DeliveryQuery {
+    /// The id of the last delivery of the previous page, 0 for the newest
+    cursor: u32,
+    limit: u8,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/webhooks",
+    responses(
+        (status = 200, body = DataVecWebhook),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_webhooks(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::WebhookService>,
+) -> Result<Data<Vec<Webhook>>, Error> {
+    service.find(&user).await.map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/webhooks",
+    request_body = NewWebhook,
+    responses(
+        (status = 200, body = DataCreatedWebhook),
+        (status = 401),
+        Error
+    ),
+)]
+async fn create_webhook(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::WebhookService>,
+    Json(webhook): Json<NewWebhook>,
+) -> Result<Data<CreatedWebhook>, Error> {
+    service.create(&user, webhook).await.map(Into::into)
+}
+
+#[utoipa::path(
+    delete,
+    tag = TAG,
+    path = "/webhooks/{id}",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn delete_webhook(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::WebhookService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.delete(&user, id).await?;
+
+    Ok(Message::ok())
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/webhooks/{id}/deliveries",
+    params(DeliveryQuery),
+    responses(
+        (status = 200, body = DataPaginatedWebhookDelivery),
+        (status = 401),
+        Error
+    ),
+)]
+async fn find_webhook_deliveries(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::WebhookService>,
+    Path(id): Path<i32>,
+    Query(query): Query<DeliveryQuery>,
+) -> Result<Data<Paginated<WebhookDelivery>>, Error> {
+    let pagination = Cursor {
+        at: query.cursor,
+        limit: query.limit,
+    };
+
+    service
+        .find_deliveries(&user, id, pagination)
+        .await
+        .map(Into::into)
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/webhooks/{id}/ping",
+    responses(
+        (status = 200, body = Message),
+        (status = 401),
+        Error
+    ),
+)]
+async fn ping_webhook(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::WebhookService>,
+    Path(id): Path<i32>,
+) -> Result<Message, Error> {
+    service.ping(&user, id).await?;
+
+    Ok(Message::ok())
+}